@@ -1,8 +1,6 @@
 mod dfs;
-mod dijkstra;
 mod utility_csr;
 mod utility_edge;
 
-pub use dijkstra::Dijkstra;
 pub use utility_csr::{CSRBuilder, CSR};
 pub use utility_edge::Edge;
@@ -1,5 +1,4 @@
 pub mod graph;
-pub mod heap;
 pub mod math;
 pub mod modint;
 pub mod range_query;
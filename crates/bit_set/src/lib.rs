@@ -1,3 +1,4 @@
+#[allow(dead_code)]
 pub struct BitSet {
     bits: Box<[u64]>
 }
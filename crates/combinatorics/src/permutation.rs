@@ -21,6 +21,7 @@ impl<T: Ord> Permutation<T> {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<&[T]> {
         match self.state {
             PermutationState::Mid => {
@@ -0,0 +1,42 @@
+// Compares `write_lines` with `fast_writeln` per element and `writeln!` on a `BufWriter`.
+//
+// Run with `cargo run --release -p fast_io --example write_lines`.
+
+use fast_io::FastOutput;
+use std::{
+    io::{sink, BufWriter, Write},
+    time::Instant,
+};
+use test_utils::xorshift;
+
+fn main() {
+    const N: usize = 1_000_000;
+
+    let mut next = xorshift(0x2545_f491_4f6c_dd1d);
+    let values = Vec::from_iter((0..N).map(|_| {
+        let x = next();
+        // various number of digits
+        x >> (x % 64)
+    }));
+
+    let mut writer = BufWriter::new(sink());
+    let start = Instant::now();
+    for value in &values {
+        writeln!(writer, "{value}").unwrap();
+    }
+    writer.flush().unwrap();
+    println!("writeln!:     {:?}", start.elapsed());
+
+    let mut out = FastOutput::new(sink());
+    let start = Instant::now();
+    let mut bytes = 0;
+    for value in &values {
+        bytes += out.fast_writeln(value).unwrap();
+    }
+    println!("fast_writeln: {bytes} bytes in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    // takes items by value, as computed answers would be
+    let bytes = out.write_lines(values.iter().copied()).unwrap();
+    println!("write_lines:  {bytes} bytes in {:?}", start.elapsed());
+}
@@ -0,0 +1,36 @@
+// Compares `fast_write_all_ln_each` with `fast_writeln` per element.
+//
+// Run with `cargo run --release -p fast_io --example write_many_ln`.
+
+use fast_io::FastOutput;
+use std::{io::sink, time::Instant};
+use test_utils::xorshift;
+
+fn main() {
+    const N: usize = 10_000_000;
+
+    let mut next = xorshift(0x2545_f491_4f6c_dd1d);
+    let values = Vec::from_iter((0..N).map(|_| {
+        let x = next();
+        // various number of digits
+        x >> (x % 64)
+    }));
+
+    let mut out = FastOutput::new(sink());
+    let start = Instant::now();
+    let mut bytes = 0;
+    for value in &values {
+        bytes += out.fast_writeln(value).unwrap();
+    }
+    println!(
+        "fast_writeln:           {bytes} bytes in {:?}",
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let bytes = out.fast_write_all_ln_each(&values).unwrap();
+    println!(
+        "fast_write_all_ln_each: {bytes} bytes in {:?}",
+        start.elapsed()
+    );
+}
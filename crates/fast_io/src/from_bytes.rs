@@ -1,4 +1,4 @@
-use std::num::IntErrorKind;
+use std::num::{IntErrorKind, ParseFloatError};
 
 #[inline]
 const fn parse_2_digits_radix_10(mut bytes_le: u16) -> Result<u16, IntErrorKind> {
@@ -121,6 +121,30 @@ pub trait FromBytes: Sized {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Err>;
 }
 
+/// Parses all tokens separated by ASCII whitespace, without [FastInput](crate::FastInput).
+///
+/// Leading and trailing whitespace is ignored, so an empty or blank input yields an empty [Vec].
+/// Returns the first error if any token is invalid.
+///
+/// # Example
+///
+/// ```
+/// use std::num::IntErrorKind;
+///
+/// use fast_io::parse_all;
+///
+/// assert_eq!(parse_all::<i32>(b"  3 -1\n4\t1\r\n"), Ok(vec![3, -1, 4, 1]));
+/// assert_eq!(parse_all::<u8>(b"\n"), Ok(vec![]));
+/// assert_eq!(parse_all::<u8>(b"1 x 300"), Err(IntErrorKind::InvalidDigit));
+/// ```
+pub fn parse_all<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>, T::Err> {
+    bytes
+        .split(u8::is_ascii_whitespace)
+        .filter(|token| !token.is_empty())
+        .map(T::from_bytes)
+        .collect()
+}
+
 macro_rules! from_bytes_int_impl {
     ( $( $int_ty:ty )* ) => {$(
         impl FromBytes for $int_ty {
@@ -237,9 +261,45 @@ from_bytes_size_impl! { isize as i32, usize as u32 }
 #[cfg(target_pointer_width = "64")]
 from_bytes_size_impl! { isize as i64, usize as u64 }
 
+macro_rules! from_bytes_float_impl {
+    ( $( $float_ty:ty )* ) => {$(
+        /// Accepts the same syntax as [`str::parse`], e.g. `3.14`, `+1`, `-0.5e-3` and `inf`.
+        /// The result is identical to that of [`str::parse`].
+        impl FromBytes for $float_ty {
+            type Err = ParseFloatError;
+
+            fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Err> {
+                match std::str::from_utf8(bytes) {
+                    Ok(s) => s.parse(),
+                    // non-ASCII bytes are invalid anyway
+                    Err(_) => String::from_utf8_lossy(bytes).parse(),
+                }
+            }
+        }
+    )*};
+}
+
+from_bytes_float_impl! { f32 f64 }
+
+/// Accepts `0`, `1`, `false` and `true`.
+impl FromBytes for bool {
+    type Err = IntErrorKind;
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Self::Err> {
+        match bytes {
+            b"0" | b"false" => Ok(false),
+            b"1" | b"true" => Ok(true),
+            [] => Err(IntErrorKind::Empty),
+            _ => Err(IntErrorKind::InvalidDigit),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::FromBytes;
+    use super::{parse_all, FromBytes};
+    use std::num::IntErrorKind;
 
     #[test]
     fn check_min_max() {
@@ -252,4 +312,71 @@ mod tests {
 
         check_min_max! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize }
     }
+
+    #[test]
+    fn float() {
+        for token in [
+            "3.14", "-0.5", "1e9", "0", "-0", "42", "+1.5", "1e-3", "2.5E+2", ".5", "5.",
+            "0.1", "123456789.123456789", "1e308", "1e-320", "1e400", "inf", "-inf", "NaN",
+        ] {
+            let expected = token.parse::<f64>().unwrap();
+            let result = f64::from_bytes(token.as_bytes()).unwrap();
+            assert!(
+                result.to_bits() == expected.to_bits() || (result.is_nan() && expected.is_nan()),
+                "{token}"
+            );
+
+            let expected = token.parse::<f32>().unwrap();
+            let result = f32::from_bytes(token.as_bytes()).unwrap();
+            assert!(
+                result.to_bits() == expected.to_bits() || (result.is_nan() && expected.is_nan()),
+                "{token}"
+            );
+        }
+
+        for token in ["", "+", "-", ".", "1.2.3", "abc", "1e", "1e+", "--1", "1,5", "0x10", "1 2"] {
+            assert!(f64::from_bytes(token.as_bytes()).is_err(), "{token}");
+            assert!(f32::from_bytes(token.as_bytes()).is_err(), "{token}");
+        }
+        assert!(f64::from_bytes(&[b'1', 0xff]).is_err());
+    }
+
+    #[test]
+    fn bool() {
+        assert_eq!(bool::from_bytes(b"0"), Ok(false));
+        assert_eq!(bool::from_bytes(b"1"), Ok(true));
+        assert_eq!(bool::from_bytes(b"false"), Ok(false));
+        assert_eq!(bool::from_bytes(b"true"), Ok(true));
+
+        assert_eq!(bool::from_bytes(b""), Err(IntErrorKind::Empty));
+        for token in ["2", "01", "-1", "True", "Y", "N", "yes", "truee", " 1"] {
+            assert_eq!(
+                bool::from_bytes(token.as_bytes()),
+                Err(IntErrorKind::InvalidDigit),
+                "{token}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_all_tokens() {
+        assert_eq!(
+            parse_all::<u64>(b"1 2  3\n\n4\r\n 5\t6\x0c7"),
+            Ok(vec![1, 2, 3, 4, 5, 6, 7])
+        );
+        assert_eq!(parse_all::<i64>(b"\n -1 +2 -3 \n"), Ok(vec![-1, 2, -3]));
+        assert_eq!(parse_all::<f64>(b"0.5\n1e3"), Ok(vec![0.5, 1e3]));
+        assert_eq!(parse_all::<bool>(b"0 1 true"), Ok(vec![false, true, true]));
+
+        assert_eq!(parse_all::<u32>(b""), Ok(vec![]));
+        assert_eq!(parse_all::<u32>(b" \n\t \r\n"), Ok(vec![]));
+
+        // the first error is returned
+        assert_eq!(
+            parse_all::<u8>(b"1 -2 256"),
+            Err(IntErrorKind::InvalidDigit)
+        );
+        assert_eq!(parse_all::<u8>(b"1 256 -2"), Err(IntErrorKind::PosOverflow));
+        assert!(parse_all::<f32>(b"1.5 abc").is_err());
+    }
 }
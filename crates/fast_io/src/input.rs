@@ -5,10 +5,25 @@ use std::{
 
 use super::FromBytes;
 
+/// Parses a token, reporting a malformed one as [`ErrorKind::InvalidData`].
+fn parse<T: FromBytes>(token: &[u8]) -> io::Result<T>
+where
+    <T as FromBytes>::Err: Debug,
+{
+    T::from_bytes(token).map_err(|e| {
+        let token = String::from_utf8_lossy(token);
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid token {token:?}: {e:?}"),
+        )
+    })
+}
+
 /// A wrapper of [BufReader](std::io::BufReader).
 pub struct FastInput<R: BufRead> {
     reader: R,
     consumed: usize,
+    line: Vec<u8>,
 }
 
 impl<R: BufRead> FastInput<R> {
@@ -18,6 +33,7 @@ impl<R: BufRead> FastInput<R> {
         Self {
             reader,
             consumed: 0,
+            line: Vec::new(),
         }
     }
 
@@ -39,8 +55,7 @@ impl<R: BufRead> FastInput<R> {
             if let Some(skip) = src.iter().position(|b| b.is_ascii_graphic()) {
                 if let Some(n) = src[skip..].iter().position(|b| !b.is_ascii_graphic()) {
                     self.consumed = skip + n;
-                    // TODO: parsing error
-                    return Ok(T::from_bytes(&src[skip..skip + n]).unwrap());
+                    return parse(&src[skip..skip + n]);
                 } else {
                     buf.extend_from_slice(&src[skip..]);
                 }
@@ -51,24 +66,26 @@ impl<R: BufRead> FastInput<R> {
         {
             let src = self.reader.fill_buf()?;
             if src.is_empty() {
+                if !buf.is_empty() {
+                    // the last token
+                    return parse(&buf);
+                }
                 return Err(Error::new(ErrorKind::Other, "no more data"));
             }
             if let Some(skip) = src.iter().position(|b| b.is_ascii_graphic()) {
                 if !buf.is_empty() && skip != 0 {
-                    // TODO: parsing error
                     self.reader.consume(skip);
-                    return Ok(T::from_bytes(&buf).unwrap());
+                    return parse(&buf);
                 }
                 if let Some(n) = src[skip..].iter().position(|b| !b.is_ascii_graphic()) {
                     self.consumed = skip + n;
-                    // TODO: parsing error
                     if !buf.is_empty() {
                         debug_assert_eq!(skip, 0);
 
                         buf.extend_from_slice(&src[..n]);
-                        return Ok(T::from_bytes(&buf).unwrap());
+                        return parse(&buf);
                     } else {
-                        return Ok(T::from_bytes(&src[skip..skip + n]).unwrap());
+                        return parse(&src[skip..skip + n]);
                     }
                 } else {
                     buf.extend_from_slice(&src[skip..]);
@@ -82,24 +99,26 @@ impl<R: BufRead> FastInput<R> {
         for _ in 0..ITERATION_LIMIT {
             let src = self.reader.fill_buf()?;
             if src.is_empty() {
+                if !buf.is_empty() {
+                    // the last token
+                    return parse(&buf);
+                }
                 return Err(Error::new(ErrorKind::Other, "no more data"));
             }
             if let Some(skip) = src.iter().position(|b| b.is_ascii_graphic()) {
                 if !buf.is_empty() && skip != 0 {
-                    // TODO: parsing error
                     self.reader.consume(skip);
-                    return Ok(T::from_bytes(&buf).unwrap());
+                    return parse(&buf);
                 }
                 if let Some(n) = src[skip..].iter().position(|b| !b.is_ascii_graphic()) {
                     self.consumed = skip + n;
-                    // TODO: parsing error
                     if !buf.is_empty() {
                         debug_assert_eq!(skip, 0);
 
                         buf.extend_from_slice(&src[..n]);
-                        return Ok(T::from_bytes(&buf).unwrap());
+                        return parse(&buf);
                     } else {
-                        return Ok(T::from_bytes(&src[skip..skip + n]).unwrap());
+                        return parse(&src[skip..skip + n]);
                     }
                 } else {
                     buf.extend_from_slice(&src[skip..]);
@@ -111,4 +130,318 @@ impl<R: BufRead> FastInput<R> {
 
         panic!("reached iteration limit: {}", ITERATION_LIMIT);
     }
+
+    /// Reads the next non-whitespace byte, e.g. a cell of a grid or a `Y`/`N` flag.
+    pub fn read_char(&mut self) -> io::Result<u8> {
+        self.reader.consume(std::mem::take(&mut self.consumed));
+
+        loop {
+            let src = self.reader.fill_buf()?;
+            if src.is_empty() {
+                return Err(Error::new(ErrorKind::Other, "no more data"));
+            }
+            if let Some(skip) = src.iter().position(|b| b.is_ascii_graphic()) {
+                let b = src[skip];
+                self.consumed = skip + 1;
+                return Ok(b);
+            }
+            let len = src.len();
+            self.reader.consume(len);
+        }
+    }
+
+    /// Reads `h` whitespace-separated rows of exactly `w` bytes each, e.g. a maze of `#` and `.`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input ends early or a row does not have length `w`.
+    pub fn read_grid(&mut self, h: usize, w: usize) -> io::Result<Vec<Vec<u8>>> {
+        let mut grid = Vec::with_capacity(h);
+        for i in 0..h {
+            let mut row = Vec::with_capacity(w);
+            self.read_bytes_into(&mut row)?;
+            if row.len() != w {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("row {} has length {}, expected {}", i, row.len(), w),
+                ));
+            }
+            grid.push(row)
+        }
+
+        Ok(grid)
+    }
+
+    /// Appends the next whitespace-separated token to `buf` without parsing.
+    fn read_bytes_into(&mut self, buf: &mut Vec<u8>) -> io::Result<()> {
+        self.reader.consume(std::mem::take(&mut self.consumed));
+
+        // skip leading whitespace
+        loop {
+            let src = self.reader.fill_buf()?;
+            if src.is_empty() {
+                return Err(Error::new(ErrorKind::Other, "no more data"));
+            }
+            if let Some(skip) = src.iter().position(|b| b.is_ascii_graphic()) {
+                self.reader.consume(skip);
+                break;
+            }
+            let len = src.len();
+            self.reader.consume(len);
+        }
+        loop {
+            let src = self.reader.fill_buf()?;
+            if let Some(n) = src.iter().position(|b| !b.is_ascii_graphic()) {
+                buf.extend_from_slice(&src[..n]);
+                self.reader.consume(n);
+                return Ok(());
+            }
+            if src.is_empty() {
+                // the last token
+                return Ok(());
+            }
+            buf.extend_from_slice(src);
+            let len = src.len();
+            self.reader.consume(len);
+        }
+    }
+
+    /// Returns the next byte without consuming it, or `None` at the end of input.
+    ///
+    /// Whitespace is not skipped, so the newline left behind by [`next_token`](Self::next_token)
+    /// can be observed here.
+    pub fn peek_byte(&mut self) -> Option<u8> {
+        self.reader.consume(std::mem::take(&mut self.consumed));
+
+        self.reader.fill_buf().ok()?.first().copied()
+    }
+
+    /// Reads bytes up to and excluding the next `\n`, which is consumed.
+    /// A trailing `\r` is also removed. At the end of input, returns the remaining bytes
+    /// (possibly empty).
+    ///
+    /// Reading a token with [`next_token`](Self::next_token) leaves the whitespace after it unread,
+    /// so a `read_line` right after a token returns the rest of that line, which is usually empty.
+    /// Call it once more to get the next line.
+    ///
+    /// Leading and trailing spaces in the line are preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner reader fails with anything other than [`ErrorKind::Interrupted`],
+    /// which is retried.
+    pub fn read_line(&mut self) -> io::Result<&[u8]> {
+        self.reader.consume(std::mem::take(&mut self.consumed));
+        self.line.clear();
+
+        loop {
+            let src = match self.reader.fill_buf() {
+                Ok(src) => src,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if src.is_empty() {
+                break;
+            }
+            if let Some(n) = src.iter().position(|&b| b == b'\n') {
+                self.line.extend_from_slice(&src[..n]);
+                self.reader.consume(n + 1);
+                break;
+            }
+            self.line.extend_from_slice(src);
+            let len = src.len();
+            self.reader.consume(len);
+        }
+        if self.line.last() == Some(&b'\r') {
+            self.line.pop();
+        }
+
+        Ok(&self.line)
+    }
+
+    /// Reads `n` whitespace-separated tokens.
+    pub fn read_vec<T: FromBytes>(&mut self, n: usize) -> io::Result<Vec<T>>
+    where
+        <T as FromBytes>::Err: Debug,
+    {
+        let mut res = Vec::with_capacity(n);
+        for _ in 0..n {
+            res.push(self.next_token()?)
+        }
+
+        Ok(res)
+    }
+
+    /// Reads two whitespace-separated tokens.
+    pub fn read_pair<A: FromBytes, B: FromBytes>(&mut self) -> io::Result<(A, B)>
+    where
+        <A as FromBytes>::Err: Debug,
+        <B as FromBytes>::Err: Debug,
+    {
+        Ok((self.next_token()?, self.next_token()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_vec_and_pair() {
+        let mut input = FastInput::new(&b"3\n1 -2 3\n  4 5.5\n6\t7\n"[..]);
+
+        let n: usize = input.next_token().unwrap();
+        assert_eq!(input.read_vec::<i32>(n).unwrap(), [1, -2, 3]);
+        assert_eq!(input.read_pair::<u8, f64>().unwrap(), (4, 5.5));
+        assert_eq!(input.read_vec::<u64>(2).unwrap(), [6, 7]);
+        assert_eq!(input.read_vec::<u64>(0).unwrap(), []);
+        assert!(input.read_vec::<u64>(1).is_err());
+    }
+
+    #[test]
+    fn short_input() {
+        // no trailing whitespace
+        let mut input = FastInput::new(&b"1 2"[..]);
+        assert_eq!(input.read_vec::<u32>(2).unwrap(), [1, 2]);
+
+        let mut input = FastInput::new(&b"1 2"[..]);
+        assert!(input.read_vec::<u32>(3).is_err());
+
+        let mut input = FastInput::new(&b"1"[..]);
+        assert!(input.read_pair::<u32, u32>().is_err());
+
+        // the last token is malformed
+        let mut input = FastInput::new(&b"1 2x"[..]);
+        let err = input.read_vec::<u32>(2).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // tiny buffer to reach the end of input after several refills
+        let mut input = FastInput::new(std::io::BufReader::with_capacity(1, &b"234x"[..]));
+        let err = input.next_token::<u32>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn malformed_token() {
+        let mut input = FastInput::new(&b"1 x2 3\n4 5y 6\n"[..]);
+        let err = input.read_vec::<u32>(3).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("x2"), "{err}");
+
+        // the rest of the stream is still readable
+        assert_eq!(input.next_token::<u32>().unwrap(), 3);
+        let err = input.read_pair::<u32, u32>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("5y"), "{err}");
+        assert_eq!(input.next_token::<u32>().unwrap(), 6);
+
+        // tokens spanning several refills
+        let data = b"12 3z4 5\n";
+        let mut input = FastInput::new(std::io::BufReader::with_capacity(2, &data[..]));
+        let err = input.read_vec::<u32>(3).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("3z4"), "{err}");
+    }
+
+    #[test]
+    fn mixed_tokens_and_lines() {
+        let mut input = FastInput::new(&b"2 3\n  hello, world \r\n# . #\n\n42\nlast"[..]);
+
+        assert_eq!(input.read_pair::<u32, u32>().unwrap(), (2, 3));
+        assert_eq!(input.peek_byte(), Some(b'\n'));
+        // the rest of the first line
+        assert_eq!(input.read_line().unwrap(), b"");
+        assert_eq!(input.peek_byte(), Some(b' '));
+        assert_eq!(input.read_line().unwrap(), b"  hello, world ");
+        assert_eq!(input.read_line().unwrap(), b"# . #");
+        assert_eq!(input.read_line().unwrap(), b"");
+        assert_eq!(input.next_token::<u32>().unwrap(), 42);
+        assert_eq!(input.read_line().unwrap(), b"");
+        assert_eq!(input.peek_byte(), Some(b'l'));
+        assert_eq!(input.read_line().unwrap(), b"last");
+        assert_eq!(input.peek_byte(), None);
+        assert_eq!(input.read_line().unwrap(), b"");
+    }
+
+    #[test]
+    fn read_line_across_refills() {
+        let data = b"1\nabcdefghijklmnopqrstuvwxyz\n7\n";
+        // tiny buffer to force lines to span several refills
+        let mut input = FastInput::new(std::io::BufReader::with_capacity(4, &data[..]));
+
+        assert_eq!(input.next_token::<u32>().unwrap(), 1);
+        assert_eq!(input.read_line().unwrap(), b"");
+        assert_eq!(input.read_line().unwrap(), b"abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(input.next_token::<u32>().unwrap(), 7);
+    }
+
+    #[test]
+    fn read_line_errors() {
+        /// Yields the given chunks and errors in order.
+        struct Chunks(Vec<io::Result<&'static [u8]>>);
+
+        impl io::Read for Chunks {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0.is_empty() {
+                    return Ok(0);
+                }
+                let chunk = self.0.remove(0)?;
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Ok(chunk.len())
+            }
+        }
+
+        let mut input = FastInput::new(std::io::BufReader::new(Chunks(vec![
+            Err(ErrorKind::Interrupted.into()),
+            Ok(b"ab"),
+            Ok(b"c\nd"),
+            Err(ErrorKind::BrokenPipe.into()),
+        ])));
+        assert_eq!(input.read_line().unwrap(), b"abc");
+        let err = input.read_line().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn read_char_and_bool() {
+        let mut input = FastInput::new(&b"Y N\n  #.\n1 0 true\n"[..]);
+
+        assert_eq!(input.read_char().unwrap(), b'Y');
+        assert_eq!(input.read_char().unwrap(), b'N');
+        assert_eq!(input.read_char().unwrap(), b'#');
+        assert_eq!(input.read_char().unwrap(), b'.');
+        assert_eq!(input.read_vec::<bool>(3).unwrap(), [true, false, true]);
+        assert!(input.read_char().is_err());
+    }
+
+    #[test]
+    fn read_grid() {
+        let data = b"3 5\n#.#.#\n.....\r\n##..#\n7";
+        for capacity in [2, 8, 1024] {
+            let mut input = FastInput::new(std::io::BufReader::with_capacity(capacity, &data[..]));
+
+            let (h, w) = input.read_pair::<usize, usize>().unwrap();
+            let grid = input.read_grid(h, w).unwrap();
+            assert_eq!(grid.len(), 3);
+            assert!(grid.iter().all(|row| row.len() == 5));
+            assert_eq!(grid[0], b"#.#.#");
+            assert_eq!(grid[1], b".....");
+            assert_eq!(grid[2], b"##..#");
+            assert_eq!(grid[2][1], b'#');
+            assert_eq!(input.next_token::<u32>().unwrap(), 7);
+        }
+
+        let mut input = FastInput::new(&b"##\n#.#\n"[..]);
+        assert_eq!(
+            input.read_grid(2, 2).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        let mut input = FastInput::new(&b"##\n"[..]);
+        assert!(input.read_grid(2, 2).is_err());
+
+        let mut input = FastInput::new(&b"##"[..]);
+        assert_eq!(input.read_grid(1, 2).unwrap(), [b"##"]);
+        assert!(input.read_grid(0, 2).unwrap().is_empty());
+    }
 }
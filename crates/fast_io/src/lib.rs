@@ -2,9 +2,9 @@ mod from_bytes;
 mod input;
 mod write;
 
-pub use from_bytes::FromBytes;
+pub use from_bytes::{parse_all, FromBytes};
 pub use input::FastInput;
-pub use write::{FastOutput, Writable};
+pub use write::{DecimalInt, FastOutput, Fixed, Writable};
 
 pub mod prelude {
     use super::{FastInput, FastOutput};
@@ -1,7 +1,7 @@
 use std::{
+    fmt,
     io::{self, BufWriter, Write},
-    mem::MaybeUninit,
-    ptr, slice,
+    ptr,
 };
 
 /// A wrapper of [BufWriter].
@@ -43,6 +43,79 @@ impl<W: Write> FastOutput<W> {
         Ok(value.write(&mut self.writer)? + self.writer.write(b"\n")?)
     }
 
+    /// Writes each item into the inner buffer followed by a newline,
+    /// returning how many bytes were written.
+    ///
+    /// This is equivalent to calling [`fast_writeln`](Self::fast_writeln) for each item,
+    /// but takes items by value so that iterators of computed answers need not be collected.
+    pub fn write_lines<T, I>(&mut self, iter: I) -> io::Result<usize>
+    where
+        T: Writable,
+        I: IntoIterator<Item = T>,
+    {
+        let mut n = 0;
+        for value in iter {
+            n += value.write(&mut self.writer)?;
+            n += self.writer.write(b"\n")?;
+        }
+
+        Ok(n)
+    }
+
+    /// Writes each integer into the inner buffer followed by a newline,
+    /// returning how many bytes were written.
+    ///
+    /// The output is the same as [`write_lines`](Self::write_lines), but lines are formatted into a local chunk
+    /// and handed to the inner buffer once per chunk rather than once per element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fast_io::FastOutput;
+    ///
+    /// let mut buf = Vec::new();
+    /// let mut out = FastOutput::new(&mut buf);
+    /// assert_eq!(out.fast_write_all_ln_each(&[3_i64, -14, 0]).unwrap(), 8);
+    /// drop(out);
+    ///
+    /// assert_eq!(buf, b"3\n-14\n0\n");
+    /// ```
+    pub fn fast_write_all_ln_each<T>(&mut self, values: &[T]) -> io::Result<usize>
+    where
+        T: DecimalInt,
+    {
+        const CHUNK_SIZE: usize = 4 * 1024;
+
+        let mut chunk = [0; CHUNK_SIZE];
+        let mut digits = [0; MAX_DEC_LEN];
+        let (mut len, mut n) = (0, 0);
+        for &value in values {
+            if len + MAX_DEC_LEN + 1 > CHUNK_SIZE {
+                self.writer.write_all(&chunk[..len])?;
+                n += len;
+                len = 0;
+            }
+
+            let start = value.encode(&mut digits);
+            chunk[len..len + MAX_DEC_LEN - start].copy_from_slice(&digits[start..]);
+            len += MAX_DEC_LEN - start;
+            chunk[len] = b'\n';
+            len += 1;
+        }
+        self.writer.write_all(&chunk[..len])?;
+
+        Ok(n + len)
+    }
+
+    /// Writes the given value with exactly `decimals` digits after the decimal point,
+    /// returning how many bytes were written.
+    ///
+    /// The output is the same as `format!("{:.*}", decimals, value)`. See [Fixed] for details.
+    #[inline]
+    pub fn fast_write_fixed(&mut self, value: f64, decimals: usize) -> io::Result<usize> {
+        Fixed(value, decimals).write(&mut self.writer)
+    }
+
     /// [array]: https://doc.rust-lang.org/nightly/core/primitive.array.html
     /// [slice]: https://doc.rust-lang.org/nightly/core/primitive.slice.html
     /// [iterator]: https://doc.rust-lang.org/std/convert/trait.AsRef.html#implementors
@@ -115,21 +188,112 @@ impl Writable for &str {
     }
 }
 
+/// Writes the components separated by a single space.
+impl<A: Writable, B: Writable> Writable for (A, B) {
+    #[inline]
+    fn write<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<usize> {
+        Ok(self.0.write(writer)? + writer.write(b" ")? + self.1.write(writer)?)
+    }
+}
+
+/// Writes the components separated by a single space.
+impl<A: Writable, B: Writable, C: Writable> Writable for (A, B, C) {
+    #[inline]
+    fn write<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<usize> {
+        Ok(self.0.write(writer)?
+            + writer.write(b" ")?
+            + self.1.write(writer)?
+            + writer.write(b" ")?
+            + self.2.write(writer)?)
+    }
+}
+
+/// A floating point number written with a fixed number of decimals.
+///
+/// The digits are formatted by [core::fmt] on the stack and copied into the writer,
+/// so no [String] is allocated. Rounding at the last digit follows `format!("{:.*}", decimals, value)`.
+///
+/// # Example
+///
+/// ```
+/// use fast_io::{FastOutput, Fixed};
+///
+/// let mut buf = Vec::new();
+/// let mut out = FastOutput::new(&mut buf);
+/// out.fast_writeln(&(Fixed(1.0 / 3.0, 4), Fixed(-2.5, 1))).unwrap();
+/// drop(out);
+///
+/// assert_eq!(buf, b"0.3333 -2.5\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fixed(pub f64, pub usize);
+
+impl Writable for Fixed {
+    #[inline]
+    fn write<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<usize> {
+        write_fmt_counted(writer, format_args!("{:.*}", self.1, self.0))
+    }
+}
+
+/// Writes the shortest representation which round-trips, same as `format!("{}", value)`.
+/// Use [Fixed] or [FastOutput::fast_write_fixed] to specify the number of decimals.
+impl Writable for f64 {
+    #[inline]
+    fn write<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<usize> {
+        write_fmt_counted(writer, format_args!("{}", self))
+    }
+}
+
+fn write_fmt_counted<W: Write + ?Sized>(writer: &mut W, args: fmt::Arguments) -> io::Result<usize> {
+    struct Counter<'a, W: ?Sized> {
+        inner: &'a mut W,
+        count: usize,
+    }
+
+    impl<W: Write + ?Sized> Write for Counter<'_, W> {
+        #[inline]
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = self.inner.write(buf)?;
+            self.count += n;
+            Ok(n)
+        }
+
+        #[inline]
+        fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    let mut counter = Counter {
+        inner: writer,
+        count: 0,
+    };
+    counter.write_fmt(args)?;
+
+    Ok(counter.count)
+}
+
+/// The maximum length of decimal representations of primitive integers, i.e. that of `i128::MIN`.
+const MAX_DEC_LEN: usize = 40;
+
+/// Primitive integers, which are formatted through the look up table.
+pub trait DecimalInt: Writable + Copy {
+    /// Writes the decimal representation of `self` into the tail of `buf`, returning where it starts.
+    fn encode(self, buf: &mut [u8; MAX_DEC_LEN]) -> usize;
+}
+
 macro_rules! writable_int_impl {
     ( $( ($signed:ty, $unsigned:ty) ),* ) => {$(
-        impl Writable for $unsigned {
-            fn write<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<usize> {
-                let mut num = *self;
-                const SIZE: usize = <$unsigned>::MAX.ilog10() as usize + 1;
-                let mut buf = [MaybeUninit::<u8>::uninit(); SIZE];
-                let mut curr = SIZE;
-                // TODO: use `slice_as_mut_ptr()`
-                let buf_ptr = buf.as_mut_ptr() as *mut u8;
+        impl DecimalInt for $unsigned {
+            fn encode(self, buf: &mut [u8; MAX_DEC_LEN]) -> usize {
+                let mut num = self;
+                let mut curr = MAX_DEC_LEN;
+                let buf_ptr = buf.as_mut_ptr();
                 let lut_ptr = DEC_DIGITS_LUT.as_ptr();
 
                 // SAFETY: Since `rem` are always less than or equal to `9996`, we can copy from
                 // `lut_ptr[rem..rem + 4]`. To show that it's OK to copy into `buf_ptr`,
-                // notice that at the beginning `curr == SIZE > log(n = MAX)`, and at each step
+                // notice that at the beginning `curr == MAX_DEC_LEN > log(n = MAX)`, and at each step
                 // this is kept the same as `n` is divided. Since `n` is always non-negative,
                 // this means that `curr > 0` so `buf_ptr[curr..curr + 4]` is safe to access.
                 unsafe {
@@ -170,7 +334,28 @@ macro_rules! writable_int_impl {
                     }
                 }
 
-                unsafe { writer.write(slice::from_raw_parts(buf_ptr.add(curr), buf.len() - curr)) }
+                curr
+            }
+        }
+
+        impl Writable for $unsigned {
+            fn write<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<usize> {
+                let mut buf = [0; MAX_DEC_LEN];
+                let start = self.encode(&mut buf);
+                writer.write(&buf[start..])
+            }
+        }
+
+        impl DecimalInt for $signed {
+            fn encode(self, buf: &mut [u8; MAX_DEC_LEN]) -> usize {
+                let mut start = self.unsigned_abs().encode(buf);
+                if self.is_negative() {
+                    // the number of digits is less than `MAX_DEC_LEN`
+                    start -= 1;
+                    buf[start] = b'-';
+                }
+
+                start
             }
         }
 
@@ -205,3 +390,133 @@ static DEC_DIGITS_LUT: [u8; 40000] = {
     }
     lut
 };
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    fn output<F>(f: F) -> Vec<u8>
+    where
+        F: FnOnce(&mut FastOutput<&mut Vec<u8>>) -> io::Result<usize>,
+    {
+        let mut buf = Vec::new();
+        let mut out = FastOutput::new(&mut buf);
+        f(&mut out).unwrap();
+        drop(out);
+        buf
+    }
+
+    #[test]
+    fn write_tuples() {
+        assert_eq!(output(|out| out.fast_write(&(1u32, -2i64))), b"1 -2");
+        assert_eq!(
+            output(|out| out.fast_writeln(&(0u8, "ab", 300u16))),
+            b"0 ab 300\n"
+        );
+    }
+
+    #[test]
+    fn write_edge_list() {
+        let edges: Vec<(u32, u32)> = vec![(0, 1), (1, 2), (12, 345)];
+        let bytes = output(|out| out.fast_writeln_all(&edges, "\n"));
+        assert_eq!(bytes, b"0 1\n1 2\n12 345\n");
+
+        let triples = [(1usize, 2usize, -3i32), (4, 5, 6)];
+        let bytes = output(|out| out.fast_write_all(triples, "\n"));
+        assert_eq!(bytes, b"1 2 -3\n4 5 6");
+    }
+
+    #[test]
+    fn write_lines_matches_writeln() {
+        let values = Vec::from_iter((0..1000u64).map(|i| i * i * 7919 % 1_000_003));
+        let expected = output(|out| {
+            let mut n = 0;
+            for value in &values {
+                n += out.fast_writeln(value)?;
+            }
+            Ok(n)
+        });
+        let mut written = 0;
+        let bytes = output(|out| {
+            written = out.write_lines(values.iter().copied())?;
+            Ok(written)
+        });
+        assert_eq!(bytes, expected);
+        assert_eq!(written, expected.len());
+
+        assert!(output(|out| out.write_lines(Vec::<u32>::new())).is_empty());
+        assert_eq!(output(|out| out.write_lines([(1u8, 2u8)])), b"1 2\n");
+    }
+
+    #[test]
+    fn write_all_ln_each_matches_writeln() {
+        fn check<T: DecimalInt>(values: &[T]) {
+            let expected = output(|out| out.write_lines(values.iter().copied()));
+            let mut written = 0;
+            let bytes = output(|out| {
+                written = out.fast_write_all_ln_each(values)?;
+                Ok(written)
+            });
+            assert_eq!(bytes, expected);
+            assert_eq!(written, expected.len());
+        }
+
+        let mut next = xorshift(0x9e37_79b9);
+
+        // more than one chunk
+        let values = Vec::from_iter((0..10_000).map(|_| next() >> (next() % 64)));
+        check(&values);
+        check(&Vec::from_iter(values.iter().map(|&v| v as i32)));
+        check(&Vec::from_iter(values.iter().map(|&v| v as u8)));
+        check(&[0_u64, 9, 10, 9999, 10_000, u64::MAX]);
+        check(&[i128::MIN, -1, 0, i128::MAX]);
+        check(&[u128::MAX]);
+        check::<usize>(&[]);
+    }
+
+    #[test]
+    fn write_fixed() {
+        for x in [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            0.5,
+            std::f64::consts::PI,
+            -std::f64::consts::E,
+            0.0000004,
+            0.0000005,
+            0.0000015,
+            -0.9999996,
+            123_456_789.123_456_79,
+            1e20,
+            -1e-10,
+            f64::MAX,
+        ] {
+            let bytes = output(|out| out.fast_write_fixed(x, 6));
+            assert_eq!(String::from_utf8(bytes).unwrap(), format!("{:.6}", x));
+        }
+        assert_eq!(output(|out| out.fast_write_fixed(2.5, 0)), b"2");
+        assert_eq!(output(|out| out.fast_write_fixed(-1.25, 3)), b"-1.250");
+    }
+
+    #[test]
+    fn write_f64() {
+        assert_eq!(output(|out| out.fast_write(&0.1)), b"0.1");
+        assert_eq!(output(|out| out.fast_write(&-3.0)), b"-3");
+        assert_eq!(
+            output(|out| out.fast_write(&(1.5, Fixed(0.25, 1)))),
+            b"1.5 0.2"
+        );
+    }
+
+    #[test]
+    fn written_byte_count() {
+        let mut buf = Vec::new();
+        let mut out = FastOutput::new(&mut buf);
+        assert_eq!(out.fast_write(&(10u32, 200u32, 3000u32)).unwrap(), 11);
+        assert_eq!(out.fast_write_fixed(-1.5, 3).unwrap(), 6);
+    }
+}
@@ -1,8 +1,36 @@
+mod non_commutative;
+
 use std::ops::RangeBounds;
 
 use math_traits::{marker::Commutative, Group};
+pub use non_commutative::NonCommutativeFenwickTree;
 
 /// A data structure which efficiently performs point updates and range queries.
+///
+/// # Example
+///
+/// ```
+/// use fenwick_tree::FenwickTree;
+/// use math_traits::monoids::Add;
+///
+/// let mut ft = FenwickTree::from_iter(vec![Add(3_i64), Add(1), Add(4), Add(1), Add(5)]);
+/// assert_eq!(ft.range_query(1..4), Add(6));
+///
+/// ft.point_update(2, Add(-4));
+/// assert_eq!(ft.range_query(..), Add(10));
+/// ```
+///
+/// # Commutativity
+///
+/// Every step below reorders elements, so the results are wrong for non-commutative groups.
+///
+/// * [`point_update`](Self::point_update) combines `elem` at the front of each block containing `i`,
+///   not at the position of `i`.
+/// * [`prefix_query`](Self::prefix_query) visits blocks from right to left, but appends them to the result.
+/// * [`range_query`](Self::range_query) cancels the common prefix of `l` and `r` as `(left part)⁻¹ ∘ (right part)`,
+///   which equals the range only if the left part can be moved next to its inverse.
+///
+/// Use [`NonCommutativeFenwickTree`](crate::NonCommutativeFenwickTree) instead.
 pub struct FenwickTree<T: Group + Commutative> {
     /// one-based indexing internally (`data[0]` is the identity element for simple implementation)
     data: Vec<T>,
@@ -126,7 +154,23 @@ impl<T: Group + Commutative> FenwickTree<T> {
     }
 
     /// [`slice::partition_point`] on the slice whose `i`-th element is
-    /// [`prefix_query(i)`](Self::prefix_query).
+    /// [`prefix_query(i + 1)`](Self::prefix_query), so the result is in `[0, N]`.
+    ///
+    /// In other words, returns the largest `i` such that `pred(prefix_query(i))` holds,
+    /// or `0` if there is no such `i` (the empty prefix is not tested).
+    /// `pred` should be monotone, that is, once it becomes `false`, it never returns to `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fenwick_tree::FenwickTree;
+    /// use math_traits::monoids::Add;
+    ///
+    /// let ft = FenwickTree::from_iter([3, 1, 4, 1, 5].map(Add));
+    /// assert_eq!(ft.partition_point(|Add(sum)| sum <= 8), 3);
+    /// assert_eq!(ft.partition_point(|_| true), 5);
+    /// assert_eq!(ft.partition_point(|_| false), 0);
+    /// ```
     ///
     /// # Time complexity
     ///
@@ -136,15 +180,17 @@ impl<T: Group + Commutative> FenwickTree<T> {
         let mut res = 0;
         let mut sum = T::identity();
 
-        // start from the largest block
+        // start from the largest block.
+        // `data[N]` is the last one, so `get` skips blocks beyond `N` and `res` never exceeds `N`.
         for d in (0..=self.data.len().ilog2()).rev() {
             if let Some(block) = self.data.get(res + (1 << d)) {
-                if pred(sum.bin_op(&block)) {
+                if pred(sum.bin_op(block)) {
                     res += 1 << d;
-                    sum = sum.bin_op(&block)
+                    sum = sum.bin_op(block)
                 }
             }
         }
+        debug_assert!(res < self.data.len());
 
         res
     }
@@ -172,3 +218,32 @@ impl<T: Group + Commutative> FromIterator<T> for FenwickTree<T> {
         Self { data }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use math_traits::monoids::Add;
+
+    use super::*;
+
+    #[test]
+    fn partition_point_boundaries() {
+        for n in 0..=33 {
+            let ft = FenwickTree::from_iter((0..n).map(|i| Add(i as u64 % 3 + 1)));
+            let prefix = Vec::from_iter((1..=n).map(|i| ft.prefix_query(i).0));
+
+            assert_eq!(ft.partition_point(|_| true), n);
+            assert_eq!(ft.partition_point(|_| false), 0);
+            if let Some(&total) = prefix.last() {
+                // crosses exactly at the last element
+                assert_eq!(ft.partition_point(|Add(sum)| sum < total), n - 1);
+                assert_eq!(ft.partition_point(|Add(sum)| sum <= total), n);
+            }
+            for threshold in 0..=prefix.last().copied().unwrap_or(0) + 1 {
+                assert_eq!(
+                    ft.partition_point(|Add(sum)| sum <= threshold),
+                    prefix.partition_point(|&sum| sum <= threshold),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::ops::RangeBounds;
+
+use math_traits::Group;
+
+/// A variant of [`FenwickTree`](crate::FenwickTree) which does not assume commutativity.
+///
+/// Each node stores the product of its block *in order*, and the raw elements are kept as well,
+/// so that a node can be recomputed from smaller nodes when an element changes.
+/// The inverse is used only in [`range_query`](Self::range_query).
+///
+/// # Example
+///
+/// ```
+/// use fenwick_tree::NonCommutativeFenwickTree;
+/// use math_traits::Group;
+///
+/// /// affine map `x -> a * x + b` over rationals, composed left to right
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Affine(f64, f64);
+///
+/// impl Group for Affine {
+///     fn identity() -> Self {
+///         Affine(1.0, 0.0)
+///     }
+///
+///     // apply `self`, then `rhs`
+///     fn bin_op(&self, rhs: &Self) -> Self {
+///         Affine(rhs.0 * self.0, rhs.0 * self.1 + rhs.1)
+///     }
+///
+///     fn inverse(&self) -> Self {
+///         Affine(1.0 / self.0, -self.1 / self.0)
+///     }
+/// }
+///
+/// let mut ft = NonCommutativeFenwickTree::from_iter([Affine(2.0, 0.0), Affine(1.0, 3.0), Affine(4.0, 0.0)]);
+/// // ((x * 2) + 3) * 4
+/// assert_eq!(ft.prefix_query(3), Affine(8.0, 12.0));
+///
+/// ft.point_set(0, Affine(1.0, 1.0));
+/// // ((x + 1) + 3) * 4
+/// assert_eq!(ft.prefix_query(3), Affine(4.0, 16.0));
+/// assert_eq!(ft.range_query(1..), Affine(4.0, 12.0));
+/// ```
+///
+/// # Performance note
+///
+/// | [from_iter](NonCommutativeFenwickTree::from_iter) | [point_set](NonCommutativeFenwickTree::point_set) | [prefix_query](NonCommutativeFenwickTree::prefix_query)/[range_query](NonCommutativeFenwickTree::range_query) |
+/// |---------------------------------------------------|---------------------------------------------------|---------------------------------------------------------------------------------------------------------------|
+/// | *O*(*N*)                                          | *O*((log *N*)²)                                   | *O*(log *N*)                                                                                                  |
+#[derive(Debug, Clone)]
+pub struct NonCommutativeFenwickTree<T: Group + Clone> {
+    /// one-based indexing internally (`raw[0]` and `data[0]` are unused)
+    raw: Vec<T>,
+    /// `data[i]` is the product over `(i - LSSB(i), i]` from left to right
+    data: Vec<T>,
+}
+
+impl<T: Group + Clone> NonCommutativeFenwickTree<T> {
+    /// Creates a new instance initialized with [`Group::identity`].
+    #[must_use]
+    pub fn new(n: usize) -> Self {
+        Self::from_iter(std::iter::repeat_with(T::identity).take(n))
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.raw.len() - 1
+    }
+
+    /// Returns the `i`-th element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given index is out of bounds.
+    pub fn point_query(&self, i: usize) -> &T {
+        assert!(i < self.len(), "index out of bounds");
+        &self.raw[i + 1]
+    }
+
+    /// Replaces the `i`-th element with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given index is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*((log *N*)²)
+    pub fn point_set(&mut self, i: usize, value: T) {
+        assert!(i < self.len(), "index out of bounds");
+
+        // one-based indexing
+        let mut i = i + 1;
+        self.raw[i] = value;
+        while i < self.data.len() {
+            self.recompute(i);
+            // add LSSB
+            i += i & i.wrapping_neg()
+        }
+    }
+
+    /// Performs `a[i] <- a[i] ∘ elem`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given index is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*((log *N*)²)
+    pub fn point_update(&mut self, i: usize, elem: T) {
+        let value = self.point_query(i).bin_op(&elem);
+        self.point_set(i, value)
+    }
+
+    /// Returns `a[0] ∘ a[1] ∘ ... ∘ a[i - 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given index is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    #[must_use]
+    pub fn prefix_query(&self, mut i: usize) -> T {
+        assert!(i < self.data.len(), "index out of bounds");
+
+        // blocks are visited from right to left
+        let mut res = T::identity();
+        while i > 0 {
+            res = self.data[i].bin_op(&res);
+            // remove LSSB
+            i &= i.wrapping_sub(1)
+        }
+
+        res
+    }
+
+    /// Returns `a[l] ∘ ... ∘ a[r - 1]`, computed as `prefix_query(l)⁻¹ ∘ prefix_query(r)`.
+    ///
+    /// If the given range is empty, then returns [`Group::identity`].
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    #[must_use]
+    pub fn range_query<R>(&self, range: R) -> T
+    where
+        R: RangeBounds<usize>,
+    {
+        let l = match range.start_bound() {
+            std::ops::Bound::Included(l) => *l,
+            std::ops::Bound::Excluded(l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Included(r) => r + 1,
+            std::ops::Bound::Excluded(r) => *r,
+            std::ops::Bound::Unbounded => self.len(),
+        };
+
+        if l >= r {
+            return T::identity();
+        }
+
+        self.prefix_query(l).inverse().bin_op(&self.prefix_query(r))
+    }
+
+    /// Recomputes `data[i]` from `raw[i]` and smaller nodes.
+    fn recompute(&mut self, i: usize) {
+        // (i - LSSB(i), i - 1] is covered by nodes `i - 1`, `(i - 1) - LSSB(i - 1)`, ...
+        let start = i & i.wrapping_sub(1);
+        let mut res = self.raw[i].clone();
+        let mut j = i - 1;
+        while j > start {
+            res = self.data[j].bin_op(&res);
+            j &= j.wrapping_sub(1)
+        }
+
+        self.data[i] = res
+    }
+}
+
+impl<T: Group + Clone> FromIterator<T> for NonCommutativeFenwickTree<T> {
+    /// Creates a new instance initialized with the given values.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut raw = vec![T::identity()];
+        raw.extend(iter);
+        let mut res = Self {
+            data: raw.clone(),
+            raw,
+        };
+        for i in 1..res.data.len() {
+            res.recompute(i)
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    /// permutation of 4 elements, where `a.bin_op(b)` applies `a` first
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Perm([usize; 4]);
+
+    impl Group for Perm {
+        fn identity() -> Self {
+            Self([0, 1, 2, 3])
+        }
+
+        fn bin_op(&self, rhs: &Self) -> Self {
+            Self(self.0.map(|i| rhs.0[i]))
+        }
+
+        fn inverse(&self) -> Self {
+            let mut inv = [0; 4];
+            for (i, &p) in self.0.iter().enumerate() {
+                inv[p] = i
+            }
+            Self(inv)
+        }
+    }
+
+    fn fold(values: &[Perm]) -> Perm {
+        values.iter().fold(Perm::identity(), |acc, x| acc.bin_op(x))
+    }
+
+    #[test]
+    fn prefix_and_range_queries_keep_order() {
+        let mut rng = xorshift(0x3c6e_f372);
+        let mut next = move || rng() as usize;
+        let random_perm = |next: &mut dyn FnMut() -> usize| {
+            let mut p = [0, 1, 2, 3];
+            for i in (1..4).rev() {
+                p.swap(i, next() % (i + 1))
+            }
+            Perm(p)
+        };
+
+        // the group is non-commutative
+        assert_ne!(
+            Perm([1, 0, 2, 3]).bin_op(&Perm([0, 2, 1, 3])),
+            Perm([0, 2, 1, 3]).bin_op(&Perm([1, 0, 2, 3]))
+        );
+
+        for n in [0, 1, 2, 5, 8, 13, 32] {
+            let mut naive = Vec::from_iter((0..n).map(|_| random_perm(&mut next)));
+            let mut ft = NonCommutativeFenwickTree::from_iter(naive.iter().copied());
+            assert_eq!(ft.len(), n);
+
+            for _ in 0..30 {
+                for i in 0..=n {
+                    assert_eq!(ft.prefix_query(i), fold(&naive[..i]));
+                }
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(ft.range_query(l..r), fold(&naive[l..r]));
+                    }
+                }
+
+                if n > 0 {
+                    let i = next() % n;
+                    let p = random_perm(&mut next);
+                    if next() % 2 == 0 {
+                        ft.point_set(i, p);
+                        naive[i] = p;
+                    } else {
+                        ft.point_update(i, p);
+                        naive[i] = naive[i].bin_op(&p);
+                    }
+                    assert_eq!(ft.point_query(i), &naive[i]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::VecDeque;
+
+use csr::CompiledCSR;
+
+/// Returns the number of edges on a shortest path from `source` to each node,
+/// or `None` if the node is unreachable.
+///
+/// Edge weights are ignored.
+///
+/// # Panics
+///
+/// Panics if `source` is out of bounds.
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn bfs<N, E>(graph: &CompiledCSR<N, E>, source: usize) -> Vec<Option<u32>> {
+    bfs_multi_source(graph, &[source])
+}
+
+/// Returns the number of edges on a shortest path from the nearest source to each node,
+/// or `None` if the node is unreachable from all the sources.
+///
+/// # Example
+///
+/// ```
+/// use csr::CSR;
+/// use graph::bfs_multi_source;
+///
+/// // 0 - 1 - 2 - 3 - 4
+/// let mut csr = CSR::with_capacity(5, 8);
+/// (0..5).for_each(|_| {
+///     csr.push_node(());
+/// });
+/// for i in 0..4 {
+///     csr.push_edge(i, i + 1, ());
+///     csr.push_edge(i + 1, i, ());
+/// }
+/// let graph = csr.build();
+///
+/// assert_eq!(
+///     bfs_multi_source(&graph, &[0, 4]),
+///     vec![Some(0), Some(1), Some(2), Some(1), Some(0)]
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if some source is out of bounds.
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn bfs_multi_source<N, E>(graph: &CompiledCSR<N, E>, sources: &[usize]) -> Vec<Option<u32>> {
+    let mut dist = vec![None; graph.num_nodes()];
+    let mut queue = VecDeque::with_capacity(graph.num_nodes());
+    for &s in sources {
+        if dist[s].is_none() {
+            dist[s] = Some(0);
+            queue.push_back(s);
+        }
+    }
+
+    while let Some(u) = queue.pop_front() {
+        let du = dist[u].unwrap();
+        for (v, _) in graph.neighbors(u) {
+            if dist[v].is_none() {
+                dist[v] = Some(du + 1);
+                queue.push_back(v);
+            }
+        }
+    }
+
+    dist
+}
+
+/// Returns the length of a shortest path from `source` to each node, or `None` if the node is unreachable,
+/// where every edge weighs either `0` or `1`.
+///
+/// # Panics
+///
+/// Panics if `source` is out of bounds or some reachable edge weighs more than `1`.
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn bfs_01<N, E>(graph: &CompiledCSR<N, E>, source: usize) -> Vec<Option<u64>>
+where
+    E: Copy + Into<u64>,
+{
+    let mut dist: Vec<Option<u64>> = vec![None; graph.num_nodes()];
+    let mut done = vec![false; graph.num_nodes()];
+    let mut deque = VecDeque::with_capacity(graph.num_nodes());
+    dist[source] = Some(0);
+    deque.push_back(source);
+
+    // labels in the deque differ by at most 1, so each node enters it at most twice
+    while let Some(u) = deque.pop_front() {
+        if std::mem::replace(&mut done[u], true) {
+            continue;
+        }
+
+        let du = dist[u].unwrap();
+        for (v, &w) in graph.neighbors(u) {
+            let w: u64 = w.into();
+            assert!(w <= 1, "edge weight should be 0 or 1, but got {}", w);
+
+            if dist[v].map_or(true, |dv| du + w < dv) {
+                dist[v] = Some(du + w);
+                if w == 0 {
+                    deque.push_front(v)
+                } else {
+                    deque.push_back(v)
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use csr::CSR;
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::Dijkstra;
+
+    /// `h * w` grid graph where `blocked` cells have no edges.
+    fn grid(h: usize, w: usize, blocked: &[usize]) -> CompiledCSR<(), u32> {
+        let mut csr = CSR::with_capacity(h * w, 4 * h * w);
+        (0..h * w).for_each(|_| {
+            csr.push_node(());
+        });
+        let open = |i: usize| !blocked.contains(&i);
+        for i in 0..h {
+            for j in 0..w {
+                let u = i * w + j;
+                if i + 1 < h && open(u) && open(u + w) {
+                    csr.push_edge(u, u + w, 1);
+                    csr.push_edge(u + w, u, 1);
+                }
+                if j + 1 < w && open(u) && open(u + 1) {
+                    csr.push_edge(u, u + 1, 1);
+                    csr.push_edge(u + 1, u, 1);
+                }
+            }
+        }
+        csr.build()
+    }
+
+    #[test]
+    fn grid_against_dijkstra() {
+        let (h, w) = (6, 7);
+        // a wall with a single gap at the bottom, and an isolated cell
+        let blocked = [3, 10, 17, 24, 31, 6];
+        let graph = grid(h, w, &blocked);
+
+        for source in [0, 20, 41] {
+            let dijkstra = Dijkstra::new(&graph, source);
+            let dist = bfs(&graph, source);
+            for (v, &d) in dist.iter().enumerate() {
+                assert_eq!(d, dijkstra.distance(v), "source = {source}, v = {v}");
+            }
+        }
+        // around the wall
+        assert_eq!(bfs(&graph, 0)[4], Some(14));
+    }
+
+    #[test]
+    fn multi_source() {
+        let (h, w) = (5, 5);
+        let graph = grid(h, w, &[]);
+        let sources = [0, 24, 12, 12];
+
+        let dist = bfs_multi_source(&graph, &sources);
+        for (v, &d) in dist.iter().enumerate() {
+            let expected = sources
+                .iter()
+                .map(|&s| Dijkstra::new(&graph, s).distance(v).unwrap())
+                .min();
+            assert_eq!(d, expected);
+        }
+        assert!(bfs_multi_source(&graph, &[]).iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn zero_one_against_dijkstra() {
+        let mut next = xorshift(0xb0f5);
+
+        for _ in 0..100 {
+            let n = 1 + next() as usize % 15;
+            let m = next() as usize % 50;
+            let mut csr = CSR::with_capacity(n, m);
+            (0..n).for_each(|_| {
+                csr.push_node(());
+            });
+            for _ in 0..m {
+                csr.push_edge(next() as usize % n, next() as usize % n, next() % 2);
+            }
+            let graph = csr.build();
+
+            let source = next() as usize % n;
+            let dijkstra = Dijkstra::new(&graph, source);
+            let dist = bfs_01(&graph, source);
+            for (v, &d) in dist.iter().enumerate() {
+                assert_eq!(d, dijkstra.distance(v));
+            }
+        }
+    }
+
+    #[test]
+    fn zero_one_bool_weights() {
+        // 0 -(1)-> 1 -(0)-> 2, 0 -(1)-> 2 -(0)-> 3
+        let mut csr = CSR::with_capacity(4, 4);
+        (0..4).for_each(|_| {
+            csr.push_node(());
+        });
+        csr.push_edge(0, 1, true);
+        csr.push_edge(1, 2, false);
+        csr.push_edge(0, 2, true);
+        csr.push_edge(2, 3, false);
+
+        assert_eq!(
+            bfs_01(&csr.build(), 0),
+            [Some(0), Some(1), Some(1), Some(1)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_one_invalid_weight() {
+        let mut csr = CSR::with_capacity(2, 1);
+        csr.push_node(());
+        csr.push_node(());
+        csr.push_edge(0, 1, 2_u32);
+
+        bfs_01(&csr.build(), 0);
+    }
+}
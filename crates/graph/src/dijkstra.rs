@@ -0,0 +1,317 @@
+use std::{cmp::Reverse, collections::BinaryHeap, ops::Add};
+
+use csr::CompiledCSR;
+
+/// Single-source shortest paths on a graph with non-negative edge weights.
+///
+/// `W::default()` is treated as zero.
+///
+/// # Example
+///
+/// ```
+/// use csr::CSR;
+/// use graph::Dijkstra;
+///
+/// let mut csr = CSR::with_capacity(4, 4);
+/// (0..4).for_each(|_| {
+///     csr.push_node(());
+/// });
+/// csr.push_edge(0, 1, 5_u64);
+/// csr.push_edge(0, 2, 1);
+/// csr.push_edge(2, 1, 2);
+/// let graph = csr.build();
+///
+/// let dijkstra = Dijkstra::new(&graph, 0);
+/// assert_eq!(dijkstra.distance(1), Some(3));
+/// assert_eq!(dijkstra.path_to(1), Some(vec![0, 2, 1]));
+/// assert_eq!(dijkstra.distance(3), None);
+/// assert_eq!(dijkstra.path_to(3), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dijkstra<W> {
+    source: usize,
+    distance: Vec<Option<W>>,
+    /// the previous node on a shortest path, or [NULL](Self::NULL)
+    parent: Vec<usize>,
+}
+
+impl<W> Dijkstra<W>
+where
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    const NULL: usize = !0;
+
+    /// Computes the shortest-path tree rooted at `source`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*((*N* + *M*) log *M*)
+    pub fn new<N>(graph: &CompiledCSR<N, W>, source: usize) -> Self {
+        let n = graph.num_nodes();
+        let mut distance = vec![None; n];
+        let mut parent = vec![Self::NULL; n];
+        distance[source] = Some(W::default());
+
+        let mut heap = BinaryHeap::with_capacity(n);
+        heap.push(Reverse((W::default(), source)));
+        while let Some(Reverse((d, u))) = heap.pop() {
+            // lazy deletion
+            if distance[u] != Some(d) {
+                continue;
+            }
+
+            for (v, &w) in graph.neighbors(u) {
+                let dv = d + w;
+                if distance[v].map_or(true, |old| dv < old) {
+                    distance[v] = Some(dv);
+                    parent[v] = u;
+                    heap.push(Reverse((dv, v)));
+                }
+            }
+        }
+
+        Self {
+            source,
+            distance,
+            parent,
+        }
+    }
+
+    /// Computes the shortest-path tree rooted at `source` on a dense graph,
+    /// where `adj[u][v]` is the weight of the edge from `u` to `v`, if any.
+    ///
+    /// This is faster than [`new`](Self::new) if the graph has about *N*² edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph::Dijkstra;
+    ///
+    /// let adj = vec![
+    ///     vec![None, Some(5_u64), Some(1)],
+    ///     vec![None, None, None],
+    ///     vec![None, Some(2), None],
+    /// ];
+    /// let dijkstra = Dijkstra::dense(&adj, 0);
+    /// assert_eq!(dijkstra.distance(1), Some(3));
+    /// assert_eq!(dijkstra.path_to(1), Some(vec![0, 2, 1]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is out of bounds or some row of `adj` is shorter than `adj`.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*²)
+    pub fn dense(adj: &[Vec<Option<W>>], source: usize) -> Self {
+        let n = adj.len();
+        let mut distance = vec![None; n];
+        let mut parent = vec![Self::NULL; n];
+        let mut visited = vec![false; n];
+        distance[source] = Some(W::default());
+
+        loop {
+            // the nearest unvisited node
+            let Some((u, d)) = (0..n)
+                .filter(|&v| !visited[v])
+                .filter_map(|v| distance[v].map(|d| (v, d)))
+                .min_by_key(|&(_, d)| d)
+            else {
+                break;
+            };
+            visited[u] = true;
+
+            for (v, &w) in adj[u][..n].iter().enumerate() {
+                let Some(w) = w else { continue };
+                let dv = d + w;
+                if !visited[v] && distance[v].map_or(true, |old| dv < old) {
+                    distance[v] = Some(dv);
+                    parent[v] = u;
+                }
+            }
+        }
+
+        Self {
+            source,
+            distance,
+            parent,
+        }
+    }
+
+    pub const fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Returns the length of a shortest path to `target`, or `None` if it is unreachable.
+    pub fn distance(&self, target: usize) -> Option<W> {
+        self.distance.get(target).and_then(|&d| d)
+    }
+
+    /// Returns the nodes on a shortest path from the source to `target`, both inclusive,
+    /// or `None` if `target` is unreachable.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(length of the path)
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance(target)?;
+
+        let mut path = vec![target];
+        while let Some(&p) = self.parent.get(*path.last().unwrap()) {
+            if p == Self::NULL {
+                break;
+            }
+            path.push(p)
+        }
+        path.reverse();
+        debug_assert_eq!(path[0], self.source);
+
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use csr::CSR;
+    use test_utils::xorshift;
+
+    use super::*;
+
+    /// the weight of the lightest edge from `u` to `v`
+    fn weight(edges: &[(usize, usize, u64)], u: usize, v: usize) -> u64 {
+        edges
+            .iter()
+            .filter(|&&(s, t, _)| (s, t) == (u, v))
+            .map(|&(_, _, w)| w)
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn path_weights_match_distance() {
+        let edges = [
+            (0, 1, 7),
+            (0, 2, 9),
+            (0, 5, 14),
+            (1, 2, 10),
+            (1, 3, 15),
+            (2, 3, 11),
+            (2, 5, 2),
+            (3, 4, 6),
+            (5, 4, 9),
+            (4, 0, 1),
+            (6, 0, 1),
+        ];
+        let graph = CSR::from_edges(vec![(); 7], edges).build();
+        let dijkstra = Dijkstra::new(&graph, 0);
+
+        let expected = [
+            Some(0),
+            Some(7),
+            Some(9),
+            Some(20),
+            Some(20),
+            Some(11),
+            None,
+        ];
+        for (v, &d) in expected.iter().enumerate() {
+            assert_eq!(dijkstra.distance(v), d);
+
+            let path = dijkstra.path_to(v);
+            assert_eq!(path.is_some(), d.is_some());
+            if let Some(path) = path {
+                assert_eq!(path.first(), Some(&0));
+                assert_eq!(path.last(), Some(&v));
+                let sum: u64 = path.windows(2).map(|e| weight(&edges, e[0], e[1])).sum();
+                assert_eq!(Some(sum), d);
+            }
+        }
+        assert_eq!(dijkstra.path_to(0), Some(vec![0]));
+        assert_eq!(dijkstra.path_to(100), None);
+    }
+
+    #[test]
+    fn dense_matches_heap_on_complete_graphs() {
+        let mut next = xorshift(0xc0ffee);
+
+        for n in 1..=30 {
+            let mut adj = vec![vec![None; n]; n];
+            let mut edges = Vec::with_capacity(n * n);
+            for (u, row) in adj.iter_mut().enumerate() {
+                for (v, w) in row.iter_mut().enumerate() {
+                    if u != v {
+                        let weight = next() % 1000;
+                        *w = Some(weight);
+                        edges.push((u, v, weight));
+                    }
+                }
+            }
+            let graph = CSR::from_edges(vec![(); n], edges.iter().copied()).build();
+
+            for source in 0..n {
+                let dense = Dijkstra::dense(&adj, source);
+                let heap = Dijkstra::new(&graph, source);
+                for v in 0..n {
+                    assert_eq!(dense.distance(v), heap.distance(v));
+                    let path = dense.path_to(v).unwrap();
+                    let sum: u64 = path.windows(2).map(|e| weight(&edges, e[0], e[1])).sum();
+                    assert_eq!(dense.distance(v), Some(sum));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dense_unreachable() {
+        let adj = vec![
+            vec![None, Some(1_u64), None],
+            vec![None; 3],
+            vec![Some(1), None, None],
+        ];
+        let dijkstra = Dijkstra::dense(&adj, 0);
+        assert_eq!(dijkstra.distance(1), Some(1));
+        assert_eq!(dijkstra.distance(2), None);
+        assert_eq!(dijkstra.path_to(2), None);
+    }
+
+    #[test]
+    fn random_against_bellman_ford() {
+        let mut next = xorshift(0x5eed);
+
+        for _ in 0..50 {
+            let n = 1 + next() as usize % 12;
+            let m = next() as usize % 40;
+            let edges = Vec::from_iter(
+                (0..m).map(|_| (next() as usize % n, next() as usize % n, next() % 10)),
+            );
+            let graph = CSR::from_edges(vec![(); n], edges.iter().copied()).build();
+            let source = next() as usize % n;
+            let dijkstra = Dijkstra::new(&graph, source);
+
+            let mut dist = vec![None; n];
+            dist[source] = Some(0);
+            for _ in 0..n {
+                for &(u, v, w) in &edges {
+                    if let Some(du) = dist[u] {
+                        if dist[v].map_or(true, |dv| du + w < dv) {
+                            dist[v] = Some(du + w);
+                        }
+                    }
+                }
+            }
+
+            for (v, &d) in dist.iter().enumerate() {
+                assert_eq!(dijkstra.distance(v), d);
+                if let Some(path) = dijkstra.path_to(v) {
+                    let sum: u64 = path.windows(2).map(|e| weight(&edges, e[0], e[1])).sum();
+                    assert_eq!(Some(sum), d);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+
+use csr::CompiledCSR;
+
+/// Returns the length of the shortest cycle in the undirected graph, or `None` if it is a forest.
+///
+/// Each undirected edge should be stored in both directions.
+/// The graph is assumed to have neither self-loops nor multiple edges.
+///
+/// # Time complexity
+///
+/// *O*(*NM*)
+pub fn girth<N, E>(graph: &CompiledCSR<N, E>) -> Option<usize> {
+    const NULL: usize = !0;
+    let n = graph.num_nodes();
+
+    let mut res = None;
+    let mut dist = vec![NULL; n];
+    let mut parent = vec![NULL; n];
+    let mut queue = VecDeque::with_capacity(n);
+    for s in 0..n {
+        dist.fill(NULL);
+        dist[s] = 0;
+        parent[s] = NULL;
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            // no shorter cycle will be found from `s`
+            if res.map_or(false, |res| 2 * dist[u] + 1 >= res) {
+                queue.clear();
+                break;
+            }
+
+            for (v, _) in graph.neighbors(u) {
+                if dist[v] == NULL {
+                    dist[v] = dist[u] + 1;
+                    parent[v] = u;
+                    queue.push_back(v);
+                } else if parent[u] != v {
+                    // a non-tree edge closes a cycle through `s` (or a shorter one)
+                    let len = dist[u] + dist[v] + 1;
+                    res = Some(res.map_or(len, |res: usize| res.min(len)));
+                }
+            }
+        }
+    }
+
+    res
+}
+
+/// Returns the length of the shortest directed cycle, or `None` if the graph is acyclic.
+///
+/// A self-loop is a cycle of length `1`.
+///
+/// # Time complexity
+///
+/// *O*(*NM*)
+pub fn girth_directed<N, E>(graph: &CompiledCSR<N, E>) -> Option<usize> {
+    const NULL: usize = !0;
+    let n = graph.num_nodes();
+
+    let mut res: Option<usize> = None;
+    let mut dist = vec![NULL; n];
+    let mut queue = VecDeque::with_capacity(n);
+    'search: for s in 0..n {
+        dist.fill(NULL);
+        dist[s] = 0;
+        queue.clear();
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            if res.map_or(false, |res| dist[u] + 1 >= res) {
+                continue 'search;
+            }
+
+            for (v, _) in graph.neighbors(u) {
+                if v == s {
+                    // BFS visits `u` in ascending order of distance
+                    res = Some(dist[u] + 1);
+                    continue 'search;
+                }
+                if dist[v] == NULL {
+                    dist[v] = dist[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_graphs::{directed, undirected};
+
+    #[test]
+    fn undirected_girth() {
+        // a triangle with a tail and a square
+        let graph = undirected(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 2),
+                (5, 6),
+                (6, 7),
+                (1, 5),
+                (7, 0),
+            ],
+        );
+        assert_eq!(girth(&graph), Some(3));
+
+        // an odd cycle and an even cycle
+        assert_eq!(
+            girth(&undirected(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)])),
+            Some(5)
+        );
+        assert_eq!(
+            girth(&undirected(4, &[(0, 1), (1, 2), (2, 3), (3, 0)])),
+            Some(4)
+        );
+        // K_4 minus an edge contains triangles
+        assert_eq!(
+            girth(&undirected(4, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)])),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn forest() {
+        assert_eq!(girth(&undirected(0, &[])), None);
+        assert_eq!(girth(&undirected(1, &[])), None);
+        // a tree and an isolated node
+        let graph = undirected(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+        assert_eq!(girth(&graph), None);
+    }
+
+    #[test]
+    fn directed_girth() {
+        // acyclic as a directed graph, though its underlying graph has cycles
+        let dag = directed(4, &[(0, 1), (0, 2), (1, 3), (2, 3), (0, 3)]);
+        assert_eq!(girth_directed(&dag), None);
+
+        let graph = directed(6, &[(0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5), (5, 3)]);
+        assert_eq!(girth_directed(&graph), Some(3));
+
+        // two-way edge and self-loop
+        assert_eq!(girth_directed(&directed(2, &[(0, 1), (1, 0)])), Some(2));
+        assert_eq!(girth_directed(&directed(3, &[(0, 1), (2, 2)])), Some(1));
+    }
+}
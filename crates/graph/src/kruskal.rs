@@ -0,0 +1,85 @@
+use union_find::UnionFind;
+
+/// Returns the total weight and edges of a minimum spanning tree, or `None` if the graph is disconnected.
+///
+/// Each edge is `(weight, u, v)`. `edges` is sorted in place, so a `&mut Vec` can be passed as well.
+/// Among edges of the same weight, ones with smaller endpoints are preferred.
+///
+/// # Example
+///
+/// ```
+/// use graph::kruskal;
+///
+/// let mut edges = vec![(3, 0, 1), (1, 1, 2), (2, 0, 2), (5, 2, 3)];
+///
+/// assert_eq!(kruskal(&mut edges, 4), Some((8, vec![(1, 2), (0, 2), (2, 3)])));
+/// assert_eq!(kruskal(&mut edges, 5), None);
+/// ```
+///
+/// # Panics
+///
+/// Panics if some edge refers to a node which is not less than `n`.
+///
+/// # Time complexity
+///
+/// *O*(*M* log *M*)
+pub fn kruskal(edges: &mut [(u64, usize, usize)], n: usize) -> Option<(u64, Vec<(usize, usize)>)> {
+    edges.sort_unstable();
+
+    let mut uf = UnionFind::new(n);
+    let mut weight = 0;
+    let mut tree = Vec::with_capacity(n.saturating_sub(1));
+    for &(w, u, v) in edges.iter() {
+        if uf.unite(u, v) {
+            weight += w;
+            tree.push((u, v));
+        }
+    }
+
+    if tree.len() + 1 >= n {
+        Some((weight, tree))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_mst() {
+        // https://en.wikipedia.org/wiki/Kruskal%27s_algorithm#Example
+        let (a, b, c, d, e, f, g) = (0, 1, 2, 3, 4, 5, 6);
+        let mut edges = vec![
+            (7, a, b),
+            (5, a, d),
+            (8, b, c),
+            (9, b, d),
+            (7, b, e),
+            (5, c, e),
+            (15, d, e),
+            (6, d, f),
+            (8, e, f),
+            (9, e, g),
+            (11, f, g),
+        ];
+
+        let (weight, tree) = kruskal(&mut edges, 7).unwrap();
+        assert_eq!(weight, 39);
+        assert_eq!(tree, [(a, d), (c, e), (d, f), (a, b), (b, e), (e, g)]);
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut edges = vec![(1, 0, 1), (2, 2, 3), (3, 1, 0)];
+        assert_eq!(kruskal(&mut edges, 4), None);
+        assert_eq!(kruskal(&mut [], 2), None);
+    }
+
+    #[test]
+    fn trivial() {
+        assert_eq!(kruskal(&mut [], 0), Some((0, vec![])));
+        assert_eq!(kruskal(&mut [(10, 0, 0)], 1), Some((0, vec![])));
+    }
+}
@@ -0,0 +1,35 @@
+//! A collection of graph algorithms.
+mod bfs;
+mod dijkstra;
+mod girth;
+mod kruskal;
+mod lowlink;
+mod manhattan_mst;
+mod negative_cycle;
+mod scc;
+mod topological_sort;
+
+pub use bfs::{bfs, bfs_01, bfs_multi_source};
+pub use dijkstra::Dijkstra;
+pub use girth::{girth, girth_directed};
+pub use kruskal::kruskal;
+pub use lowlink::{articulation_points, bridges};
+pub use manhattan_mst::manhattan_mst;
+pub use negative_cycle::find_negative_cycle_from;
+pub use scc::scc;
+pub use topological_sort::topological_sort;
+
+#[cfg(test)]
+mod test_graphs {
+    use csr::{CompiledCSR, CSR};
+
+    /// Unweighted directed graph on `0..n`.
+    pub(crate) fn directed(n: usize, edges: &[(usize, usize)]) -> CompiledCSR<(), ()> {
+        CSR::from_edges(vec![(); n], edges.iter().map(|&(u, v)| (u, v, ()))).build()
+    }
+
+    /// Unweighted undirected graph on `0..n`.
+    pub(crate) fn undirected(n: usize, edges: &[(usize, usize)]) -> CompiledCSR<(), ()> {
+        CSR::from_edges_undirected(vec![(); n], edges.iter().map(|&(u, v)| (u, v, ()))).build()
+    }
+}
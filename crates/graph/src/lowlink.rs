@@ -0,0 +1,205 @@
+use csr::CompiledCSR;
+
+/// Returns the bridges of the undirected graph as `(u, v)` with `u < v`, in lexicographic order.
+///
+/// Each undirected edge should be stored in both directions. Parallel edges are never bridges.
+///
+/// # Example
+///
+/// ```
+/// use csr::CSR;
+/// use graph::bridges;
+///
+/// // triangle 0-1-2 with a tail 2-3
+/// let mut csr = CSR::with_capacity(4, 8);
+/// (0..4).for_each(|_| {
+///     csr.push_node(());
+/// });
+/// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+///     csr.push_edge(u, v, ());
+///     csr.push_edge(v, u, ());
+/// }
+///
+/// assert_eq!(bridges(&csr.build()), vec![(2, 3)]);
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn bridges<N, E>(graph: &CompiledCSR<N, E>) -> Vec<(usize, usize)> {
+    let mut res = lowlink(graph).0;
+    res.sort_unstable();
+
+    res
+}
+
+/// Returns the articulation points of the undirected graph in ascending order.
+///
+/// Each undirected edge should be stored in both directions.
+///
+/// # Example
+///
+/// ```
+/// use csr::CSR;
+/// use graph::articulation_points;
+///
+/// // triangle 0-1-2 with a tail 2-3
+/// let mut csr = CSR::with_capacity(4, 8);
+/// (0..4).for_each(|_| {
+///     csr.push_node(());
+/// });
+/// for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+///     csr.push_edge(u, v, ());
+///     csr.push_edge(v, u, ());
+/// }
+///
+/// assert_eq!(articulation_points(&csr.build()), vec![2]);
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn articulation_points<N, E>(graph: &CompiledCSR<N, E>) -> Vec<usize> {
+    let is_articulation = lowlink(graph).1;
+
+    Vec::from_iter((0..graph.num_nodes()).filter(|&u| is_articulation[u]))
+}
+
+/// Computes bridges and articulation points with a single iterative DFS.
+fn lowlink<N, E>(graph: &CompiledCSR<N, E>) -> (Vec<(usize, usize)>, Vec<bool>) {
+    const NULL: usize = !0;
+    let n = graph.num_nodes();
+
+    let mut order = vec![NULL; n];
+    let mut low = vec![NULL; n];
+    let mut is_articulation = vec![false; n];
+    let mut bridges = Vec::new();
+    let mut num_visited = 0;
+
+    // (node, parent, whether the edge to the parent has been skipped, the number of DFS children, neighbors)
+    let mut call_stack = Vec::new();
+    for s in 0..n {
+        if order[s] != NULL {
+            continue;
+        }
+
+        order[s] = num_visited;
+        low[s] = num_visited;
+        num_visited += 1;
+        call_stack.push((s, NULL, false, 0, graph.neighbors(s)));
+
+        while let Some((u, p, skipped, children, neighbors)) = call_stack.last_mut() {
+            let u = *u;
+            if let Some((v, _)) = neighbors.next() {
+                // skip exactly one copy of the tree edge, so that parallel edges count as back edges
+                if v == *p && !*skipped {
+                    *skipped = true;
+                } else if order[v] == NULL {
+                    *children += 1;
+                    order[v] = num_visited;
+                    low[v] = num_visited;
+                    num_visited += 1;
+                    call_stack.push((v, u, false, 0, graph.neighbors(v)));
+                } else {
+                    low[u] = low[u].min(order[v]);
+                }
+                continue;
+            }
+
+            let (_, p, _, children, _) = call_stack.pop().unwrap();
+            if p == NULL {
+                // the root is an articulation point iff it has two or more DFS children
+                is_articulation[u] = children >= 2;
+            } else {
+                low[p] = low[p].min(low[u]);
+                if low[u] > order[p] {
+                    bridges.push((p.min(u), p.max(u)));
+                }
+                // the root is handled separately
+                if low[u] >= order[p] && call_stack.len() >= 2 {
+                    is_articulation[p] = true;
+                }
+            }
+        }
+    }
+
+    (bridges, is_articulation)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::test_graphs::undirected;
+
+    fn num_components(n: usize, edges: &[(usize, usize)]) -> usize {
+        let mut uf = union_find::UnionFind::new(n);
+        for &(u, v) in edges {
+            uf.unite(u, v);
+        }
+        uf.groups().count()
+    }
+
+    #[test]
+    fn tree() {
+        let edges = [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (6, 5)];
+        let graph = undirected(7, &edges);
+
+        let mut expected = Vec::from_iter(edges.iter().map(|&(u, v)| (u.min(v), u.max(v))));
+        expected.sort_unstable();
+        assert_eq!(bridges(&graph), expected);
+        assert_eq!(articulation_points(&graph), [0, 1, 2, 5]);
+    }
+
+    #[test]
+    fn cycle() {
+        let n = 6;
+        let edges = Vec::from_iter((0..n).map(|i| (i, (i + 1) % n)));
+        let graph = undirected(n, &edges);
+
+        assert!(bridges(&graph).is_empty());
+        assert!(articulation_points(&graph).is_empty());
+    }
+
+    #[test]
+    fn parallel_edges() {
+        // 0 = 1 - 2 (double edge between 0 and 1)
+        let graph = undirected(3, &[(0, 1), (1, 0), (1, 2)]);
+
+        assert_eq!(bridges(&graph), [(1, 2)]);
+        assert_eq!(articulation_points(&graph), [1]);
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = xorshift(0xb41d);
+        let mut next = move || rng() as usize;
+
+        for _ in 0..200 {
+            let n = 1 + next() % 10;
+            let edges = Vec::from_iter((0..next() % 15).map(|_| (next() % n, next() % n)));
+            let graph = undirected(n, &edges);
+            let base = num_components(n, &edges);
+
+            let mut expected = Vec::new();
+            for i in 0..edges.len() {
+                let mut rest = edges.clone();
+                let (u, v) = rest.remove(i);
+                if num_components(n, &rest) > base {
+                    expected.push((u.min(v), u.max(v)));
+                }
+            }
+            expected.sort_unstable();
+            assert_eq!(bridges(&graph), expected, "{edges:?}");
+
+            let expected = Vec::from_iter((0..n).filter(|&x| {
+                let rest =
+                    Vec::from_iter(edges.iter().filter(|&&(u, v)| u != x && v != x).copied());
+                // `x` itself becomes an isolated node
+                num_components(n, &rest) > base + 1
+            }));
+            assert_eq!(articulation_points(&graph), expected, "{edges:?}");
+        }
+    }
+}
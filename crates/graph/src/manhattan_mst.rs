@@ -0,0 +1,146 @@
+use seg_lib::{Monoid, SegmentTree};
+use union_find::UnionFind;
+
+/// Returns the total weight and edges of a minimum spanning tree of the complete graph on given points,
+/// where the weight of each edge is the Manhattan distance between its endpoints.
+///
+/// Only *O*(*N*) candidate edges are generated: for each point and each octant around it,
+/// the nearest point in the octant is the only one which can be connected to the point in the MST.
+///
+/// # Time complexity
+///
+/// *O*(*N* log *N*)
+pub fn manhattan_mst(points: &[(i64, i64)]) -> (i64, Vec<(usize, usize)>) {
+    let mut candidates = candidate_edges(points);
+    candidates.sort_unstable();
+
+    let mut uf = UnionFind::new(points.len());
+    let mut weight = 0;
+    let mut edges = Vec::with_capacity(points.len().saturating_sub(1));
+    for (w, i, j) in candidates {
+        if uf.unite(i, j) {
+            weight += w;
+            edges.push((i, j));
+        }
+    }
+
+    (weight, edges)
+}
+
+/// Sweeps four octants, the other four are covered by symmetry.
+fn candidate_edges(points: &[(i64, i64)]) -> Vec<(i64, usize, usize)> {
+    let n = points.len();
+    let mut points = points.to_vec();
+    let mut candidates = Vec::with_capacity(4 * n);
+
+    let mut order = Vec::from_iter(0..n);
+    for dir in 0..4 {
+        if dir % 2 == 1 {
+            points.iter_mut().for_each(|(x, y)| std::mem::swap(x, y));
+        } else if dir == 2 {
+            points.iter_mut().for_each(|(x, _)| *x = -*x);
+        }
+
+        // for each `i`, find `j` such that `x[j] >= x[i]` and `y[j] - x[j] >= y[i] - x[i]`,
+        // which minimizes `x[j] + y[j]`.
+        order.sort_unstable_by_key(|&i| points[i]);
+        let mut keys = Vec::from_iter(points.iter().map(|&(x, y)| y - x));
+        keys.sort_unstable();
+        keys.dedup();
+
+        // prefix minimum is not a group, so `fenwick_tree` does not fit here.
+        let mut nearest = SegmentTree::<Nearest>::new(keys.len());
+        for &i in order.iter().rev() {
+            let (x, y) = points[i];
+            let key = keys.partition_point(|&k| k < y - x);
+
+            let Nearest(dist, j) = nearest.range_query(key..);
+            if j != usize::MAX {
+                candidates.push((dist - (x + y), i, j));
+            }
+            if Nearest(x + y, i) < *nearest.point_query(key) {
+                nearest.point_update(key, Nearest(x + y, i));
+            }
+        }
+    }
+
+    candidates
+}
+
+/// `(x + y, index)` of the nearest point found so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Nearest(i64, usize);
+
+impl Monoid for Nearest {
+    const IS_COMMUTATIVE: bool = true;
+
+    fn identity() -> Self {
+        Self(i64::MAX, usize::MAX)
+    }
+
+    fn binary_operation(&self, rhs: &Self) -> Self {
+        *self.min(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    fn brute_force(points: &[(i64, i64)]) -> i64 {
+        let n = points.len();
+        let mut edges = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                let ((xi, yi), (xj, yj)) = (points[i], points[j]);
+                edges.push(((xi - xj).abs() + (yi - yj).abs(), i, j));
+            }
+        }
+        edges.sort_unstable();
+
+        let mut uf = UnionFind::new(n);
+        edges
+            .into_iter()
+            .filter(|&(_, i, j)| uf.unite(i, j))
+            .map(|(w, _, _)| w)
+            .sum()
+    }
+
+    #[test]
+    fn trivial() {
+        assert_eq!(manhattan_mst(&[]), (0, vec![]));
+        assert_eq!(manhattan_mst(&[(3, -4)]), (0, vec![]));
+        assert_eq!(manhattan_mst(&[(0, 0), (0, 0)]).0, 0);
+    }
+
+    #[test]
+    fn compare_with_brute_force() {
+        let mut next = xorshift(88172645463325252);
+        let mut rand = |m: u64| (next() % m) as i64;
+
+        for n in 1..=40 {
+            for range in [3, 10, 1000] {
+                let points = Vec::from_iter((0..n).map(|_| {
+                    (
+                        rand(range) - range as i64 / 2,
+                        rand(range) - range as i64 / 2,
+                    )
+                }));
+                let (weight, edges) = manhattan_mst(&points);
+                assert_eq!(weight, brute_force(&points), "{points:?}");
+
+                // `edges` forms a spanning tree with the reported weight
+                assert_eq!(edges.len(), n - 1);
+                let mut uf = UnionFind::new(n);
+                let mut sum = 0;
+                for (i, j) in edges {
+                    assert!(uf.unite(i, j));
+                    sum += (points[i].0 - points[j].0).abs() + (points[i].1 - points[j].1).abs();
+                }
+                assert_eq!(sum, weight);
+            }
+        }
+    }
+}
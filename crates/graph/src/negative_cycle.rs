@@ -0,0 +1,133 @@
+/// Returns a negative cycle reachable from `source` as a sequence of nodes, or `None` if there is no such cycle.
+///
+/// For the returned `cycle`, there are edges from `cycle[i]` to `cycle[i + 1]` and from the last node to the first one,
+/// and the sum of their weights is negative.
+///
+/// # Panics
+///
+/// Panics if some edge or `source` refers to a node which is not less than `n`.
+///
+/// # Time complexity
+///
+/// *O*(*NM*)
+pub fn find_negative_cycle_from(
+    n: usize,
+    edges: &[(usize, usize, i64)],
+    source: usize,
+) -> Option<Vec<usize>> {
+    const NULL: usize = !0;
+    let mut dist = vec![None; n];
+    let mut pred = vec![NULL; n];
+    dist[source] = Some(0);
+
+    // Bellman-Ford. Some node is still relaxed in the n-th round iff a negative cycle is reachable.
+    let mut last_relaxed = source;
+    for _ in 0..n {
+        let mut relaxed = None;
+        for &(u, v, w) in edges {
+            if let Some(du) = dist[u] {
+                if dist[v].map_or(true, |dv| du + w < dv) {
+                    dist[v] = Some(du + w);
+                    pred[v] = u;
+                    relaxed = Some(v);
+                }
+            }
+        }
+        last_relaxed = relaxed?;
+    }
+
+    // `last_relaxed` may hang off the cycle, but going back `n` times always lands on it
+    let mut start = last_relaxed;
+    for _ in 0..n {
+        start = pred[start]
+    }
+
+    let mut cycle = vec![start];
+    let mut i = pred[start];
+    while i != start {
+        cycle.push(i);
+        i = pred[i]
+    }
+    cycle.reverse();
+
+    Some(cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    fn cycle_weight(edges: &[(usize, usize, i64)], cycle: &[usize]) -> i64 {
+        let k = cycle.len();
+        (0..k)
+            .map(|i| {
+                edges
+                    .iter()
+                    .filter(|&&(u, v, _)| u == cycle[i] && v == cycle[(i + 1) % k])
+                    .map(|&(_, _, w)| w)
+                    .min()
+                    .expect("not a cycle")
+            })
+            .sum()
+    }
+
+    #[test]
+    fn reachable_negative_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 (weights: 2 -> 3 -> 4 -> 2 sums to -1)
+        let edges = [
+            (0, 1, 5),
+            (1, 2, 1),
+            (2, 3, 2),
+            (3, 4, -4),
+            (4, 2, 1),
+            (4, 5, 10),
+        ];
+        let cycle = find_negative_cycle_from(6, &edges, 0).unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert!(cycle_weight(&edges, &cycle) < 0);
+        assert!(cycle.iter().all(|i| [2, 3, 4].contains(i)));
+
+        // self-loop with negative weight
+        let cycle = find_negative_cycle_from(2, &[(0, 1, 3), (1, 1, -1)], 0).unwrap();
+        assert_eq!(cycle, [1]);
+    }
+
+    #[test]
+    fn unreachable_negative_cycle() {
+        // 2 -> 3 -> 2 is negative but unreachable from 0
+        let edges = [(0, 1, 1), (2, 3, -5), (3, 2, 1), (2, 0, 1)];
+        assert_eq!(find_negative_cycle_from(4, &edges, 0), None);
+        assert!(find_negative_cycle_from(4, &edges, 2).is_some());
+    }
+
+    #[test]
+    fn no_negative_cycle() {
+        // negative edges but non-negative cycles
+        let edges = [(0, 1, -3), (1, 2, 2), (2, 0, 1), (1, 3, -10)];
+        assert_eq!(find_negative_cycle_from(4, &edges, 0), None);
+        assert_eq!(find_negative_cycle_from(1, &[], 0), None);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut next = xorshift(0x2545_f491_4f6c_dd1d);
+        let mut rand = |m: u64| next() % m;
+
+        for _ in 0..300 {
+            let n = rand(8) as usize + 1;
+            let m = rand(20) as usize;
+            let edges = Vec::from_iter((0..m).map(|_| {
+                (
+                    rand(n as u64) as usize,
+                    rand(n as u64) as usize,
+                    rand(20) as i64 - 6,
+                )
+            }));
+            if let Some(cycle) = find_negative_cycle_from(n, &edges, 0) {
+                assert!(cycle_weight(&edges, &cycle) < 0, "{edges:?} {cycle:?}");
+            }
+        }
+    }
+}
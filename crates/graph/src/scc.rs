@@ -0,0 +1,172 @@
+use csr::CompiledCSR;
+
+/// Returns the id of the strongly connected component of each node.
+///
+/// Ids are `0, 1, ..., k - 1`, where *k* is the number of components, and they are in
+/// reverse topological order of the condensation: for each edge from `u` to `v`,
+/// `scc[u] >= scc[v]` holds.
+///
+/// This is Tarjan's algorithm without recursion, so deep graphs do not overflow the stack.
+///
+/// # Example
+///
+/// ```
+/// use csr::CSR;
+/// use graph::scc;
+///
+/// // 0 <-> 1 -> 2 <-> 3
+/// let mut csr = CSR::with_capacity(4, 5);
+/// (0..4).for_each(|_| {
+///     csr.push_node(());
+/// });
+/// for (u, v) in [(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)] {
+///     csr.push_edge(u, v, ());
+/// }
+///
+/// assert_eq!(scc(&csr.build()), vec![1, 1, 0, 0]);
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn scc<N, E>(graph: &CompiledCSR<N, E>) -> Vec<usize> {
+    const NULL: usize = !0;
+    let n = graph.num_nodes();
+
+    let mut order = vec![NULL; n];
+    let mut low = vec![NULL; n];
+    // a visited node without an id is on the stack
+    let mut id = vec![NULL; n];
+    let mut num_visited = 0;
+    let mut num_components = 0;
+
+    let mut stack = Vec::with_capacity(n);
+    let mut call_stack = Vec::new();
+    for s in 0..n {
+        if order[s] != NULL {
+            continue;
+        }
+
+        order[s] = num_visited;
+        low[s] = num_visited;
+        num_visited += 1;
+        stack.push(s);
+        call_stack.push((s, graph.neighbors(s)));
+
+        while let Some((u, neighbors)) = call_stack.last_mut() {
+            let u = *u;
+            if let Some((v, _)) = neighbors.next() {
+                if order[v] == NULL {
+                    order[v] = num_visited;
+                    low[v] = num_visited;
+                    num_visited += 1;
+                    stack.push(v);
+                    call_stack.push((v, graph.neighbors(v)));
+                } else if id[v] == NULL {
+                    low[u] = low[u].min(order[v]);
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(p, _)) = call_stack.last() {
+                low[p] = low[p].min(low[u]);
+            }
+            // `u` is the first visited node of its component
+            if low[u] == order[u] {
+                while let Some(v) = stack.pop() {
+                    id[v] = num_components;
+                    if v == u {
+                        break;
+                    }
+                }
+                num_components += 1;
+            }
+        }
+    }
+
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::test_graphs::directed;
+
+    fn check(n: usize, edges: &[(usize, usize)], id: &[usize]) {
+        // reach[u][v] = u can reach v
+        let mut reach = vec![vec![false; n]; n];
+        for (u, row) in reach.iter_mut().enumerate() {
+            row[u] = true;
+        }
+        for &(u, v) in edges {
+            reach[u][v] = true;
+        }
+        for k in 0..n {
+            for u in 0..n {
+                for v in 0..n {
+                    reach[u][v] |= reach[u][k] && reach[k][v];
+                }
+            }
+        }
+
+        let k = id.iter().max().map_or(0, |&k| k + 1);
+        assert!((0..k).all(|c| id.contains(&c)));
+        for u in 0..n {
+            for v in 0..n {
+                assert_eq!(id[u] == id[v], reach[u][v] && reach[v][u]);
+            }
+        }
+        for &(u, v) in edges {
+            assert!(id[u] >= id[v]);
+        }
+    }
+
+    #[test]
+    fn known_components() {
+        // {0, 1, 2} -> {3, 4} -> {5}, {6} -> {5}, {7} isolated with a self-loop
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 3),
+            (4, 5),
+            (6, 5),
+            (7, 7),
+        ];
+        let id = scc(&directed(8, &edges));
+        check(8, &edges, &id);
+
+        assert_eq!(id[0], id[1]);
+        assert_eq!(id[1], id[2]);
+        assert_eq!(id[3], id[4]);
+        assert!(id[2] > id[3] && id[4] > id[5] && id[6] > id[5]);
+        assert_eq!(id.iter().max(), Some(&4));
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = xorshift(0x5cc0);
+        let mut next = move || rng() as usize;
+
+        for _ in 0..100 {
+            let n = 1 + next() % 15;
+            let edges = Vec::from_iter((0..next() % 30).map(|_| (next() % n, next() % n)));
+            check(n, &edges, &scc(&directed(n, &edges)));
+        }
+    }
+
+    #[test]
+    fn long_path() {
+        // deep enough to overflow a recursive implementation
+        let n = 1_000_000;
+        let edges = Vec::from_iter((0..n - 1).map(|i| (i, i + 1)));
+        let id = scc(&directed(n, &edges));
+
+        assert!((0..n).all(|i| id[i] == n - 1 - i));
+    }
+}
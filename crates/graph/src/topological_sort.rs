@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+use csr::CompiledCSR;
+
+/// Returns the nodes in topological order, or `None` if the graph has a directed cycle.
+///
+/// This is Kahn's algorithm. Among the nodes whose in-degree drops to zero at the same time,
+/// the result follows the order of indices at first and then the order of edges,
+/// so it is deterministic.
+///
+/// # Example
+///
+/// ```
+/// use csr::CSR;
+/// use graph::topological_sort;
+///
+/// let mut csr = CSR::with_capacity(3, 2);
+/// (0..3).for_each(|_| {
+///     csr.push_node(());
+/// });
+/// csr.push_edge(2, 0, ());
+/// csr.push_edge(0, 1, ());
+///
+/// assert_eq!(topological_sort(&csr.build()), Some(vec![2, 0, 1]));
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(*N* + *M*)
+pub fn topological_sort<N, E>(graph: &CompiledCSR<N, E>) -> Option<Vec<usize>> {
+    let n = graph.num_nodes();
+    let mut in_degree = vec![0_usize; n];
+    for u in 0..n {
+        for (v, _) in graph.neighbors(u) {
+            in_degree[v] += 1;
+        }
+    }
+
+    let mut queue = VecDeque::from_iter((0..n).filter(|&u| in_degree[u] == 0));
+    let mut order = Vec::with_capacity(n);
+    while let Some(u) = queue.pop_front() {
+        order.push(u);
+        for (v, _) in graph.neighbors(u) {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    // nodes on (or reachable from) a cycle never reach zero in-degree
+    if order.len() == n {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::test_graphs::directed;
+
+    fn is_topological_order(n: usize, edges: &[(usize, usize)], order: &[usize]) -> bool {
+        let mut position = vec![n; n];
+        for (i, &u) in order.iter().enumerate() {
+            position[u] = i;
+        }
+
+        order.len() == n
+            && position.iter().all(|&i| i < n)
+            && edges.iter().all(|&(u, v)| position[u] < position[v])
+    }
+
+    #[test]
+    fn dag() {
+        let edges = [
+            (5, 2),
+            (5, 0),
+            (4, 0),
+            (4, 1),
+            (2, 3),
+            (3, 1),
+            (0, 3),
+            (5, 3),
+        ];
+        let order = topological_sort(&directed(7, &edges)).unwrap();
+
+        assert!(is_topological_order(7, &edges, &order));
+        assert_eq!(order, [4, 5, 6, 2, 0, 3, 1]);
+        assert_eq!(topological_sort(&directed(0, &[])), Some(vec![]));
+    }
+
+    #[test]
+    fn random_dag() {
+        let mut rng = xorshift(0x7050);
+        let mut next = move || rng() as usize;
+
+        for _ in 0..100 {
+            let n = 1 + next() % 20;
+            // edges go forward along a hidden permutation
+            let mut perm = Vec::from_iter(0..n);
+            for i in (1..n).rev() {
+                perm.swap(i, next() % (i + 1));
+            }
+            let edges = Vec::from_iter((0..next() % 60).filter_map(|_| {
+                let (a, b) = (next() % n, next() % n);
+                (a < b).then_some((perm[a], perm[b]))
+            }));
+
+            let order = topological_sort(&directed(n, &edges)).unwrap();
+            assert!(is_topological_order(n, &edges, &order));
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        assert_eq!(topological_sort(&directed(1, &[(0, 0)])), None);
+        assert_eq!(
+            topological_sort(&directed(5, &[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)])),
+            None
+        );
+    }
+}
@@ -0,0 +1,652 @@
+/// A priority queue implemented with a (lazy) binomial heap, which supports efficient `push` operation.
+///
+/// This is a max heap.
+#[derive(Debug, Clone)]
+pub struct BinomialHeap<T> {
+    // `arena[0]` is the root
+    arena: Vec<Box<BinomialTree<T>>>,
+    size: usize,
+}
+
+impl<T> Default for BinomialHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BinomialHeap<T> {
+    /// Creates an empty binomial heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let heap0 = BinomialHeap::<()>::new();
+    /// assert!(heap0.is_empty());
+    ///
+    /// // more efficient way
+    /// let mut heap1 = BinomialHeap::from(vec![2, 3, 5, 7, 11]);
+    /// assert_eq!(heap1.pop(), Some(11));
+    ///
+    /// let heap2 = BinomialHeap::from_iter(0..100);
+    /// assert_eq!(heap2.size(), 100);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            arena: vec![],
+            size: 0,
+        }
+    }
+
+    /// Returns the number of elements in the binomial heap.
+    ///
+    /// Binomial Heap is a forest, so the name `len` is not suitable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::new();
+    /// assert_eq!(heap.size(), 0);
+    ///
+    /// heap.push(100);
+    /// heap.push(200);
+    ///
+    /// assert_eq!(heap.size(), 2);
+    /// ```
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Checks if the binomial heap is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::new();
+    /// assert!(heap.is_empty());
+    ///
+    /// heap.extend(vec![2, 3, 5]);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    /// Returns the minimum element, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::new();
+    ///
+    /// assert!(heap.peek().is_none());
+    ///
+    /// heap.extend(0..10);
+    ///
+    /// assert_eq!(heap.peek(), Some(&9));
+    /// assert_eq!(heap.peek(), Some(&9));
+    /// assert_eq!(heap.peek(), Some(&9));
+    /// ```
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn peek(&self) -> Option<&T> {
+        self.arena.first().map(|node| node.peek())
+    }
+
+    /// Returns an iterator visiting all elements in arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::from(vec![3, 1, 4, 1, 5]);
+    /// heap.pop();
+    ///
+    /// let mut values = Vec::from_iter(heap.iter().copied());
+    /// values.sort_unstable();
+    /// assert_eq!(values, [1, 1, 3, 4]);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*n*) in total
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut stack = Vec::from_iter(self.arena.iter().map(|node| node.as_ref()));
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.child.as_deref());
+            stack.extend(node.sibling.as_deref());
+
+            Some(node.peek())
+        })
+    }
+}
+
+impl<T: Ord> BinomialHeap<T> {
+    /// Pushes an item onto the binomial heap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::new();
+    /// assert!(heap.is_empty());
+    ///
+    /// heap.push(100);
+    /// assert_eq!(heap.peek(), Some(&100));
+    ///
+    /// heap.push(200);
+    /// assert_eq!(heap.pop(), Some(200));
+    /// assert_eq!(heap.pop(), Some(100));
+    /// assert!(heap.is_empty());
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn push(&mut self, value: T) {
+        let Self { arena, size } = self;
+
+        // lazy implementation
+        arena.push(Box::new(BinomialTree::new(value)));
+        *size += 1;
+
+        // `arena[0]` is the root
+        if arena.len() >= 2 {
+            let n = arena.len() - 1;
+            if arena[0].peek() < arena[n].peek() {
+                arena.swap(0, n);
+            }
+        }
+    }
+
+    /// Removes the maximum element, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::from_iter(5..15);
+    /// assert_eq!(heap.pop(), Some(14));
+    ///
+    /// heap.push(100);
+    /// assert_eq!(heap.peek(), Some(&100));
+    /// assert_eq!(heap.pop(), Some(100));
+    /// assert_eq!(heap.pop(), Some(13));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*), amortized.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (root, siblings) = self.arena.swap_remove(0).pop();
+        self.arena.extend(siblings);
+        self.size -= 1;
+
+        if !self.is_empty() {
+            self.consolidate();
+        }
+
+        Some(root)
+    }
+
+    /// Returns a mutable reference to the maximum element, or `None`.
+    ///
+    /// If the element is modified, the heap is restored when the returned [`PeekMut`] is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::from(vec![3, 1, 4, 1, 5]);
+    /// if let Some(mut max) = heap.peek_mut() {
+    ///     *max = 2;
+    /// }
+    /// assert_eq!(heap.pop(), Some(4));
+    /// assert_eq!(heap.into_sorted_vec(), [3, 2, 1, 1]);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1) if the element is not modified.
+    /// Otherwise, *O*((log *n*)²) to sift it down plus the cost of consolidating the forest as in [`pop`](Self::pop).
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut {
+                heap: self,
+                modified: false,
+            })
+        }
+    }
+
+    /// Merges trees of the same order, and moves the tree with the maximum root to `arena[0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is empty.
+    fn consolidate(&mut self) {
+        // melding
+        let mut new_arena = Vec::from_iter(
+            std::iter::repeat_with(|| None::<Box<BinomialTree<T>>>)
+                .take(self.size().ilog2() as usize + 1),
+        );
+        for mut one in self.arena.drain(..) {
+            loop {
+                let i = one.order();
+                if let Some(other) = std::mem::take(&mut new_arena[i]) {
+                    assert!(one.merge(*other).is_ok())
+                } else {
+                    new_arena[i] = Some(one);
+                    break;
+                }
+            }
+        }
+
+        assert!(self.arena.is_empty());
+        let mut new_arena = new_arena.into_iter().skip_while(|v| v.is_none());
+        if let Some(mut max_v) = new_arena.next().and_then(|v| v) {
+            for mut node in new_arena.flatten() {
+                if node.peek() > max_v.peek() {
+                    std::mem::swap(&mut node, &mut max_v);
+                }
+                self.arena.push(node);
+            }
+
+            // `self.arena[0]` is the root.
+            let i = self.arena.len();
+            self.arena.push(max_v);
+            self.arena.swap(i, 0);
+        }
+    }
+}
+
+/// A mutable reference to the maximum element of a [`BinomialHeap`], returned by [`BinomialHeap::peek_mut`].
+pub struct PeekMut<'a, T: Ord> {
+    heap: &'a mut BinomialHeap<T>,
+    /// whether the heap needs to be restored on drop
+    modified: bool,
+}
+
+impl<T: Ord> std::ops::Deref for PeekMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.heap.arena[0].peek()
+    }
+}
+
+impl<T: Ord> std::ops::DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.modified = true;
+        &mut self.heap.arena[0].value
+    }
+}
+
+impl<T: Ord> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        if self.modified {
+            // the root may be smaller than its children or other roots
+            self.heap.arena[0].sift_down();
+            self.heap.consolidate();
+        }
+    }
+}
+
+impl<T: Ord> BinomialHeap<T> {
+    /// Consumes the binomial heap and returns its elements in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let heap = BinomialHeap::from(vec![3, 1, 4, 1, 5]);
+    /// assert_eq!(heap.into_sorted_vec(), [5, 4, 3, 1, 1]);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*n* log *n*)
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut res = Vec::with_capacity(self.size);
+        while let Some(value) = self.pop() {
+            res.push(value)
+        }
+
+        res
+    }
+
+    /// Moves all the elements of `other` into `self`.
+    ///
+    /// Trees of the same order are not merged here but in the next [`pop`](Self::pop),
+    /// as with [`push`](Self::push).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::BinomialHeap;
+    ///
+    /// let mut heap = BinomialHeap::from(vec![1, 4, 2]);
+    /// heap.append(BinomialHeap::from(vec![3, 5]));
+    ///
+    /// assert_eq!(heap.size(), 5);
+    /// assert_eq!(
+    ///     Vec::from_iter(std::iter::from_fn(|| heap.pop())),
+    ///     vec![5, 4, 3, 2, 1],
+    /// );
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*) if both heaps have been consolidated by [`pop`](Self::pop).
+    pub fn append(&mut self, mut other: Self) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            *self = other;
+            return;
+        }
+
+        // `arena[0]` is the root
+        if self.arena[0].peek() < other.arena[0].peek() {
+            std::mem::swap(self, &mut other);
+        }
+        self.arena.append(&mut other.arena);
+        self.size += other.size;
+    }
+}
+
+impl<T: Ord> Extend<T> for BinomialHeap<T> {
+    fn extend<U: IntoIterator<Item = T>>(&mut self, iter: U) {
+        let Self { arena, size } = self;
+
+        let n = arena.len();
+        arena.extend(
+            iter.into_iter()
+                .map(|value| Box::new(BinomialTree::new(value))),
+        );
+        *size += arena.len() - n;
+
+        // `self.arena[0]` is the root.
+        if !arena.is_empty() {
+            let mut i = 0;
+            for j in n..arena.len() {
+                if arena[j].peek() > arena[i].peek() {
+                    i = j
+                }
+            }
+            arena.swap(i, 0);
+        }
+    }
+}
+
+impl<U: Ord> FromIterator<U> for BinomialHeap<U> {
+    fn from_iter<T: IntoIterator<Item = U>>(iter: T) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+
+        heap
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinomialHeap<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_iter(value)
+    }
+}
+
+/// Prioritized binomial tree.
+#[derive(Debug, Clone)]
+struct BinomialTree<T> {
+    value: T,
+    order: usize,
+    child: Option<Box<BinomialTree<T>>>,
+    sibling: Option<Box<BinomialTree<T>>>,
+}
+
+impl<T> BinomialTree<T> {
+    /// Returns singleton.
+    const fn new(value: T) -> Self {
+        Self {
+            value,
+            order: 0,
+            child: None,
+            sibling: None,
+        }
+    }
+
+    const fn order(&self) -> usize {
+        self.order
+    }
+
+    const fn peek(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Ord> BinomialTree<T> {
+    /// Returns the root and children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given nodes is invalid.
+    fn pop(self) -> (T, Vec<Box<Self>>) {
+        let Self {
+            value,
+            order,
+            mut child,
+            sibling,
+        } = self;
+
+        assert!(sibling.is_none());
+
+        let mut children = Vec::with_capacity(order);
+        while let Some(mut c) = child {
+            let sibling = std::mem::take(&mut c.sibling);
+            children.push(c);
+            child = sibling
+        }
+
+        (value, children)
+    }
+
+    /// Restores the heap order after the root value decreased.
+    fn sift_down(&mut self) {
+        let mut node = self;
+        while let Some(k) = node.largest_child() {
+            let mut child = node.child.as_deref_mut().unwrap();
+            for _ in 0..k {
+                child = child.sibling.as_deref_mut().unwrap();
+            }
+
+            std::mem::swap(&mut node.value, &mut child.value);
+            node = child;
+        }
+    }
+
+    /// Returns the position of the largest child in the list of children if it is greater than `self`.
+    fn largest_child(&self) -> Option<usize> {
+        let (mut res, mut max) = (None, &self.value);
+        let mut child = self.child.as_deref();
+        let mut k = 0;
+        while let Some(c) = child {
+            if c.value > *max {
+                (res, max) = (Some(k), &c.value);
+            }
+            child = c.sibling.as_deref();
+            k += 1;
+        }
+
+        res
+    }
+
+    /// Merge two
+    ///
+    /// # Panics
+    ///
+    /// Panics if given nodes is invalid.
+    fn merge(&mut self, mut other: Self) -> Result<(), Self> {
+        if self.order != other.order {
+            return Err(other);
+        }
+
+        assert!(self.sibling.is_none());
+        assert!(other.sibling.is_none());
+
+        if self.value < other.value {
+            std::mem::swap(self, &mut other);
+        }
+
+        // `self` takes priority over `other`.
+        self.order += 1;
+
+        let child = std::mem::take(&mut self.child);
+        other.sibling = child;
+        self.child = Some(Box::new(other));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn test_ordering() {
+        let mut heap = BinomialHeap::from_iter(0..100);
+
+        assert_eq!(
+            Vec::from_iter(std::iter::repeat_with(|| heap.pop().unwrap()).take(100)),
+            Vec::from_iter((0..100).rev())
+        );
+        assert!(heap.is_empty())
+    }
+
+    #[test]
+    fn test_append() {
+        for (n, m) in [
+            (0, 0),
+            (0, 5),
+            (5, 0),
+            (1, 100),
+            (100, 1),
+            (37, 64),
+            (64, 37),
+        ] {
+            let mut rng = xorshift(0x1234_5678_u64 + n as u64 * 1000 + m as u64);
+            let mut next = move || rng() % 50;
+            let a = Vec::from_iter(std::iter::repeat_with(&mut next).take(n));
+            let b = Vec::from_iter(std::iter::repeat_with(&mut next).take(m));
+
+            let mut heap = BinomialHeap::from(a.clone());
+            let mut other = BinomialHeap::from(b.clone());
+            // consolidate some trees before melding
+            let mut popped = Vec::new();
+            popped.extend(heap.pop());
+            popped.extend(other.pop());
+            heap.push(100);
+            heap.append(other);
+            heap.extend(popped.iter().copied());
+            assert_eq!(heap.size(), n + m + 1);
+
+            let mut expected = [a, b, vec![100]].concat();
+            expected.sort_unstable_by(|x, y| y.cmp(x));
+            assert_eq!(Vec::from_iter(std::iter::from_fn(|| heap.pop())), expected);
+            assert!(heap.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_into_sorted_vec_and_iter() {
+        let mut rng = xorshift(0x9e37_79b9);
+        let mut next = move || rng() % 100;
+
+        for n in [0, 1, 2, 7, 64, 100] {
+            let mut expected = Vec::from_iter(std::iter::repeat_with(&mut next).take(n));
+            let mut heap = BinomialHeap::from(expected.clone());
+            assert_eq!(heap.iter().count(), heap.size());
+
+            // consolidate trees, then push lazily again
+            if let Some(max) = heap.pop() {
+                heap.push(max);
+            }
+            let more = [next(), next()];
+            heap.extend(more);
+            expected.extend(more);
+            expected.sort_unstable_by(|x, y| y.cmp(x));
+            assert_eq!(heap.iter().count(), heap.size());
+
+            let mut all = Vec::from_iter(heap.iter().copied());
+            all.sort_unstable_by(|x, y| y.cmp(x));
+            assert_eq!(all, expected);
+            assert_eq!(heap.into_sorted_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut rng = xorshift(0x0dd_ba11);
+        let mut next = move || rng() % 100;
+
+        for n in [1, 2, 7, 64, 100] {
+            let mut expected = Vec::from_iter(std::iter::repeat_with(&mut next).take(n));
+            let mut heap = BinomialHeap::from(expected.clone());
+            // consolidate some trees, then push lazily again
+            let max = heap.pop().unwrap();
+            heap.push(max);
+            let more = [next(), next()];
+            heap.extend(more);
+            expected.extend(more);
+            expected.sort_unstable();
+
+            while !heap.is_empty() {
+                let max = *expected.last().unwrap();
+                assert_eq!(heap.peek_mut().as_deref(), Some(&max));
+
+                // decrease the maximum
+                let decreased = max.saturating_sub(next() % 30);
+                *heap.peek_mut().unwrap() = decreased;
+                expected.pop();
+                let i = expected.partition_point(|&v| v < decreased);
+                expected.insert(i, decreased);
+                assert_eq!(heap.peek(), expected.last());
+
+                assert_eq!(heap.pop(), expected.pop());
+                assert_eq!(heap.size(), expected.len());
+            }
+            assert!(heap.peek_mut().is_none());
+        }
+    }
+
+    #[test]
+    fn test_merge_node() {
+        const BIT: usize = 10;
+
+        let mut heap = BinomialHeap::from_iter(0..1 << BIT);
+        while heap.pop().is_some() {
+            assert!(heap.arena.len() <= BIT);
+        }
+    }
+}
@@ -1,5 +1,3 @@
-use itertools::Itertools;
-
 /// A priority queue implemented with implicit simple D-ary heap.
 ///
 /// This is a max heap.
@@ -104,7 +102,7 @@ impl<T: Ord, const D: usize> DAryHeap<T, D> {
     /// # Example
     ///
     /// ```
-    /// use alds::heap::DAryHeap;
+    /// use heap::DAryHeap;
     ///
     /// let mut heap = DAryHeap::<_, 8>::new();
     ///
@@ -141,7 +139,7 @@ impl<T: Ord, const D: usize> DAryHeap<T, D> {
     /// # Example
     ///
     /// ```
-    /// use alds::heap::DAryHeap;
+    /// use heap::DAryHeap;
     ///
     /// let mut heap = DAryHeap::<_, 8>::with_capacity(3);
     ///
@@ -164,7 +162,7 @@ impl<T: Ord, const D: usize> DAryHeap<T, D> {
     /// # Example
     ///
     /// ```
-    /// use alds::heap::DAryHeap;
+    /// use heap::DAryHeap;
     ///
     /// let mut heap = DAryHeap::<_, 8>::from(vec![1, 3, 5, 7, 9, -8, -6, -4, -2, 0]);
     ///
@@ -189,6 +187,33 @@ impl<T: Ord, const D: usize> DAryHeap<T, D> {
         Some(res)
     }
 
+    /// Consumes the heap and returns the elements in ascending order, like [`BinaryHeap::into_sorted_vec`].
+    ///
+    /// [`BinaryHeap::into_sorted_vec`]: std::collections::BinaryHeap::into_sorted_vec
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::DAryHeap;
+    ///
+    /// let heap = DAryHeap::<_, 4>::from(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+    ///
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 1, 2, 3, 4, 5, 6, 9]);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// O(*D* *n* log_D *n*)
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut res = Vec::with_capacity(self.len());
+        while let Some(max) = self.pop() {
+            res.push(max)
+        }
+        res.reverse();
+
+        res
+    }
+
     /// If *i* is out of bounds, do nothing.
     ///
     /// # Time complexity
@@ -200,7 +225,7 @@ impl<T: Ord, const D: usize> DAryHeap<T, D> {
         while let Some(max_c) = self
             .data
             .get(D * p + 1..)
-            .and_then(|children| children.iter().take(D).position_max())
+            .and_then(|children| position_max(&children[..D.min(children.len())]))
         {
             let c = D * p + 1 + max_c;
 
@@ -225,10 +250,73 @@ impl<T: Ord, const D: usize> From<Vec<T>> for DAryHeap<T, D> {
 
         // since sum_(k=0)^d k D^(d - k) ~ D^(d-1), where d := ilog_D(n),
         // time complexity is *O*(*n* / *D*) for D-ary heap.
-        for i in (0..heap.len() / D).rev() {
+        // nodes with at least one child
+        for i in (0..(heap.len().saturating_sub(1) + D - 1) / D).rev() {
             heap.shift_down(i);
         }
 
         heap
     }
 }
+
+/// Returns the index of the last maximum element, or `None` if `slice` is empty.
+#[inline]
+pub(crate) fn position_max<T: Ord>(slice: &[T]) -> Option<usize> {
+    (0..slice.len()).reduce(|i, j| if slice[i] <= slice[j] { j } else { i })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn into_sorted_vec() {
+        assert!(DAryHeap::<i32, 2>::new().into_sorted_vec().is_empty());
+
+        let mut next = xorshift(0x0123_4567);
+        let values = Vec::from_iter(std::iter::repeat_with(|| next() % 20).take(200));
+        let mut expected = values.clone();
+        expected.sort_unstable();
+
+        assert_eq!(
+            DAryHeap::<_, 1>::from(values.clone()).into_sorted_vec(),
+            expected
+        );
+        assert_eq!(
+            DAryHeap::<_, 3>::from(values.clone()).into_sorted_vec(),
+            expected
+        );
+
+        let mut heap = DAryHeap::<_, 8>::new();
+        for v in values {
+            heap.push(v)
+        }
+        assert_eq!(heap.into_sorted_vec(), expected);
+    }
+
+    #[test]
+    fn heapify_last_internal_node() {
+        // the last internal node has fewer than `D` children
+        assert_eq!(
+            DAryHeap::<_, 4>::from(vec![0, 0, 0, 0, 0, 1]).into_sorted_vec(),
+            [0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            DAryHeap::<_, 3>::from(vec![0, 0, 0, 0, 0, 1]).into_sorted_vec(),
+            [0, 0, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn heapify_empty() {
+        assert!(DAryHeap::<i32, 1>::from(vec![]).is_empty());
+        assert!(DAryHeap::<i32, 2>::from(vec![]).is_empty());
+        assert_eq!(DAryHeap::<_, 1>::from(vec![3]).into_sorted_vec(), [3]);
+        assert_eq!(
+            DAryHeap::<_, 1>::from(vec![3, 1, 2]).into_sorted_vec(),
+            [1, 2, 3]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use super::d_ary_heap::position_max;
+
+/// A D-ary max heap whose elements can be updated through handles.
+///
+/// [push](DAryHeapWithHandle::push) returns a handle, which stays valid until the element is popped.
+/// Handles are assigned in the order of insertion, starting from 0.
+///
+/// # Example
+///
+/// ```
+/// use heap::DAryHeapWithHandle;
+///
+/// let mut heap = DAryHeapWithHandle::<_, 4>::new();
+/// let a = heap.push(10);
+/// let b = heap.push(20);
+/// let c = heap.push(30);
+///
+/// heap.change_priority(a, 40);
+/// heap.change_priority(c, 0);
+///
+/// assert_eq!(heap.pop(), Some((a, 40)));
+/// assert_eq!(heap.pop(), Some((b, 20)));
+/// assert_eq!(heap.pop(), Some((c, 0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DAryHeapWithHandle<T, const D: usize> {
+    /// data[0] is the root node.
+    data: Vec<T>,
+    /// position -> handle
+    handle: Vec<usize>,
+    /// handle -> position, or [NULL](Self::NULL) if the element has been popped
+    position: Vec<usize>,
+}
+
+impl<T: Ord, const D: usize> Default for DAryHeapWithHandle<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const D: usize> DAryHeapWithHandle<T, D> {
+    const NULL: usize = !0;
+
+    const fn assert_branching_factor() {
+        assert!(D > 0, "branching factor `D` should be positive.");
+    }
+
+    /// Creates an empty heap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `D = 0`.
+    pub const fn new() -> Self {
+        Self::assert_branching_factor();
+
+        Self {
+            data: Vec::new(),
+            handle: Vec::new(),
+            position: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks if the element of the given handle is in the heap.
+    pub fn contains(&self, handle: usize) -> bool {
+        self.position
+            .get(handle)
+            .map_or(false, |&i| i != Self::NULL)
+    }
+
+    /// Returns the element of the given handle if it is in the heap.
+    pub fn get(&self, handle: usize) -> Option<&T> {
+        if self.contains(handle) {
+            Some(&self.data[self.position[handle]])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the maximum element and its handle.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn peek(&self) -> Option<(usize, &T)> {
+        self.data.first().map(|v| (self.handle[0], v))
+    }
+
+    /// Pushes an item and returns its handle.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log_D *n*)
+    pub fn push(&mut self, item: T) -> usize {
+        let handle = self.position.len();
+        self.position.push(self.data.len());
+        self.handle.push(handle);
+        self.data.push(item);
+
+        self.shift_up(self.data.len() - 1);
+
+        handle
+    }
+
+    /// Removes the maximum element and returns it with its handle.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*D* log_D *n*)
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = self.data.len() - 1;
+        self.swap(0, last);
+        let handle = self.handle.pop().unwrap();
+        self.position[handle] = Self::NULL;
+        let res = self.data.pop().unwrap();
+        self.shift_down(0);
+
+        Some((handle, res))
+    }
+
+    /// Replaces the element of the given handle with `new`, returning the old one.
+    /// The element moves up or down depending on whether its priority is raised or lowered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is not in the heap.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*D* log_D *n*)
+    pub fn change_priority(&mut self, handle: usize, new: T) -> T {
+        assert!(self.contains(handle), "invalid handle: {}", handle);
+
+        let i = self.position[handle];
+        let old = std::mem::replace(&mut self.data[i], new);
+        if old < self.data[i] {
+            self.shift_up(i)
+        } else {
+            self.shift_down(i)
+        }
+
+        old
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+        self.handle.swap(i, j);
+        self.position[self.handle[i]] = i;
+        self.position[self.handle[j]] = j;
+    }
+
+    fn shift_up(&mut self, i: usize) {
+        let mut c = i;
+        while c > 0 {
+            let p = (c - 1) / D;
+
+            if self.data[p] >= self.data[c] {
+                break;
+            }
+
+            self.swap(p, c);
+            c = p;
+        }
+    }
+
+    /// If *i* is out of bounds, do nothing.
+    fn shift_down(&mut self, i: usize) {
+        let mut p = i;
+        while let Some(max_c) = self
+            .data
+            .get(D * p + 1..)
+            .and_then(|children| position_max(&children[..D.min(children.len())]))
+        {
+            let c = D * p + 1 + max_c;
+
+            if self.data[p] >= self.data[c] {
+                break;
+            }
+
+            self.swap(p, c);
+            p = c
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn raise_above_max() {
+        let mut heap = DAryHeapWithHandle::<_, 3>::new();
+        let handles = Vec::from_iter((0..10).map(|i| heap.push(i * 10)));
+
+        assert_eq!(heap.change_priority(handles[2], 1000), 20);
+        assert_eq!(heap.peek(), Some((handles[2], &1000)));
+        assert_eq!(heap.change_priority(handles[9], -1), 90);
+        assert_eq!(heap.get(handles[9]), Some(&-1));
+
+        let order = Vec::from_iter(std::iter::from_fn(|| heap.pop()));
+        assert_eq!(
+            order,
+            [
+                (2, 1000),
+                (8, 80),
+                (7, 70),
+                (6, 60),
+                (5, 50),
+                (4, 40),
+                (3, 30),
+                (1, 10),
+                (0, 0),
+                (9, -1)
+            ]
+        );
+        assert!(handles.iter().all(|&h| !heap.contains(h)));
+    }
+
+    #[test]
+    fn random_updates() {
+        let mut next = xorshift(0xdead_beef);
+
+        let mut heap = DAryHeapWithHandle::<_, 4>::new();
+        // naive[handle] = Some(priority) if alive
+        let mut naive = Vec::new();
+        for _ in 0..2000 {
+            match next() % 3 {
+                0 => {
+                    let v = next() % 100;
+                    assert_eq!(heap.push(v), naive.len());
+                    naive.push(Some(v));
+                }
+                1 => {
+                    let expected = naive.iter().copied().flatten().max();
+                    let popped = heap.pop();
+                    assert_eq!(popped.map(|(_, v)| v), expected);
+                    if let Some((h, v)) = popped {
+                        assert_eq!(naive[h], Some(v));
+                        naive[h] = None;
+                    }
+                }
+                _ => {
+                    if naive.is_empty() {
+                        continue;
+                    }
+                    let h = next() as usize % naive.len();
+                    let v = next() % 100;
+                    if let Some(old) = naive[h] {
+                        assert_eq!(heap.change_priority(h, v), old);
+                        naive[h] = Some(v);
+                    } else {
+                        assert!(!heap.contains(h));
+                    }
+                }
+            }
+            assert_eq!(heap.len(), naive.iter().flatten().count());
+        }
+    }
+}
@@ -1,17 +1,17 @@
 //! Heap collections.
 //!
-//! # Selection guide
-//!
 //! # References
 //! 1. [A Back-to-Basics Empirical Study of Priority Queues](https://epubs.siam.org/doi/abs/10.1137/1.9781611973198.7).
 mod binomial_heap;
 mod d_ary_heap;
+mod d_ary_heap_with_handle;
 mod pairing_heap;
 mod pairing_heap2;
 mod quad_heap;
 
-pub use binomial_heap::BinomialHeap;
+pub use binomial_heap::{BinomialHeap, PeekMut};
 pub use d_ary_heap::DAryHeap;
+pub use d_ary_heap_with_handle::DAryHeapWithHandle;
 pub use pairing_heap::PairingHeap;
-// pub use pairing_heap2::PairingHeap2;
+pub use pairing_heap2::{Entry, PairingHeap2};
 pub use quad_heap::QuadHeap;
@@ -0,0 +1,376 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::Debug,
+    hash::Hash,
+    rc::{Rc, Weak},
+};
+
+/// A max heap whose elements are identified by keys, so that their priorities can be changed.
+///
+/// # Example
+///
+/// ```
+/// use heap::PairingHeap;
+///
+/// let mut heap = PairingHeap::new();
+/// heap.insert("a", 10);
+/// heap.insert("b", 20);
+/// heap.insert("c", 30);
+///
+/// heap.prioritise("a", 40);
+/// heap.prioritise("c", 0);
+///
+/// assert_eq!(heap.pop(), Some(("a", 40)));
+/// assert_eq!(heap.pop(), Some(("b", 20)));
+/// assert_eq!(heap.pop(), Some(("c", 0)));
+/// ```
+#[derive(Debug)]
+pub struct PairingHeap<I, P> {
+    root: Option<Rc<NodeRef<I, P>>>,
+    map: HashMap<I, Rc<NodeRef<I, P>>>,
+}
+
+impl<I: Hash + Eq + Clone, P: Ord> Default for PairingHeap<I, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Hash + Eq + Clone, P: Ord> PairingHeap<I, P> {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            map: HashMap::default(),
+        }
+    }
+
+    pub fn insert(&mut self, identifier: I, priority: P) -> bool {
+        if self.map.contains_key(&identifier) {
+            return false;
+        }
+
+        let node = Rc::new(RefCell::new(Node::new(identifier.clone(), priority)));
+
+        self.map.insert(identifier, Rc::clone(&node));
+        self.root = if let Some(root) = std::mem::take(&mut self.root) {
+            Some(Node::meld(root, node))
+        } else {
+            Some(node)
+        };
+
+        true
+    }
+
+    pub fn remove(&mut self, identifier: I) -> Option<(I, P)> {
+        if self
+            .map
+            .get(&identifier)
+            .is_some_and(|node| node.borrow().is_root())
+        {
+            self.pop()
+        } else if let Some(mut node) = self.map.remove(&identifier) {
+            Node::detach(&mut node);
+
+            if let Some(child) = Node::pair_and_detach_children(&mut node) {
+                self.root = Some(Node::meld(std::mem::take(&mut self.root).unwrap(), child));
+            }
+
+            let node = Rc::into_inner(node).unwrap().into_inner();
+            Some((node.identifier, node.priority))
+        } else {
+            None
+        }
+    }
+
+    /// Removes the maximum element and returns it with its identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::PairingHeap;
+    ///
+    /// let mut heap = PairingHeap::new();
+    /// for i in 0..100 {
+    ///     let id = i;
+    ///     let priority = -i;
+    ///     assert!(heap.insert(id, priority))
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Vec::from_iter(std::iter::from_fn(|| heap.pop())),
+    ///     Vec::from_iter((0..100).map(|i| (i, -i))),
+    /// )
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*), amortized
+    pub fn pop(&mut self) -> Option<(I, P)> {
+        if let Some(mut root) = std::mem::take(&mut self.root) {
+            assert!(root.borrow().is_root());
+            assert!(self.map.remove(root.borrow().identifier()).is_some());
+
+            self.root = Node::pair_and_detach_children(&mut root);
+            assert!(self.root.as_ref().map_or(true, |c| c.borrow().is_root()));
+
+            let root = Rc::into_inner(root).unwrap().into_inner();
+            Some((root.identifier, root.priority))
+        } else {
+            None
+        }
+    }
+
+    /// Changes the priority of the element of the given identifier.
+    /// Returns `false` if there is no such element.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*), amortized
+    pub fn prioritise(&mut self, identifier: I, new_priority: P) -> bool {
+        let Some(mut node) = self.map.get(&identifier).cloned() else {
+            return false;
+        };
+
+        let root = std::mem::take(&mut self.root).unwrap();
+        let root = if Rc::ptr_eq(&root, &node) {
+            if &new_priority >= node.borrow().priority() {
+                node.borrow_mut().priority = new_priority;
+                node
+            } else {
+                // children may have higher priorities than the new one
+                let child = Node::pair_and_detach_children(&mut node);
+                node.borrow_mut().priority = new_priority;
+                match child {
+                    Some(child) => Node::meld(node, child),
+                    None => node,
+                }
+            }
+        } else {
+            Node::detach(&mut node);
+
+            let root = if node.borrow().priority() > &new_priority {
+                // children may have higher priorities than the new one
+                match Node::pair_and_detach_children(&mut node) {
+                    Some(child) => Node::meld(root, child),
+                    None => root,
+                }
+            } else {
+                // the subtree remains heap-ordered
+                root
+            };
+            node.borrow_mut().priority = new_priority;
+
+            Node::meld(root, node)
+        };
+        self.root = Some(root);
+
+        true
+    }
+}
+
+type NodeRef<I, P> = RefCell<Node<I, P>>;
+
+#[derive(Debug, Clone)]
+struct Node<I, P> {
+    identifier: I,
+    priority: P,
+
+    parent: Option<Weak<NodeRef<I, P>>>,
+    child: Option<Rc<NodeRef<I, P>>>,
+    sibling: Option<Rc<NodeRef<I, P>>>,
+}
+
+impl<I, P: Ord> Node<I, P> {
+    fn new(identifier: I, priority: P) -> Self {
+        Self {
+            identifier,
+            priority,
+            parent: None,
+            child: None,
+            sibling: None,
+        }
+    }
+
+    const fn identifier(&self) -> &I {
+        &self.identifier
+    }
+
+    const fn priority(&self) -> &P {
+        &self.priority
+    }
+
+    const fn is_root(&self) -> bool {
+        self.parent.is_none() && self.sibling.is_none()
+    }
+
+    fn has_child(&self, node: &Rc<NodeRef<I, P>>) -> bool {
+        self.child
+            .as_ref()
+            .is_some_and(|child| Rc::ptr_eq(child, node))
+    }
+
+    fn has_sibling(&self, node: &Rc<NodeRef<I, P>>) -> bool {
+        self.sibling
+            .as_ref()
+            .is_some_and(|child| Rc::ptr_eq(child, node))
+    }
+
+    /// Detaches given node from the parent and siblings.
+    fn detach(node: &mut Rc<NodeRef<I, P>>) {
+        let parent = std::mem::take(&mut node.borrow_mut().parent);
+        if let Some(parent) = parent {
+            let sibling = std::mem::take(&mut node.borrow_mut().sibling);
+            if let Some(sibling) = &sibling {
+                // `sibling` takes the place of `node`
+                sibling.borrow_mut().parent = Some(Weak::clone(&parent));
+            }
+
+            let parent = Weak::upgrade(&parent).unwrap();
+            if parent.borrow().has_child(node) {
+                parent.borrow_mut().child = sibling;
+            } else if parent.borrow().has_sibling(node) {
+                parent.borrow_mut().sibling = sibling;
+            } else {
+                unreachable!("given node should be a child or sibling of the parent")
+            }
+        }
+
+        assert!(node.borrow().is_root())
+    }
+
+    /// Detaches all children of given node and melds them by the two-pass pairing.
+    fn pair_and_detach_children(node: &mut Rc<NodeRef<I, P>>) -> Option<Rc<NodeRef<I, P>>> {
+        let mut children = Vec::new();
+        let mut child = std::mem::take(&mut node.borrow_mut().child);
+        while let Some(inner) = child {
+            inner.borrow_mut().parent = None;
+            child = std::mem::take(&mut inner.borrow_mut().sibling);
+            children.push(inner);
+        }
+
+        // meld pairs from left to right, and then the results from right to left
+        let mut pairs = Vec::with_capacity((children.len() + 1) / 2);
+        let mut children = children.into_iter();
+        while let Some(first) = children.next() {
+            pairs.push(match children.next() {
+                Some(second) => Node::meld(first, second),
+                None => first,
+            })
+        }
+
+        pairs.into_iter().rev().reduce(Node::meld)
+    }
+
+    fn meld(mut root_1: Rc<NodeRef<I, P>>, mut root_2: Rc<NodeRef<I, P>>) -> Rc<NodeRef<I, P>> {
+        assert!(root_1.borrow().is_root());
+        assert!(root_2.borrow().is_root());
+
+        if root_1.borrow().priority() < root_2.borrow().priority() {
+            std::mem::swap(&mut root_1, &mut root_2);
+        }
+
+        let child = std::mem::take(&mut root_1.borrow_mut().child);
+        if let Some(child) = &child {
+            child.borrow_mut().parent = Some(Rc::downgrade(&root_2));
+        }
+        root_2.borrow_mut().sibling = child;
+        root_2.borrow_mut().parent = Some(Rc::downgrade(&root_1));
+        root_1.borrow_mut().child = Some(root_2);
+
+        root_1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn test_pop() {
+        let mut heap = PairingHeap::new();
+
+        assert!(heap.insert(0, -100));
+        assert!(!heap.insert(0, 100));
+        assert!(heap.insert(1, 1000));
+        assert!(heap.insert(2, 2000));
+
+        assert_eq!(heap.pop(), Some((2, 2000)));
+        assert_eq!(heap.pop(), Some((1, 1000)));
+        assert_eq!(heap.pop(), Some((0, -100)));
+        assert!(heap.pop().is_none());
+    }
+
+    #[test]
+    fn raise_non_root_above_max() {
+        let mut heap = PairingHeap::new();
+        for i in 0..10 {
+            assert!(heap.insert(i, i * 10));
+        }
+        // make the tree deep enough
+        assert_eq!(heap.pop(), Some((9, 90)));
+
+        assert!(heap.prioritise(2, 1000));
+        assert!(heap.prioritise(5, 500));
+        assert!(heap.prioritise(8, -1));
+        assert!(!heap.prioritise(9, 0));
+
+        assert_eq!(
+            Vec::from_iter(std::iter::from_fn(|| heap.pop())),
+            [
+                (2, 1000),
+                (5, 500),
+                (7, 70),
+                (6, 60),
+                (4, 40),
+                (3, 30),
+                (1, 10),
+                (0, 0),
+                (8, -1)
+            ]
+        );
+    }
+
+    #[test]
+    fn random_operations() {
+        let mut next = xorshift(0x0123_4567_89ab_cdef);
+
+        let mut heap = PairingHeap::new();
+        let mut naive = HashMap::new();
+        for _ in 0..3000 {
+            let id = next() % 50;
+            let priority = (next() % 100) as i64;
+            match next() % 4 {
+                0 => {
+                    let inserted = heap.insert(id, priority);
+                    assert_eq!(inserted, !naive.contains_key(&id));
+                    if inserted {
+                        naive.insert(id, priority);
+                    }
+                }
+                1 => {
+                    let expected = naive.iter().map(|(_, &p)| p).max();
+                    let popped = heap.pop();
+                    assert_eq!(popped.map(|(_, p)| p), expected);
+                    if let Some((id, p)) = popped {
+                        assert_eq!(naive.remove(&id), Some(p));
+                    }
+                }
+                2 => assert_eq!(heap.remove(id), naive.remove(&id).map(|p| (id, p))),
+                _ => {
+                    assert_eq!(heap.prioritise(id, priority), naive.contains_key(&id));
+                    if let Some(p) = naive.get_mut(&id) {
+                        *p = priority
+                    }
+                }
+            }
+        }
+        while let Some((id, p)) = heap.pop() {
+            assert_eq!(Some(&p), naive.values().max());
+            assert_eq!(naive.remove(&id), Some(p));
+        }
+        assert!(naive.is_empty());
+    }
+}
@@ -0,0 +1,428 @@
+use std::cmp::Ordering;
+
+/// Implicit eager pairing heap.
+///
+/// [push](PairingHeap2::push) returns a tag, which stays valid until the item is popped or removed.
+/// Tags are assigned in the order of insertion, starting from 0, and never reused.
+///
+/// # Example
+///
+/// ```
+/// use heap::PairingHeap2;
+///
+/// let mut heap = PairingHeap2::new();
+/// let a = heap.push('a', 10);
+/// let b = heap.push('b', 20);
+/// let c = heap.push('c', 30);
+///
+/// heap.update_priority(a, 40);
+/// assert_eq!(heap.remove(c).map(|e| e.item), Some('c'));
+///
+/// assert_eq!(heap.pop().map(|e| (e.item, e.priority)), Some(('a', 40)));
+/// assert_eq!(heap.pop().map(|e| (e.item, e.priority)), Some(('b', 20)));
+/// assert!(heap.pop().is_none());
+/// assert!(!heap.contains(b));
+/// ```
+pub struct PairingHeap2<P, I> {
+    /// `data[tag]` is `None` if the item has been popped or removed.
+    data: Vec<Option<Node<P, I>>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+impl<P: Ord, I> Default for PairingHeap2<P, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Ord, I> PairingHeap2<P, I> {
+    pub const fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, tag: usize) -> bool {
+        self.data.get(tag).map_or(false, Option::is_some)
+    }
+
+    /// Appends a new item to the pairing heap.
+    ///
+    /// Use [`Self::update_priority`] for stored item.
+    pub fn push(&mut self, item: I, priority: P) -> usize {
+        let tag = self.data.len();
+        self.data.push(Some(Node::new(item, priority)));
+        self.len += 1;
+
+        self.root = Some(match self.root {
+            Some(root) => self.meld(root, tag),
+            None => tag,
+        });
+
+        tag
+    }
+
+    pub fn peek(&self) -> Option<&Entry<P, I>> {
+        self.root.map(|tag| self.node(tag).ref_entry())
+    }
+
+    pub fn get(&self, tag: usize) -> Option<&Entry<P, I>> {
+        self.data
+            .get(tag)
+            .and_then(Option::as_ref)
+            .map(|node| node.ref_entry())
+    }
+
+    /// Removes the item with the highest priority.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*), amortized
+    pub fn pop(&mut self) -> Option<Entry<P, I>> {
+        let root = self.root?;
+        self.remove(root)
+    }
+
+    /// Removes the item of the given tag.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*), amortized
+    pub fn remove(&mut self, tag: usize) -> Option<Entry<P, I>> {
+        if !self.contains(tag) {
+            return None;
+        }
+
+        // parent
+        //  └ this
+        //     ├ (child)  <- detach if exists
+        //     └ (sibling)
+        let child = self.detach_and_orchestrate_children(tag);
+
+        // parent                                   parent
+        //  └ this                              =>   └ (sibling)
+        //     └ (sibling) <- detach if exists      this <- isolated
+        self.detach_node(tag);
+        self.root = match (self.root, child) {
+            (Some(root), Some(child)) => Some(self.meld(root, child)),
+            (root, child) => root.or(child),
+        };
+
+        self.len -= 1;
+        Some(self.data[tag].take().unwrap().into_entry())
+    }
+
+    /// Replaces the priority of the item of the given tag.
+    /// Returns `false` if the tag has expired.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *n*), amortized
+    pub fn update_priority(&mut self, tag: usize, new_priority: P) -> bool {
+        if !self.contains(tag) {
+            return false;
+        }
+
+        match self.node_mut(tag).update_priority(new_priority) {
+            Ordering::Equal => (),
+            // the subtree rooted at `this` remains heap-ordered
+            Ordering::Less => {
+                if self.root != Some(tag) {
+                    self.detach_node(tag);
+                    self.root = Some(self.meld(self.root.unwrap(), tag))
+                }
+            }
+            // children may have higher priorities than `this`
+            Ordering::Greater => {
+                if let Some(child) = self.detach_and_orchestrate_children(tag) {
+                    self.detach_node(tag);
+                    let root = match self.root {
+                        Some(root) => self.meld(root, child),
+                        None => child,
+                    };
+                    self.root = Some(self.meld(root, tag))
+                }
+            }
+        }
+
+        true
+    }
+
+    fn node(&self, tag: usize) -> &Node<P, I> {
+        self.data[tag].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, tag: usize) -> &mut Node<P, I> {
+        self.data[tag].as_mut().unwrap()
+    }
+
+    /// Merges two root nodes, then returns the tag of the new root (`root_1` or `root_2`).
+    ///
+    /// # Panics
+    ///
+    /// Panic if given tags have expired or may panic if they are tied with non-root nodes.
+    fn meld(&mut self, mut root_1: usize, mut root_2: usize) -> usize {
+        if self.node(root_1).priority_cmp(self.node(root_2)).is_lt() {
+            std::mem::swap(&mut root_1, &mut root_2);
+        }
+
+        // `root_1` has priority over `root_2`.
+        assert!(self.node_mut(root_2).replace_parent(root_1).is_none());
+        if let Some(child_1) = self.node_mut(root_1).replace_child(root_2) {
+            assert!(self.node_mut(root_2).replace_sibling(child_1).is_none());
+            assert_eq!(self.node_mut(child_1).replace_parent(root_2), Some(root_1));
+        }
+
+        root_1
+    }
+
+    /// Detaches children, orchestrates them by pairing, then returns the tag of their root.
+    ///
+    /// ```text
+    /// (root)?                 (root)?
+    ///  └ this                  └ this
+    ///     ┝ (child)?       =>     ┝ [no child]
+    ///     |  ┝ (child)?           └ (sibling)?
+    ///     |  └ (sibling)?
+    ///     └ (sibling)?        (child)?  <- returned after orchestrating siblings
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given tag has expired
+    fn detach_and_orchestrate_children(&mut self, tag: usize) -> Option<usize> {
+        let mut new_root = self.node_mut(tag).take_child()?;
+        assert!(self.node_mut(new_root).take_parent().is_some());
+
+        // iterate over children and orchestrate them
+        let mut next = self.node_mut(new_root).take_sibling();
+        while let Some(first) = next.take() {
+            // detach from the parent for consistency
+            assert!(self.node_mut(first).take_parent().is_some());
+
+            new_root = if let Some(second) = self.node_mut(first).take_sibling() {
+                assert!(self.node_mut(second).take_parent().is_some());
+                next = self.node_mut(second).take_sibling();
+
+                let pair = self.meld(first, second);
+                self.meld(new_root, pair)
+            } else {
+                self.meld(new_root, first)
+            };
+        }
+
+        Some(new_root)
+    }
+
+    /// Detaches the subtree rooted at given node from the tree.
+    /// See this:
+    ///
+    /// ```text
+    /// (1) parent              parent          | (2) [no parent]
+    ///      └ this          =>  └ (sibling)?   |      └ this  <- root    =>  [no root]
+    ///         ┝ (child)?      this            |         ┝ (child)?          this
+    ///         └ (sibling)?     └ (child)?     |         └ [no sibling]       └ (child)?
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given tag is expired.
+    fn detach_node(&mut self, tag: usize) {
+        if let Some(parent) = self.node_mut(tag).take_parent() {
+            // attaches the sibling to the parent
+            let sibling = self.node_mut(tag).take_sibling();
+            if let Some(sibling) = sibling {
+                assert_eq!(self.node_mut(sibling).replace_parent(parent), Some(tag));
+            }
+
+            if self.node(parent).has_child(tag) {
+                self.node_mut(parent).child = sibling;
+            } else {
+                assert!(self.node(parent).has_sibling(tag));
+                self.node_mut(parent).sibling = sibling;
+            }
+        } else {
+            assert_eq!(self.root.take(), Some(tag));
+        }
+
+        assert!(self.node(tag).is_root())
+    }
+}
+
+#[derive(Debug)]
+pub struct Entry<P, I> {
+    pub priority: P,
+    pub item: I,
+}
+
+/// `parent` is the previous node in the list of siblings, or the parent if the node is the first child.
+struct Node<P, I> {
+    entry: Entry<P, I>,
+
+    parent: Option<usize>,
+    child: Option<usize>,
+    sibling: Option<usize>,
+}
+
+impl<P: Ord, I> Node<P, I> {
+    const fn new(item: I, priority: P) -> Self {
+        Self {
+            entry: Entry { priority, item },
+            parent: None,
+            child: None,
+            sibling: None,
+        }
+    }
+
+    const fn is_root(&self) -> bool {
+        self.parent.is_none() && self.sibling.is_none()
+    }
+
+    fn priority_cmp(&self, other: &Self) -> Ordering {
+        self.entry.priority.cmp(&other.entry.priority)
+    }
+
+    /// Returns the order of the old priority to the new one.
+    fn update_priority(&mut self, new_priority: P) -> Ordering {
+        let order = self.entry.priority.cmp(&new_priority);
+        self.entry.priority = new_priority;
+
+        order
+    }
+
+    const fn ref_entry(&self) -> &Entry<P, I> {
+        &self.entry
+    }
+
+    fn into_entry(self) -> Entry<P, I> {
+        self.entry
+    }
+
+    fn replace_parent(&mut self, tag: usize) -> Option<usize> {
+        self.parent.replace(tag)
+    }
+
+    fn take_parent(&mut self) -> Option<usize> {
+        self.parent.take()
+    }
+
+    fn replace_child(&mut self, tag: usize) -> Option<usize> {
+        self.child.replace(tag)
+    }
+
+    fn take_child(&mut self) -> Option<usize> {
+        self.child.take()
+    }
+
+    fn has_child(&self, tag: usize) -> bool {
+        self.child.is_some_and(|child_tag| child_tag == tag)
+    }
+
+    fn replace_sibling(&mut self, tag: usize) -> Option<usize> {
+        self.sibling.replace(tag)
+    }
+
+    fn take_sibling(&mut self) -> Option<usize> {
+        self.sibling.take()
+    }
+
+    fn has_sibling(&self, tag: usize) -> bool {
+        self.sibling.is_some_and(|sibling_tag| sibling_tag == tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn update_both_directions() {
+        let mut heap = PairingHeap2::new();
+        let tags = Vec::from_iter((0..10).map(|i| heap.push(i, i * 10)));
+        assert_eq!(heap.pop().map(|e| e.item), Some(9));
+
+        assert!(heap.update_priority(tags[2], 1000));
+        assert!(heap.update_priority(tags[8], -1));
+        assert!(heap.update_priority(tags[5], 50));
+        assert!(!heap.update_priority(tags[9], 0));
+        assert_eq!(heap.peek().map(|e| e.item), Some(2));
+        assert_eq!(heap.get(tags[8]).map(|e| e.priority), Some(-1));
+
+        assert_eq!(heap.remove(tags[7]).map(|e| e.priority), Some(70));
+        assert!(heap.remove(tags[7]).is_none());
+        assert_eq!(heap.len(), 8);
+
+        let order = Vec::from_iter(std::iter::from_fn(|| heap.pop()).map(|e| e.item));
+        assert_eq!(order, [2, 6, 5, 4, 3, 1, 0, 8]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn random_against_binary_heap() {
+        let mut next = xorshift(0xfeed);
+
+        let mut heap = PairingHeap2::new();
+        // (priority, tag) with lazy deletion
+        let mut reference = BinaryHeap::new();
+        // current[tag] = Some(priority) if alive
+        let mut current = Vec::new();
+        for _ in 0..5000 {
+            match next() % 4 {
+                0 => {
+                    let p = next() % 100;
+                    let tag = heap.push(current.len(), p);
+                    assert_eq!(tag, current.len());
+                    current.push(Some(p));
+                    reference.push((p, tag));
+                }
+                1 => {
+                    while reference
+                        .peek()
+                        .is_some_and(|&(p, tag)| current[tag] != Some(p))
+                    {
+                        reference.pop();
+                    }
+                    let expected = reference.peek().map(|&(p, _)| p);
+                    let popped = heap.pop();
+                    assert_eq!(popped.as_ref().map(|e| e.priority), expected);
+                    // ties may be broken differently
+                    if let Some(Entry { priority, item }) = popped {
+                        assert_eq!(current[item], Some(priority));
+                        current[item] = None;
+                    }
+                }
+                2 if !current.is_empty() => {
+                    let tag = next() as usize % current.len();
+                    let p = next() % 100;
+                    assert_eq!(heap.update_priority(tag, p), current[tag].is_some());
+                    if current[tag].is_some() {
+                        current[tag] = Some(p);
+                        reference.push((p, tag));
+                    }
+                }
+                3 if !current.is_empty() => {
+                    let tag = next() as usize % current.len();
+                    assert_eq!(heap.remove(tag).map(|e| e.priority), current[tag]);
+                    current[tag] = None;
+                }
+                _ => (),
+            }
+            assert_eq!(heap.len(), current.iter().flatten().count());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use super::d_ary_heap::position_max;
 
 /// A priority queue implemented with quaternary heap.
 ///
@@ -91,7 +91,7 @@ impl<T: Ord> QuadHeap<T> {
     /// # Example
     ///
     /// ```
-    /// use alds::heap::QuadHeap;
+    /// use heap::QuadHeap;
     ///
     /// let mut heap = QuadHeap::new();
     ///
@@ -128,7 +128,7 @@ impl<T: Ord> QuadHeap<T> {
     /// # Example
     ///
     /// ```
-    /// use alds::heap::QuadHeap;
+    /// use heap::QuadHeap;
     ///
     /// let mut heap = QuadHeap::with_capacity(3);
     ///
@@ -151,7 +151,7 @@ impl<T: Ord> QuadHeap<T> {
     /// # Example
     ///
     /// ```
-    /// use alds::heap::QuadHeap;
+    /// use heap::QuadHeap;
     ///
     /// let mut heap = QuadHeap::from(vec![1, 3, 5, 7, 9, -8, -6, -4, -2, 0]);
     ///
@@ -176,13 +176,49 @@ impl<T: Ord> QuadHeap<T> {
         Some(res)
     }
 
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use heap::QuadHeap;
+    ///
+    /// let mut heap = QuadHeap::from_iter([1, 5, 3]);
+    /// let mut other = QuadHeap::from_iter([4, 2, 6]);
+    /// heap.append(&mut other);
+    ///
+    /// assert!(other.is_empty());
+    /// assert_eq!(
+    ///     Vec::from_iter(std::iter::from_fn(|| heap.pop())),
+    ///     vec![6, 5, 4, 3, 2, 1],
+    /// );
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*n* + *m*)
+    pub fn append(&mut self, other: &mut Self) {
+        self.data.append(&mut other.data);
+        self.heapify();
+    }
+
+    /// Restores the heap property bottom-up.
+    fn heapify(&mut self) {
+        // since sum_(k=0)^d k D^(d - k) ~ D^(d-1), where d := ilog_D(n),
+        // time complexity is *O*(*n* / *D*) for D-ary heap.
+        // nodes with at least one child
+        for i in (0..(self.len() + Self::D - 2) / Self::D).rev() {
+            self.shift_down(i);
+        }
+    }
+
     /// If *i* is out of bounds, do nothing.
     fn shift_down(&mut self, i: usize) {
         let mut p = i;
         while let Some(max_c) = self
             .data
             .get(Self::D * p + 1..)
-            .and_then(|children| children.iter().take(Self::D).position_max())
+            .and_then(|children| position_max(&children[..Self::D.min(children.len())]))
         {
             let c = Self::D * p + 1 + max_c;
 
@@ -202,13 +238,65 @@ impl<T: Ord> From<Vec<T>> for QuadHeap<T> {
     /// *O*(*n*)
     fn from(vec: Vec<T>) -> Self {
         let mut heap = Self { data: vec };
+        heap.heapify();
 
-        // since sum_(k=0)^d k D^(d - k) ~ D^(d-1), where d := ilog_D(n),
-        // time complexity is *O*(*n* / *D*) for D-ary heap.
-        for i in (0..heap.len() / Self::D).rev() {
-            heap.shift_down(i);
+        heap
+    }
+}
+
+impl<T: Ord> FromIterator<T> for QuadHeap<T> {
+    /// # Time complexity
+    ///
+    /// *O*(*n*)
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    fn is_heap<T: Ord>(data: &[T]) -> bool {
+        (1..data.len()).all(|c| data[(c - 1) / 4] >= data[c])
+    }
+
+    fn random_values(seed: u64) -> impl FnMut() -> u64 {
+        let mut next = xorshift(seed);
+        move || next() % 1000
+    }
+
+    #[test]
+    fn bulk_build() {
+        for n in [0, 1, 2, 5, 6, 7, 21, 22, 100, 1000] {
+            let values =
+                Vec::from_iter(std::iter::repeat_with(random_values(n as u64 + 1)).take(n));
+            let mut heap = QuadHeap::from_iter(values.iter().copied());
+            assert!(is_heap(heap.as_slice()), "n = {n}");
+
+            let mut expected = values;
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            assert_eq!(Vec::from_iter(std::iter::from_fn(|| heap.pop())), expected);
         }
+    }
 
-        heap
+    #[test]
+    fn append() {
+        for (n, m) in [(0, 0), (0, 10), (10, 0), (1, 50), (50, 1), (33, 77)] {
+            let a = Vec::from_iter(std::iter::repeat_with(random_values(n as u64 + 7)).take(n));
+            let b = Vec::from_iter(std::iter::repeat_with(random_values(m as u64 + 11)).take(m));
+
+            let mut heap = QuadHeap::from(a.clone());
+            let mut other = QuadHeap::from(b.clone());
+            heap.append(&mut other);
+            assert!(other.is_empty());
+            assert!(is_heap(heap.as_slice()));
+
+            let mut expected = [a, b].concat();
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            assert_eq!(Vec::from_iter(std::iter::from_fn(|| heap.pop())), expected);
+        }
     }
 }
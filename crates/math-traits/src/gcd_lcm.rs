@@ -77,3 +77,184 @@ macro_rules! gcd_lcm_impl {
 }
 
 gcd_lcm_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g`, where `g` is the non-negative GCD of `a` and `b`.
+///
+/// Unlike [GCD], zeros are accepted: `ext_gcd(a, 0)` returns `(|a|, sign(a), 0)`
+/// and `ext_gcd(0, 0)` returns `(0, 0, 0)`.
+///
+/// # Example
+///
+/// ```
+/// use math_traits::ext_gcd;
+///
+/// let (g, x, y) = ext_gcd(240, -46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + (-46) * y, 2);
+/// ```
+///
+/// # Panics
+///
+/// May overflow if `a` or `b` is `i64::MIN`.
+///
+/// # Time complexity
+///
+/// *O*(log min(|*a*|, |*b*|))
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if a == 0 && b == 0 {
+        return (0, 0, 0);
+    }
+
+    // invariant: a * x0 + b * y0 = g0 and a * x1 + b * y1 = g1
+    let (mut g0, mut g1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while g1 != 0 {
+        let div = g0 / g1;
+
+        (g0, g1) = (g1, g0 - g1 * div);
+        (x0, x1) = (x1, x0 - x1 * div);
+        (y0, y1) = (y1, y0 - y1 * div);
+    }
+
+    if g0 < 0 {
+        (-g0, -x0, -y0)
+    } else {
+        (g0, x0, y0)
+    }
+}
+
+/// Returns the GCD of all the values, where `gcd(0, x) = x`, or `0` if the iterator is empty.
+///
+/// # Example
+///
+/// ```
+/// use math_traits::gcd_all;
+///
+/// assert_eq!(gcd_all([12, 18, 0, 30]), 6);
+/// assert_eq!(gcd_all([]), 0);
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(*N* + log max *a*<sub>*i*</sub>)
+pub fn gcd_all<I>(iter: I) -> u64
+where
+    I: IntoIterator<Item = u64>,
+{
+    iter.into_iter()
+        .fold(0, |acc, v| acc.gcd(v).unwrap_or(acc | v))
+}
+
+/// Returns the LCM of all the values, or `1` if the iterator is empty.
+///
+/// Returns `None` if the LCM overflows `u64`.
+/// If any value is `0`, returns `Some(0)` unless an overflow occurs first.
+///
+/// # Example
+///
+/// ```
+/// use math_traits::lcm_all;
+///
+/// assert_eq!(lcm_all([4, 6, 10]), Some(60));
+/// assert_eq!(lcm_all([]), Some(1));
+/// assert_eq!(lcm_all([1 << 32, 3 << 32]), Some(3 << 32));
+/// assert_eq!(lcm_all([1 << 32, 3]), Some(3 << 32));
+/// assert_eq!(lcm_all([1 << 63, 3]), None);
+/// ```
+pub fn lcm_all<I>(iter: I) -> Option<u64>
+where
+    I: IntoIterator<Item = u64>,
+{
+    iter.into_iter().try_fold(1_u64, |acc, v| match acc.gcd(v) {
+        Some(gcd) => (acc / gcd).checked_mul(v),
+        None => Some(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ext_gcd_bezout() {
+        let values = [
+            0,
+            1,
+            -1,
+            2,
+            -3,
+            6,
+            12,
+            -18,
+            35,
+            64,
+            97,
+            -1_000_000_007,
+            998_244_353,
+            i64::MAX,
+            i64::MIN + 1,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (g, x, y) = ext_gcd(a, b);
+                assert!(g >= 0);
+                assert_eq!(
+                    a as i128 * x as i128 + b as i128 * y as i128,
+                    g as i128,
+                    "{a} {b}"
+                );
+
+                let expected = match (a.unsigned_abs(), b.unsigned_abs()) {
+                    (0, b) => b,
+                    (a, 0) => a,
+                    (a, b) => a.gcd(b).unwrap(),
+                };
+                assert_eq!(g as u64, expected, "{a} {b}");
+            }
+        }
+
+        assert_eq!(ext_gcd(0, 0), (0, 0, 0));
+        assert_eq!(ext_gcd(-5, 0), (5, -1, 0));
+        assert_eq!(ext_gcd(0, 7), (7, 0, 1));
+    }
+
+    #[test]
+    fn fold_slices() {
+        let values = [0_u64, 4, 6, 8, 10, 12, 15, 90, 97];
+        for l in 0..=values.len() {
+            for r in l..=values.len() {
+                let slice = &values[l..r];
+
+                let g = gcd_all(slice.iter().copied());
+                assert!(slice.iter().all(|&v| v % g.max(1) == 0));
+                if slice.iter().any(|&v| v != 0) {
+                    assert_eq!(
+                        (1..=g).rev().find(|d| slice.iter().all(|&v| v % d == 0)),
+                        Some(g)
+                    );
+                } else {
+                    assert_eq!(g, 0);
+                }
+
+                let l = lcm_all(slice.iter().copied()).unwrap();
+                if slice.contains(&0) {
+                    assert_eq!(l, 0);
+                } else {
+                    assert!(slice.iter().all(|&v| l % v == 0));
+                    assert!((1..l).all(|m| slice.iter().any(|&v| m % v != 0)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fold_empty() {
+        assert_eq!(gcd_all(std::iter::empty()), 0);
+        assert_eq!(lcm_all(std::iter::empty()), Some(1));
+        assert_eq!(gcd_all(vec![]), 0);
+        assert_eq!(lcm_all([0]), Some(0));
+        assert_eq!(lcm_all([u64::MAX, u64::MAX]), Some(u64::MAX));
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+    }
+}
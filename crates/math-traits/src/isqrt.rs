@@ -0,0 +1,45 @@
+/// Returns the largest `r` such that `r * r <= n`.
+///
+/// # Example
+///
+/// ```
+/// use math_traits::isqrt;
+///
+/// assert_eq!(isqrt(24), 4);
+/// assert_eq!(isqrt(25), 5);
+/// assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(1)
+pub fn isqrt(n: u64) -> u64 {
+    // TODO: use u64::isqrt()
+    // `f64` has only 53 bits of precision, so fix up the rounding error without overflow
+    let mut r = (n as f64).sqrt() as u64;
+    while r.checked_mul(r).map_or(true, |s| s > n) {
+        r -= 1
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|s| s <= n) {
+        r += 1
+    }
+
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn around_squares() {
+        for r in (0..1000).chain([(1 << 26) - 1, 1 << 26, u32::MAX as u64 - 1]) {
+            let s = r * r;
+            assert_eq!(isqrt(s), r);
+            assert_eq!(isqrt(s + 2 * r), r);
+            assert_eq!(isqrt(s + 2 * r + 1), r + 1);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(u64::MAX - 1), u32::MAX as u64);
+    }
+}
@@ -1,7 +1,11 @@
 mod gcd_lcm;
+mod isqrt;
 mod macros;
+pub mod monoids;
+mod ring;
 
-pub use gcd_lcm::{GCD, LCM};
+pub use gcd_lcm::{ext_gcd, gcd_all, lcm_all, GCD, LCM};
+pub use isqrt::isqrt;
 pub(crate) use macros::forward_ref_binop;
 
 pub trait Monoid {
@@ -15,6 +19,49 @@ pub trait Group {
     fn inverse(&self) -> Self;
 }
 
+/// A ring with unity, such as integers (with wrapping arithmetic) and modular integers.
+///
+/// Multiplication is not assumed to be commutative.
+///
+/// # Example
+///
+/// ```
+/// use math_traits::Ring;
+///
+/// /// Evaluates the polynomial with coefficients in ascending order of degree.
+/// fn horner<R: Ring>(coeffs: &[R], x: R) -> R {
+///     coeffs
+///         .iter()
+///         .rev()
+///         .fold(R::zero(), |acc, c| acc.mul(&x).add(c))
+/// }
+///
+/// // 3 - 2x + x^3
+/// assert_eq!(horner(&[3, -2, 0, 1], 4_i64), 59);
+/// assert_eq!(horner(&[3, -2, 0, 1], -3_i32), -18);
+/// assert_eq!(horner(&[3.0, -2.0, 0.0, 1.0], 0.5_f64), 2.125);
+/// assert_eq!(horner::<u64>(&[], 10), 0);
+///
+/// // wraps around
+/// assert_eq!(horner(&[1, 1], u8::MAX), 0);
+/// assert_eq!(horner(&[0, 0, 1], 16_u8), 0);
+/// ```
+pub trait Ring: Sized {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+    fn neg(&self) -> Self;
+}
+
+/// A ring where every non-zero element has a multiplicative inverse.
+///
+/// It is also implemented for modular integers of composite moduli, where only some elements are invertible.
+pub trait Field: Ring {
+    /// Returns the multiplicative inverse of `self` if exists.
+    fn inv(&self) -> Option<Self>;
+}
+
 pub mod marker {
     /// A marker trait for idempotent binary operations.
     pub trait Idempotent {}
@@ -0,0 +1,259 @@
+//! Ready-made wrappers of primitive integers.
+//!
+//! | type           | operation      | identity       | [Group] | [Commutative] | [Idempotent] |
+//! |----------------|----------------|----------------|---------|---------------|--------------|
+//! | [Add]          | wrapping `+`   | `0`            | yes     | yes           |              |
+//! | [Xor]          | `^`            | `0`            | yes     | yes           |              |
+//! | [Min]          | `min`          | `MAX`          |         | yes           | yes          |
+//! | [Max]          | `max`          | `MIN`          |         | yes           | yes          |
+//! | [Gcd]          | GCD            | `0`            |         | yes           | yes          |
+//!
+//! # Example
+//!
+//! ```
+//! use math_traits::{monoids::{Add, Min}, Group, Monoid};
+//!
+//! let sum = [3, 1, 4].iter().fold(<Add<i64> as Monoid>::identity(), |acc, &x| {
+//!     Monoid::bin_op(&acc, &Add(x))
+//! });
+//! assert_eq!(sum, Add(8));
+//! assert_eq!(Group::bin_op(&sum, &Add(3).inverse()), Add(5));
+//!
+//! let min = [3, 1, 4].iter().fold(Min::identity(), |acc, &x| acc.bin_op(&Min(x)));
+//! assert_eq!(min, Min(1_u32));
+//! ```
+//!
+//! They also implement `seg_lib::Monoid`, so that they can be used in segment trees as well.
+//!
+//! [Commutative]: crate::marker::Commutative
+//! [Idempotent]: crate::marker::Idempotent
+use crate::{
+    marker::{Commutative, Idempotent},
+    Group, Monoid,
+};
+
+/// Sum with wrapping arithmetic, so that unsigned integers also form a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Add<T>(pub T);
+
+/// Bitwise exclusive or.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Xor<T>(pub T);
+
+/// Minimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub T);
+
+/// Maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub T);
+
+/// Greatest common divisor, where `gcd(0, x) = |x|`.
+///
+/// Results are non-negative, with one exception for signed `T`:
+/// if every operand is `0` or `T::MIN`, the result is `T::MIN` since |`T::MIN`| = 2^(`T::BITS` - 1) does not fit in `T`.
+/// The operation is idempotent up to sign, that is, gcd(*a*, *a*) = |*a*|.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Gcd<T>(pub T);
+
+macro_rules! int_monoids_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for Add<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl Group for Add<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+
+            #[inline]
+            fn inverse(&self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+
+        impl Commutative for Add<$t> {}
+
+        impl Monoid for Xor<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl Group for Xor<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+
+            #[inline]
+            fn inverse(&self) -> Self {
+                *self
+            }
+        }
+
+        impl Commutative for Xor<$t> {}
+
+        impl Monoid for Min<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(<$t>::MAX)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0.min(rhs.0))
+            }
+        }
+
+        impl Commutative for Min<$t> {}
+        impl Idempotent for Min<$t> {}
+
+        impl Monoid for Max<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(<$t>::MIN)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0.max(rhs.0))
+            }
+        }
+
+        impl Commutative for Max<$t> {}
+        impl Idempotent for Max<$t> {}
+    )*};
+}
+
+int_monoids_impl! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+macro_rules! gcd_monoid_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for Gcd<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                let (mut a, mut b) = (self.0.unsigned_abs(), rhs.0.unsigned_abs());
+                while b != 0 {
+                    (a, b) = (b, a % b)
+                }
+
+                Self(a as $t)
+            }
+        }
+
+        impl Commutative for Gcd<$t> {}
+        impl Idempotent for Gcd<$t> {}
+    )*};
+}
+
+gcd_monoid_impl! { i8 i16 i32 i64 i128 isize }
+
+macro_rules! gcd_monoid_unsigned_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for Gcd<$t> {
+            #[inline]
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            fn bin_op(&self, rhs: &Self) -> Self {
+                let (mut a, mut b) = (self.0, rhs.0);
+                while b != 0 {
+                    (a, b) = (b, a % b)
+                }
+
+                Self(a)
+            }
+        }
+
+        impl Commutative for Gcd<$t> {}
+        impl Idempotent for Gcd<$t> {}
+    )*};
+}
+
+gcd_monoid_unsigned_impl! { u8 u16 u32 u64 u128 usize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fold<T: Monoid>(values: impl IntoIterator<Item = T>) -> T {
+        values
+            .into_iter()
+            .fold(T::identity(), |acc, x| acc.bin_op(&x))
+    }
+
+    #[test]
+    fn identities() {
+        for x in [i64::MIN, -5, 0, 7, i64::MAX] {
+            assert_eq!(Monoid::bin_op(&Add(x), &Monoid::identity()), Add(x));
+            assert_eq!(Monoid::bin_op(&Xor(x), &Monoid::identity()), Xor(x));
+            assert_eq!(Min(x).bin_op(&Min::identity()), Min(x));
+            assert_eq!(Max(x).bin_op(&Max::identity()), Max(x));
+        }
+        for x in [0_u64, 1, 12, u64::MAX] {
+            assert_eq!(Gcd(x).bin_op(&Gcd::identity()), Gcd(x));
+            assert_eq!(Gcd::identity().bin_op(&Gcd(x)), Gcd(x));
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        for x in [0_u32, 1, 100, u32::MAX] {
+            assert_eq!(Group::bin_op(&Add(x), &Add(x).inverse()), Add(0));
+            assert_eq!(Group::bin_op(&Xor(x), &Xor(x).inverse()), Xor(0));
+        }
+        assert_eq!(
+            Group::bin_op(&Add(i8::MIN), &Add(i8::MIN).inverse()),
+            Add(0)
+        );
+    }
+
+    #[test]
+    fn folds() {
+        let values = [12_u64, 18, 30, 42];
+
+        assert_eq!(fold(values.map(Add)), Add(102));
+        // 12 ^ 18 == 30
+        assert_eq!(fold(values.map(Xor)), Xor(42));
+        assert_eq!(fold(values.map(Min)), Min(12));
+        assert_eq!(fold(values.map(Max)), Max(42));
+        assert_eq!(fold(values.map(Gcd)), Gcd(6));
+        assert_eq!(fold(Vec::<Gcd<u8>>::new()), Gcd(0));
+        assert_eq!(fold([Gcd(-12_i64), Gcd(0), Gcd(18)]), Gcd(6));
+        assert_eq!(fold([Add(u8::MAX), Add(2)]), Add(1));
+    }
+}
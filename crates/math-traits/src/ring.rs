@@ -0,0 +1,94 @@
+use crate::{Field, Ring};
+
+macro_rules! int_ring_impl {
+    ( $( $t:ty )* ) => {$(
+        /// Wrapping arithmetic, that is, integers modulo `2^BITS`.
+        impl Ring for $t {
+            #[inline]
+            fn zero() -> Self {
+                0
+            }
+
+            #[inline]
+            fn one() -> Self {
+                1
+            }
+
+            #[inline]
+            fn add(&self, rhs: &Self) -> Self {
+                self.wrapping_add(*rhs)
+            }
+
+            #[inline]
+            fn mul(&self, rhs: &Self) -> Self {
+                self.wrapping_mul(*rhs)
+            }
+
+            #[inline]
+            fn neg(&self) -> Self {
+                self.wrapping_neg()
+            }
+        }
+    )*};
+}
+
+int_ring_impl! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+macro_rules! float_field_impl {
+    ( $( $t:ty )* ) => {$(
+        /// Up to rounding errors.
+        impl Ring for $t {
+            #[inline]
+            fn zero() -> Self {
+                0.0
+            }
+
+            #[inline]
+            fn one() -> Self {
+                1.0
+            }
+
+            #[inline]
+            fn add(&self, rhs: &Self) -> Self {
+                self + rhs
+            }
+
+            #[inline]
+            fn mul(&self, rhs: &Self) -> Self {
+                self * rhs
+            }
+
+            #[inline]
+            fn neg(&self) -> Self {
+                -self
+            }
+        }
+
+        impl Field for $t {
+            #[inline]
+            fn inv(&self) -> Option<Self> {
+                (*self != 0.0).then(|| self.recip())
+            }
+        }
+    )*};
+}
+
+float_field_impl! { f32 f64 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_neg() {
+        assert_eq!(Ring::neg(&1_u32), u32::MAX);
+        assert_eq!(Ring::neg(&i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn float_inverses() {
+        assert_eq!(4.0_f64.inv(), Some(0.25));
+        assert_eq!((-0.5_f32).inv(), Some(-2.0));
+        assert_eq!(0.0_f64.inv(), None);
+    }
+}
@@ -1,38 +1,5 @@
-/// Sort interval queries in Hilbert order.
-///
-/// ## Mo's Algorithm
-///
-/// See [this](https://codeforces.com/blog/entry/61203).
-///
-/// ## Example
-///
-/// ```
-/// use alds::range_query::mo_algorithm;
-///
-/// let queries = vec![(0, 1), (0, 5), (0, 10), (2, 3), (2, 9), (4, 9), (7, 8), (9, 10)];
-/// for i in mo_algorithm(&queries) {
-///     let (l, r) = queries[i];
-///     // do something
-/// }
-/// ```
-pub fn mo_algorithm(queries: &[(usize, usize)]) -> Vec<usize> {
-    let mut res = Vec::from_iter(0..queries.len());
-    let exp = queries
-        .iter()
-        .map(|(l, r)| l.max(r))
-        .max()
-        .unwrap_or(&0)
-        .next_power_of_two()
-        .ilog2()
-        + 1;
-    let h_order = Vec::from_iter(queries.iter().map(|&(x, y)| hilbert_order(x, y, exp)));
-
-    res.sort_unstable_by_key(|&i| h_order[i]);
-    res
-}
-
 /// Calculate Hilbert order.
-fn hilbert_order(x: usize, y: usize, exp: u32) -> usize {
+pub(crate) fn hilbert_order(x: usize, y: usize, exp: u32) -> usize {
     fn _hilbert_order(x: usize, y: usize, exp: u32, dir: Dir) -> usize {
         if exp == 0 {
             return 0;
@@ -98,10 +65,10 @@ mod tests {
         assert_eq!(
             calc_hilbert_order(2),
             vec![
-                vec![00, 01, 14, 15],
-                vec![03, 02, 13, 12],
-                vec![04, 07, 08, 11],
-                vec![05, 06, 09, 10]
+                vec![0, 1, 14, 15],
+                vec![3, 2, 13, 12],
+                vec![4, 7, 8, 11],
+                vec![5, 6, 9, 10]
             ]
         )
     }
@@ -111,9 +78,9 @@ mod tests {
         assert_eq!(
             calc_hilbert_order(3),
             vec![
-                vec![00, 03, 04, 05, 58, 59, 60, 63],
-                vec![01, 02, 07, 06, 57, 56, 61, 62],
-                vec![14, 13, 08, 09, 54, 55, 50, 49],
+                vec![0, 3, 4, 5, 58, 59, 60, 63],
+                vec![1, 2, 7, 6, 57, 56, 61, 62],
+                vec![14, 13, 8, 9, 54, 55, 50, 49],
                 vec![15, 12, 11, 10, 53, 52, 51, 48],
                 vec![16, 17, 30, 31, 32, 33, 46, 47],
                 vec![19, 18, 29, 28, 35, 34, 45, 44],
@@ -0,0 +1,198 @@
+//! Offline range queries by Mo's algorithm.
+//!
+//! See [this](https://codeforces.com/blog/entry/61203) for the Hilbert order.
+mod hilbert_order;
+mod with_updates;
+
+use hilbert_order::hilbert_order;
+pub use with_updates::MoWithUpdates;
+
+/// Answers offline queries on half-open ranges `l..r` by moving both ends one element at a time.
+///
+/// Queries are sorted in Hilbert order, so that the total number of moves is *O*(*N* √*Q*),
+/// where *N* is the maximum end of the ranges and *Q* is the number of queries.
+///
+/// # Example
+///
+/// ```
+/// use mo_algorithm::MoAlgorithm;
+///
+/// // the number of distinct values in a range
+/// let a = [1, 2, 1, 3, 2, 2];
+/// let mo = MoAlgorithm::from_iter([(0, 3), (1, 6), (2, 2), (0, 6)]);
+///
+/// // (the number of occurrences of each value, the number of distinct values)
+/// let mut state = ([0; 4], 0);
+/// let answers = mo.run(
+///     &mut state,
+///     |(count, distinct), i| {
+///         count[a[i]] += 1;
+///         if count[a[i]] == 1 {
+///             *distinct += 1
+///         }
+///     },
+///     |(count, distinct), i| {
+///         count[a[i]] -= 1;
+///         if count[a[i]] == 0 {
+///             *distinct -= 1
+///         }
+///     },
+///     |&(_, distinct)| distinct,
+/// );
+/// assert_eq!(answers, [2, 3, 0, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoAlgorithm {
+    queries: Vec<(usize, usize)>,
+    /// indices of queries in Hilbert order
+    order: Vec<usize>,
+}
+
+impl MoAlgorithm {
+    /// Processes the queries and returns the answers in the original order.
+    ///
+    /// `add(state, i)` and `remove(state, i)` are called when the `i`-th element enters or leaves the current range,
+    /// and `answer(state)` is called once per query when the current range matches it.
+    /// The range is always extended before it is shrunk, so it never becomes inverted.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N* √*Q*) calls of `add` and `remove`
+    pub fn run<S, T, A, D, F>(
+        &self,
+        state: &mut S,
+        mut add: A,
+        mut remove: D,
+        mut answer: F,
+    ) -> Vec<T>
+    where
+        A: FnMut(&mut S, usize),
+        D: FnMut(&mut S, usize),
+        F: FnMut(&S) -> T,
+    {
+        let mut res = Vec::from_iter(std::iter::repeat_with(|| None).take(self.queries.len()));
+        let (mut l, mut r) = (0, 0);
+        for &i in &self.order {
+            let (ql, qr) = self.queries[i];
+
+            while l > ql {
+                l -= 1;
+                add(state, l)
+            }
+            while r < qr {
+                add(state, r);
+                r += 1
+            }
+            while l < ql {
+                remove(state, l);
+                l += 1
+            }
+            while r > qr {
+                r -= 1;
+                remove(state, r)
+            }
+
+            res[i] = Some(answer(state))
+        }
+
+        Vec::from_iter(res.into_iter().map(Option::unwrap))
+    }
+
+    /// Returns the number of queries.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+}
+
+impl FromIterator<(usize, usize)> for MoAlgorithm {
+    /// Collects queries `(l, r)`, each of which represents `l..r`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `l > r` for some query.
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> Self {
+        let queries = Vec::from_iter(iter);
+        for &(l, r) in &queries {
+            assert!(l <= r, "invalid range: {}..{}", l, r)
+        }
+
+        let exp = queries
+            .iter()
+            .map(|&(_, r)| r)
+            .max()
+            .unwrap_or(0)
+            .next_power_of_two()
+            .ilog2()
+            + 1;
+        let h_order = Vec::from_iter(queries.iter().map(|&(l, r)| hilbert_order(l, r, exp)));
+        let mut order = Vec::from_iter(0..queries.len());
+        order.sort_unstable_by_key(|&i| h_order[i]);
+
+        Self { queries, order }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn distinct_count_random() {
+        let mut next = xorshift(0x1234_5678);
+
+        for _ in 0..20 {
+            let n = 1 + next() as usize % 200;
+            let a = Vec::from_iter((0..n).map(|_| next() as usize % 30));
+            let queries = Vec::from_iter((0..300).map(|_| {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                (l.min(r), l.max(r))
+            }));
+            let mo = MoAlgorithm::from_iter(queries.iter().copied());
+            assert_eq!(mo.len(), queries.len());
+
+            let mut state = (vec![0; 30], 0);
+            let answers = mo.run(
+                &mut state,
+                |(count, distinct), i| {
+                    count[a[i]] += 1;
+                    if count[a[i]] == 1 {
+                        *distinct += 1
+                    }
+                },
+                |(count, distinct), i| {
+                    count[a[i]] -= 1;
+                    if count[a[i]] == 0 {
+                        *distinct -= 1
+                    }
+                },
+                |&(_, distinct)| distinct,
+            );
+
+            for (&(l, r), answer) in queries.iter().zip(answers) {
+                let mut values = a[l..r].to_vec();
+                values.sort_unstable();
+                values.dedup();
+                assert_eq!(answer, values.len(), "{l}..{r}");
+            }
+        }
+    }
+
+    #[test]
+    fn no_queries() {
+        let mo = MoAlgorithm::from_iter(None);
+        assert!(mo.is_empty());
+        assert!(mo.run(&mut (), |_, _| (), |_, _| (), |_| 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverted_range() {
+        MoAlgorithm::from_iter([(3, 2)]);
+    }
+}
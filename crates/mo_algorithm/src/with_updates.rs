@@ -0,0 +1,246 @@
+use std::ops::Range;
+
+/// Answers offline queries on half-open ranges `l..r` interleaved with point updates,
+/// by moving both ends and the time one step at a time.
+///
+/// Each query is given as `(l, r, t)`, which is asked after the first `t` updates are applied.
+/// Queries are sorted by `(l / B, r / B, t)` with *B* ≈ *N*^(2/3), so that the total number of moves is
+/// *O*(*N*^(5/3)) if *N*, the number of queries and the number of updates are of the same order.
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Range;
+///
+/// use mo_algorithm::MoWithUpdates;
+///
+/// // the sum over a range, with point assignments
+/// struct State {
+///     a: Vec<i64>,
+///     updates: Vec<(usize, i64)>,
+///     sum: i64,
+/// }
+///
+/// let mut state = State { a: vec![1, 2, 3, 4], updates: vec![(1, 20), (3, 40)], sum: 0 };
+/// let mo = MoWithUpdates::new(2, [(0, 4, 0), (0, 2, 1), (1, 4, 2), (3, 4, 1)]);
+///
+/// // swapping makes the update its own inverse
+/// let swap = |s: &mut State, t: usize, range: Range<usize>| {
+///     let (i, ref mut v) = s.updates[t];
+///     if range.contains(&i) {
+///         s.sum += *v - s.a[i];
+///     }
+///     std::mem::swap(&mut s.a[i], v);
+/// };
+/// let answers = mo.run(
+///     &mut state,
+///     |s, i| s.sum += s.a[i],
+///     |s, i| s.sum -= s.a[i],
+///     swap,
+///     swap,
+///     |s| s.sum,
+/// );
+/// assert_eq!(answers, [10, 21, 63, 4]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoWithUpdates {
+    queries: Vec<(usize, usize, usize)>,
+    /// indices of queries in the sorted order
+    order: Vec<usize>,
+}
+
+impl MoWithUpdates {
+    /// Sorts queries `(l, r, t)`, each of which represents `l..r` after the first `t` of `num_updates` updates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `l > r` or `t > num_updates` for some query.
+    pub fn new<I>(num_updates: usize, queries: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, usize)>,
+    {
+        let queries = Vec::from_iter(queries);
+        for &(l, r, t) in &queries {
+            assert!(l <= r, "invalid range: {}..{}", l, r);
+            assert!(t <= num_updates, "time {} out of bounds", t);
+        }
+
+        let n = queries.iter().map(|&(_, r, _)| r).max().unwrap_or(0);
+        let block = ((n as f64).powf(2.0 / 3.0) as usize).max(1);
+        let mut order = Vec::from_iter(0..queries.len());
+        order.sort_unstable_by_key(|&i| {
+            let (l, r, t) = queries[i];
+            (l / block, r / block, t)
+        });
+
+        Self { queries, order }
+    }
+
+    /// Processes the queries and returns the answers in the original order.
+    ///
+    /// `add(state, i)` and `remove(state, i)` are called when the `i`-th element enters or leaves the current range.
+    /// `apply_update(state, t, range)` and `undo_update(state, t, range)` are called when the `t`-th update
+    /// is applied or reverted while the current range is `range`.
+    /// `answer(state)` is called once per query when the current range and time match it.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*^(5/3)) calls of the hooks, if the sizes are of the same order
+    pub fn run<S, T, A, D, U, V, F>(
+        &self,
+        state: &mut S,
+        mut add: A,
+        mut remove: D,
+        mut apply_update: U,
+        mut undo_update: V,
+        mut answer: F,
+    ) -> Vec<T>
+    where
+        A: FnMut(&mut S, usize),
+        D: FnMut(&mut S, usize),
+        U: FnMut(&mut S, usize, Range<usize>),
+        V: FnMut(&mut S, usize, Range<usize>),
+        F: FnMut(&S) -> T,
+    {
+        let mut res = Vec::from_iter(std::iter::repeat_with(|| None).take(self.queries.len()));
+        let (mut l, mut r, mut t) = (0, 0, 0);
+        for &i in &self.order {
+            let (ql, qr, qt) = self.queries[i];
+
+            while l > ql {
+                l -= 1;
+                add(state, l)
+            }
+            while r < qr {
+                add(state, r);
+                r += 1
+            }
+            while l < ql {
+                remove(state, l);
+                l += 1
+            }
+            while r > qr {
+                r -= 1;
+                remove(state, r)
+            }
+            while t < qt {
+                apply_update(state, t, l..r);
+                t += 1
+            }
+            while t > qt {
+                t -= 1;
+                undo_update(state, t, l..r)
+            }
+
+            res[i] = Some(answer(state))
+        }
+
+        Vec::from_iter(res.into_iter().map(Option::unwrap))
+    }
+
+    /// Returns the number of queries.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    struct State {
+        a: Vec<usize>,
+        /// `(position, value)`, swapped with the array on each application
+        updates: Vec<(usize, usize)>,
+        count: Vec<usize>,
+        distinct: usize,
+    }
+
+    impl State {
+        fn add(&mut self, i: usize) {
+            self.count[self.a[i]] += 1;
+            if self.count[self.a[i]] == 1 {
+                self.distinct += 1
+            }
+        }
+
+        fn remove(&mut self, i: usize) {
+            self.count[self.a[i]] -= 1;
+            if self.count[self.a[i]] == 0 {
+                self.distinct -= 1
+            }
+        }
+
+        fn swap(&mut self, t: usize, range: Range<usize>) {
+            let i = self.updates[t].0;
+            if range.contains(&i) {
+                self.remove(i);
+                std::mem::swap(&mut self.a[i], &mut self.updates[t].1);
+                self.add(i);
+            } else {
+                std::mem::swap(&mut self.a[i], &mut self.updates[t].1);
+            }
+        }
+    }
+
+    #[test]
+    fn distinct_count_with_assignments() {
+        let mut rng = xorshift(0x510e_527f);
+        let mut next = move || rng() as usize;
+
+        for _ in 0..20 {
+            let n = 1 + next() % 100;
+            let a = Vec::from_iter((0..n).map(|_| next() % 20));
+
+            // brute force, processing operations online
+            let mut b = a.clone();
+            let (mut updates, mut queries, mut expected) = (vec![], vec![], vec![]);
+            for _ in 0..300 {
+                if next() % 3 == 0 {
+                    let (i, v) = (next() % n, next() % 20);
+                    b[i] = v;
+                    updates.push((i, v));
+                } else {
+                    let (l, r) = (next() % (n + 1), next() % (n + 1));
+                    let (l, r) = (l.min(r), l.max(r));
+                    queries.push((l, r, updates.len()));
+
+                    let mut values = b[l..r].to_vec();
+                    values.sort_unstable();
+                    values.dedup();
+                    expected.push(values.len());
+                }
+            }
+
+            let mo = MoWithUpdates::new(updates.len(), queries.iter().copied());
+            assert_eq!(mo.len(), queries.len());
+            let mut state = State {
+                a,
+                updates,
+                count: vec![0; 20],
+                distinct: 0,
+            };
+            let answers = mo.run(
+                &mut state,
+                State::add,
+                State::remove,
+                State::swap,
+                State::swap,
+                |s| s.distinct,
+            );
+            assert_eq!(answers, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn time_out_of_bounds() {
+        MoWithUpdates::new(1, [(0, 1, 2)]);
+    }
+}
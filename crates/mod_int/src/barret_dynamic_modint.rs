@@ -9,6 +9,7 @@ use rustc_hash::FxHashMap;
 use crate::{
     inv_gcd,
     macros::{forward_ref_mint_binop, forward_ref_mint_op_assign, forward_ref_mint_unop},
+    PowTable,
 };
 
 /// Owner and factory for [`BDMint`] instances with the same modulus.
@@ -102,6 +103,29 @@ impl Barret {
             x.wrapping_add(self.modulus)
         }
     }
+
+    /// Precomputes powers of `base` for [`PowTable::pow`], which is faster than [`BDMint::pow`]
+    /// when the same `base` is raised to many exponents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::Barret;
+    ///
+    /// let barret = Barret::new(1_000_000_007);
+    /// let table = barret.pow_precompute(3);
+    ///
+    /// assert_eq!(table.pow(0), barret.mint(1));
+    /// assert_eq!(table.pow(20), barret.mint(3).pow(20));
+    /// assert_eq!(table.pow(1_000_000_006), barret.mint(1));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1), but 256 multiplications
+    pub fn pow_precompute(&self, base: u64) -> PowTable<BDMint<'_>> {
+        PowTable::new(self.mint(base), self.mint(1))
+    }
 }
 
 /// Modular integer with a runtime-specified modulus based on
@@ -345,7 +369,7 @@ impl AddAssign for BDMint<'_> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         self.value += rhs.value;
-        if self.value > self.barret.modulus {
+        if self.value >= self.barret.modulus {
             self.value -= self.barret.modulus
         }
     }
@@ -382,3 +406,55 @@ impl Neg for BDMint<'_> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn add_at_modulus_boundary() {
+        for m in [1_u32, 2, 3, 7, 998_244_353, u32::MAX] {
+            let barret = Barret::new(m);
+            let m = m as u64;
+            // (lhs, rhs) summing to `M`, `M + 1` and `2M - 1`
+            for (lhs, rhs) in [(m - 1, 1), (1, m - 1), (m / 2, m - m / 2), (m - 1, m - 1)]
+                .into_iter()
+                .chain((m > 1).then_some((m - 1, 2)))
+            {
+                let sum = barret.mint(lhs) + barret.mint(rhs);
+                assert!(sum.value() < m);
+                assert_eq!(sum.value(), (lhs + rhs) % m);
+                assert_eq!(sum, barret.mint(lhs + rhs));
+            }
+            assert_eq!(barret.mint(m - 1) + barret.mint(1), barret.mint(0));
+        }
+    }
+
+    #[test]
+    fn pow_table_matches_pow() {
+        let mut next = xorshift(0xbb67_ae85);
+
+        for m in [1_u32, 2, 1_000_000, 998_244_353, u32::MAX] {
+            let barret = Barret::new(m);
+            for _ in 0..20 {
+                let base = next();
+                let table = barret.pow_precompute(base);
+                let base = barret.mint(base);
+
+                for exp in [0, 1, 15, 16, 17, 255, 256] {
+                    assert_eq!(table.pow(exp), base.pow(exp as u32));
+                }
+                for _ in 0..50 {
+                    let exp = next();
+                    // base^exp = (base^(2^32))^hi * base^lo
+                    let (hi, lo) = ((exp >> 32) as u32, exp as u32);
+                    let expected = base.pow(1 << 16).pow(1 << 16).pow(hi) * base.pow(lo);
+                    assert_eq!(table.pow(exp), expected);
+                    assert_eq!(table.pow(lo as u64), base.pow(lo));
+                }
+            }
+        }
+    }
+}
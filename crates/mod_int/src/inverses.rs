@@ -0,0 +1,57 @@
+/// Returns `inv` such that `i * inv[i] = 1 mod p` for each `1 <= i <= n`. `inv[0]` is set to `0`.
+///
+/// Uses the recurrence `inv[i] = -(p / i) * inv[p % i] mod p`, which is much faster than
+/// computing each inverse independently.
+///
+/// # Panics
+///
+/// Panics if `n >= p`. `p` should be prime, otherwise the result is meaningless.
+///
+/// # Time complexity
+///
+/// *O*(*N*)
+pub fn inverses_up_to(n: usize, p: u64) -> Vec<u64> {
+    assert!((n as u64) < p, "{n} has no inverse modulo {p}");
+
+    let mut inv = vec![0; n + 1];
+    if n >= 1 {
+        inv[1] = 1;
+    }
+    for i in 2..=n {
+        let q = p / i as u64;
+        let x = (q as u128 * inv[(p % i as u64) as usize] as u128 % p as u128) as u64;
+        inv[i] = (p - x) % p;
+    }
+
+    inv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverses() {
+        for (n, p) in [(0, 2), (1, 2), (2, 3), (100_000, 998_244_353), (10, 11), (1000, 1009)] {
+            let inv = inverses_up_to(n, p);
+            assert_eq!(inv.len(), n + 1);
+            assert_eq!(inv[0], 0);
+            for (i, inv_i) in inv.into_iter().enumerate().skip(1) {
+                assert_eq!(inv_i * i as u64 % p, 1, "i = {i}, p = {p}");
+            }
+        }
+
+        // large modulus
+        let p = (1 << 61) - 1;
+        let inv = inverses_up_to(1000, p);
+        for (i, inv_i) in inv.into_iter().enumerate().skip(1) {
+            assert_eq!(inv_i as u128 * i as u128 % p as u128, 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiple_of_modulus() {
+        inverses_up_to(7, 7);
+    }
+}
@@ -5,10 +5,11 @@
 //! ## [`SMint`]
 //!
 //! * Compile-time fixed non-zero modulus
+//! * Implements [`Ring`](math_traits::Ring) and [`Field`](math_traits::Field) for generic algorithms
 //!
 //! ## [`MDMint`]
 //!
-//! * Runtime-specified *odd* modulus
+//! * Runtime-specified *odd* modulus, or any non-zero modulus with [`Montgomery::new_any`]
 //! * May be faster than [`BDMint`]
 //!
 //! ## [`BDMint`]
@@ -27,6 +28,11 @@
 //! [^1]: More precisely, same cost as Euclidean GCD algorithm.
 //!
 //!
+//! # Features
+//!
+//! * `serde`: implements `Serialize` for all modular integers and `Deserialize` for [`SMint`].
+//!
+//!
 //! # References
 //!
 //! ## Montgomery reduction
@@ -38,11 +44,18 @@
 //! * [wiki](https://en.wikipedia.org/wiki/Barrett_reduction)
 mod barret_dynamic_modint;
 mod inv_gcd;
+mod inverses;
 mod macros;
 mod montgomery_dynamic_modint;
+mod pow_table;
+mod ring_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod static_modint;
 
 pub use barret_dynamic_modint::{BDMint, Barret};
 pub(self) use inv_gcd::inv_gcd;
+pub use inverses::inverses_up_to;
 pub use montgomery_dynamic_modint::{MDMint, Montgomery};
+pub use pow_table::PowTable;
 pub use static_modint::SMint;
@@ -12,10 +12,21 @@ use crate::{
 /// Owner and factory for [`MDMint`] instances with the same modulus.
 ///
 /// To use a different modulus, create a new [`Montgomery`] with the desired modulus.
+///
+/// Montgomery reduction applies only to the odd part of the modulus.
+/// For an even modulus `2^k * m`, see [`new_any`](Self::new_any).
 pub struct Montgomery {
     modulus: u64,
+    /// the odd part of `modulus`
+    odd: u64,
+    /// `-inv(odd) mod RADIX`
     neg_inv_modulus_mod_radix: u64,
+    /// `RADIX^2 mod odd`
     radix2_mod_modulus: u64,
+    /// `2^k - 1`, where `2^k` is the largest power of two dividing `modulus`
+    mask: u64,
+    /// `inv(odd) mod 2^k`
+    inv_odd_mod_pow2: u64,
 }
 
 impl Montgomery {
@@ -28,7 +39,39 @@ impl Montgomery {
     /// `modulus` should be an positive odd integer.
     pub const fn new(modulus: u32) -> Self {
         assert!(modulus % 2 == 1, "modulus should be an odd integer");
-        let modulus = modulus as u64;
+        Self::new_any(modulus)
+    }
+
+    /// Creates a new [`Montgomery`] with the given `modulus`, which may be even.
+    ///
+    /// An even modulus `2^k * m` is split into the odd part `m`, handled by Montgomery reduction,
+    /// and `2^k`, handled by bit masks. They are combined by CRT only in [`MDMint::value`].
+    ///
+    /// # Performance note
+    ///
+    /// For an odd `modulus`, the result is the same as [`new`](Self::new) and so is the performance.
+    /// Otherwise, every operation on [`MDMint`] also updates the `2^k` part, which costs a branch and a few bit operations,
+    /// and [`MDMint::value`] additionally needs one multiplication for CRT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::Montgomery;
+    ///
+    /// let montgomery = Montgomery::new_any(1_000_000);
+    /// let x = montgomery.mint(123_456) * montgomery.mint(654_321);
+    /// assert_eq!(x.value(), 123_456 * 654_321 % 1_000_000);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub const fn new_any(modulus: u32) -> Self {
+        assert!(modulus != 0, "modulus should be positive");
+        let k = modulus.trailing_zeros();
+        let mask = (1 << k) - 1;
+        let full_modulus = modulus as u64;
+        let modulus = (modulus >> k) as u64;
 
         // r^2 mod m = r^2 - m mod m in u64 for r = 2^32.
         let radix2_mod_modulus = modulus.wrapping_neg() % modulus;
@@ -51,9 +94,13 @@ impl Montgomery {
         assert!(modulus.wrapping_mul(inv_modulus_mod_radix) % Self::RADIX == 1);
 
         Self {
-            modulus,
+            modulus: full_modulus,
+            odd: modulus,
             neg_inv_modulus_mod_radix: Self::RADIX - inv_modulus_mod_radix, // im > 0
             radix2_mod_modulus,
+            mask,
+            // inverse modulo `RADIX = 2^32` is also the one modulo `2^k` for `k < 32`
+            inv_odd_mod_pow2: inv_modulus_mod_radix & mask,
         }
     }
 
@@ -64,24 +111,25 @@ impl Montgomery {
 
         MDMint {
             r_value,
+            low: value as u64 & self.mask,
             montgomery: self,
         }
     }
 
-    /// Returns `x * inv(RADIX) mod modulus` if `x < modulus * RADIX`
+    /// Returns `x * inv(RADIX) mod odd` if `x < odd * RADIX`
     const fn reduce(&self, x: u64) -> u64 {
-        assert!(x < self.modulus * Self::RADIX);
+        assert!(x < self.odd * Self::RADIX);
 
         // s * m = x * m * im = s * (r * ir - 1) = -x mod r => x + s * m = 0 mod r
         let s = (x % Self::RADIX) * self.neg_inv_modulus_mod_radix % Self::RADIX;
         // s * m + (r - 1) <= (r - 1)^2 + (r - 1) = r * (r - 1) < r^2 => non-overflowing
-        let t = x / Self::RADIX + (x % Self::RADIX + s * self.modulus) / Self::RADIX;
+        let t = x / Self::RADIX + (x % Self::RADIX + s * self.odd) / Self::RADIX;
 
         // 0 <= x + s * m < m * r + r * m < 2 * m * r => t < 2 * m
-        if t < self.modulus {
+        if t < self.odd {
             t
         } else {
-            t - self.modulus
+            t - self.odd
         }
     }
 }
@@ -100,15 +148,25 @@ impl Montgomery {
 /// To use [`MDMint`] with a different modulus, create a new [`Montgomery`] instance.
 #[derive(Clone, Copy)]
 pub struct MDMint<'a> {
-    /// x * RADIX mod modulus
+    /// x * RADIX mod odd
     r_value: u64,
+    /// x mod 2^k, always `0` for odd moduli
+    low: u64,
     montgomery: &'a Montgomery,
 }
 
 impl MDMint<'_> {
     /// Returns the value.
     pub const fn value(&self) -> u64 {
-        self.montgomery.reduce(self.r_value)
+        let mont = self.montgomery;
+        let a = mont.reduce(self.r_value);
+        if mont.mask == 0 {
+            return a;
+        }
+
+        // CRT: x = a + odd * t, where odd * t = low - a mod 2^k
+        let t = self.low.wrapping_sub(a).wrapping_mul(mont.inv_odd_mod_pow2) & mont.mask;
+        a + mont.odd * t
     }
 
     /// Returns the modulus.
@@ -131,12 +189,10 @@ impl MDMint<'_> {
     }
 
     /// Returns the inverse of `self` if exists.
-    pub const fn inv(mut self) -> Option<Self> {
+    pub const fn inv(self) -> Option<Self> {
         if let Some((inv, 1)) = inv_gcd(self.value(), self.modulus()) {
-            let mont = self.montgomery;
-
-            self.r_value = mont.reduce(mont.radix2_mod_modulus * inv);
-            return Some(self);
+            // `inv < modulus <= u32::MAX`
+            return Some(self.montgomery.mint(inv as u32));
         }
 
         None
@@ -162,6 +218,9 @@ impl Hash for MDMint<'_> {
     #[inline]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.r_value.hash(state);
+        if self.montgomery.mask != 0 {
+            self.low.hash(state);
+        }
         self.montgomery.modulus.hash(state);
     }
 }
@@ -169,7 +228,8 @@ impl Hash for MDMint<'_> {
 impl PartialEq for MDMint<'_> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.r_value == other.r_value
+        // `low` is always `0` for odd moduli
+        self.r_value == other.r_value && (self.montgomery.mask == 0 || self.low == other.low)
     }
 }
 
@@ -233,9 +293,13 @@ forward_ref_mint_op_assign!( impl<'a> MulAssign, mul_assign for MDMint<'a> );
 impl AddAssign for MDMint<'_> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
+        let odd = self.montgomery.odd;
         self.r_value += rhs.r_value;
-        if self.r_value > self.modulus() {
-            self.r_value -= self.modulus()
+        if self.r_value >= odd {
+            self.r_value -= odd
+        }
+        if self.montgomery.mask != 0 {
+            self.low = (self.low + rhs.low) & self.montgomery.mask
         }
     }
 }
@@ -243,9 +307,13 @@ impl AddAssign for MDMint<'_> {
 impl SubAssign for MDMint<'_> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
+        let odd = self.montgomery.odd;
         self.r_value = self.r_value.wrapping_sub(rhs.r_value);
-        if self.r_value >= self.modulus() {
-            self.r_value = self.r_value.wrapping_add(self.modulus());
+        if self.r_value >= odd {
+            self.r_value = self.r_value.wrapping_add(odd);
+        }
+        if self.montgomery.mask != 0 {
+            self.low = self.low.wrapping_sub(rhs.low) & self.montgomery.mask
         }
     }
 }
@@ -254,7 +322,11 @@ impl MulAssign for MDMint<'_> {
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
         // v1 * v2 < m * m < m * r
-        self.r_value = self.montgomery.reduce(self.r_value * rhs.r_value)
+        self.r_value = self.montgomery.reduce(self.r_value * rhs.r_value);
+        if self.montgomery.mask != 0 {
+            // l1 * l2 < 2^k * 2^k <= 2^62
+            self.low = (self.low * rhs.low) & self.montgomery.mask
+        }
     }
 }
 
@@ -266,9 +338,87 @@ impl Neg for MDMint<'_> {
     #[inline]
     fn neg(mut self) -> Self::Output {
         if self.r_value > 0 {
-            self.r_value = self.montgomery.modulus - self.r_value;
+            self.r_value = self.montgomery.odd - self.r_value;
+        }
+        if self.montgomery.mask != 0 {
+            self.low = self.low.wrapping_neg() & self.montgomery.mask;
         }
 
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn add_at_modulus_boundary() {
+        for m in [1_u32, 3, 7, 998_244_353, u32::MAX] {
+            let montgomery = Montgomery::new(m);
+            // (lhs, rhs) summing to `M`, `M + 1` and `2M - 1`
+            for (lhs, rhs) in [(m - 1, 1), (1, m - 1), (m / 2, m - m / 2), (m - 1, m - 1)]
+                .into_iter()
+                .chain((m > 1).then_some((m - 1, 2)))
+            {
+                let sum = montgomery.mint(lhs) + montgomery.mint(rhs);
+                let expected = (lhs as u64 + rhs as u64) % m as u64;
+                assert!(sum.value() < m as u64);
+                assert_eq!(sum.value(), expected);
+                assert_eq!(sum, montgomery.mint(expected as u32));
+            }
+            assert_eq!(
+                montgomery.mint(m - 1) + montgomery.mint(1),
+                montgomery.mint(0)
+            );
+        }
+    }
+
+    #[test]
+    fn even_moduli_match_barret() {
+        use crate::Barret;
+
+        let mut next = xorshift(0x6a09_e667);
+
+        for m in [
+            1_000_000_u32,
+            2,
+            4,
+            6,
+            1 << 31,
+            998_244_352,
+            3 << 30,
+            u32::MAX - 1,
+            // odd moduli take the same path as `Montgomery::new`
+            7,
+            998_244_353,
+        ] {
+            let montgomery = Montgomery::new_any(m);
+            let barret = Barret::new(m);
+            assert_eq!(montgomery.mint(0).modulus(), m as u64);
+
+            for _ in 0..1000 {
+                let (a, b) = (next() as u32, next() as u32);
+                let (ma, mb) = (montgomery.mint(a), montgomery.mint(b));
+                let (ba, bb) = (barret.mint(a as u64), barret.mint(b as u64));
+
+                assert_eq!(ma.value(), ba.value());
+                assert_eq!((ma * mb).value(), (ba * bb).value());
+                assert_eq!((ma + mb).value(), (ba + bb).value());
+                assert_eq!((ma - mb).value(), (ba - bb).value());
+                assert_eq!((-ma).value(), (-ba).value());
+                assert_eq!(ma.pow(b % 100).value(), ba.pow(b % 100).value());
+                assert_eq!(ma.inv().map(|v| v.value()), ba.inv().map(|v| v.value()));
+                assert_eq!(ma == mb, ba == bb);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_modulus_needs_new_any() {
+        Montgomery::new(1_000_000);
+    }
+}
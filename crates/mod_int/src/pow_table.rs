@@ -0,0 +1,65 @@
+use std::ops::MulAssign;
+
+/// Powers of a fixed base created by [`Barret::pow_precompute`](crate::Barret::pow_precompute)
+/// or [`SMint::pow_precompute`](crate::SMint::pow_precompute).
+///
+/// # Performance note
+///
+/// The table holds `base^(d * 16^j)` for every 4-bit digit `d` and position `j` of `u64` exponents,
+/// that is, 256 elements.
+/// In return, [`pow`](Self::pow) takes at most 16 multiplications and no squaring,
+/// while exponentiation by squaring takes up to 128 multiplications for 64-bit exponents.
+#[derive(Debug, Clone)]
+pub struct PowTable<M> {
+    /// `table[j * RADIX + d] = base^(d * RADIX^j)`
+    table: Box<[M]>,
+}
+
+impl<M: Copy + MulAssign> PowTable<M> {
+    const RADIX: usize = 1 << 4;
+    const DIGITS: usize = u64::BITS as usize / 4;
+
+    /// Precomputes powers of `base`, where `one` is the multiplicative identity.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1), but 256 multiplications
+    pub(crate) fn new(mut base: M, one: M) -> Self {
+        let mut table = Vec::with_capacity(Self::DIGITS * Self::RADIX);
+        for _ in 0..Self::DIGITS {
+            let mut power = one;
+            for _ in 0..Self::RADIX {
+                table.push(power);
+                power *= base;
+            }
+            // base^16
+            base = power;
+        }
+
+        Self {
+            table: table.into_boxed_slice(),
+        }
+    }
+
+    /// Returns `base^exp`, where `0^0 = 1`.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1), at most 16 multiplications
+    pub fn pow(&self, mut exp: u64) -> M {
+        // `table[0] = base^0`
+        let mut res = self.table[0];
+        for digits in self.table.chunks_exact(Self::RADIX) {
+            if exp == 0 {
+                break;
+            }
+            let d = exp as usize % Self::RADIX;
+            if d != 0 {
+                res *= digits[d]
+            }
+            exp /= Self::RADIX as u64
+        }
+
+        res
+    }
+}
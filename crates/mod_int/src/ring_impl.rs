@@ -0,0 +1,75 @@
+//! [`Ring`] and [`Field`] from [`math_traits`].
+//!
+//! Only [`SMint`] implements them, since dynamic modular integers cannot create
+//! [`Ring::zero`] or [`Ring::one`] without [`Barret`](crate::Barret) or [`Montgomery`](crate::Montgomery).
+
+use math_traits::{Field, Ring};
+
+use crate::SMint;
+
+impl<const MOD: u64> Ring for SMint<MOD> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    #[inline]
+    fn add(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    #[inline]
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+
+    #[inline]
+    fn neg(&self) -> Self {
+        -*self
+    }
+}
+
+/// Only units are invertible if the modulus is composite.
+impl<const MOD: u64> Field for SMint<MOD> {
+    #[inline]
+    fn inv(&self) -> Option<Self> {
+        SMint::inv(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Mint = SMint<998_244_353>;
+
+    #[test]
+    fn ring_operations() {
+        for x in -50_i64..50 {
+            for y in -50_i64..50 {
+                let (mx, my) = (Mint::from(x), Mint::from(y));
+                assert_eq!(Ring::add(&mx, &my), Mint::from(x + y));
+                assert_eq!(Ring::mul(&mx, &my), Mint::from(x * y));
+            }
+            assert_eq!(Ring::neg(&Mint::from(x)), Mint::from(-x));
+        }
+        assert_eq!(Mint::zero(), Mint::new(0));
+        assert_eq!(Mint::one().neg(), Mint::new(Mint::MODULUS - 1));
+    }
+
+    #[test]
+    fn field_inverses() {
+        for x in 1..100 {
+            let x = Mint::new(x);
+            assert_eq!(Field::inv(&x).map(|inv| x.mul(&inv)), Some(Mint::one()));
+        }
+        assert_eq!(Field::inv(&Mint::zero()), None);
+        assert_eq!(Field::inv(&SMint::<12>::new(4)), None);
+        assert_eq!(Field::inv(&SMint::<12>::new(5)), Some(SMint::new(5)));
+    }
+}
@@ -0,0 +1,89 @@
+//! Optional [`serde`] support.
+//!
+//! [`SMint`] is serialized as its value, since the modulus is a part of the type.
+//! Dynamic modular integers are serialized as `(value, modulus)` pairs.
+//! They cannot be deserialized because they borrow [`Barret`](crate::Barret) or [`Montgomery`](crate::Montgomery).
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{BDMint, MDMint, SMint};
+
+impl<const MOD: u64> Serialize for SMint<MOD> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value().serialize(serializer)
+    }
+}
+
+impl<'de, const MOD: u64> Deserialize<'de> for SMint<MOD> {
+    /// Fails if the value is not less than the modulus.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        if value < MOD {
+            Ok(Self::new(value))
+        } else {
+            Err(D::Error::custom(format_args!(
+                "value {value} is not less than modulus {MOD}"
+            )))
+        }
+    }
+}
+
+impl Serialize for BDMint<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.value(), self.modulus()).serialize(serializer)
+    }
+}
+
+impl Serialize for MDMint<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.value(), self.modulus()).serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+
+    use super::*;
+    use crate::{Barret, Montgomery};
+
+    type Mint = SMint<998_244_353>;
+
+    #[test]
+    fn round_trip() {
+        assert_tokens(&Mint::new(5), &[Token::U64(5)]);
+        assert_tokens(&Mint::new(998_244_352), &[Token::U64(998_244_352)]);
+        assert_tokens(
+            &[Mint::new(1), Mint::new(3), Mint::new(9)],
+            &[
+                Token::Tuple { len: 3 },
+                Token::U64(1),
+                Token::U64(3),
+                Token::U64(9),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens_error::<Mint>(
+            &[Token::U64(998_244_353)],
+            "value 998244353 is not less than modulus 998244353",
+        );
+    }
+
+    #[test]
+    fn dynamic_pairs() {
+        let pair = |value, modulus| {
+            [
+                Token::Tuple { len: 2 },
+                Token::U64(value),
+                Token::U64(modulus),
+                Token::TupleEnd,
+            ]
+        };
+
+        let barret = Barret::new(1_000);
+        assert_ser_tokens(&barret.mint(1_234), &pair(234, 1_000));
+
+        let montgomery = Montgomery::new(999);
+        assert_ser_tokens(&montgomery.mint(1_234), &pair(235, 999));
+    }
+}
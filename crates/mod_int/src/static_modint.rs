@@ -1,12 +1,13 @@
 use std::{
     fmt::{Debug, Display},
     iter::{Product, Sum},
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 use crate::{
     inv_gcd,
     macros::{forward_ref_mint_binop, forward_ref_mint_op_assign, forward_ref_mint_unop},
+    PowTable,
 };
 
 /// Modular integer with a compile-time fixed modulus.
@@ -18,15 +19,66 @@ pub struct SMint<const MOD: u64> {
 impl<const MOD: u64> SMint<MOD> {
     const MAX_MOD: u64 = 1 << (u64::BITS / 2);
 
-    pub const fn new(value: u64) -> Self {
+    /// Evaluated at compile time whenever an instance is created, so that invalid moduli are rejected.
+    const CHECK_MODULUS: () = {
+        assert!(MOD != 0, "modulus should not be zero");
         assert!(
             MOD <= Self::MAX_MOD,
             "modulus should be less than or equal to 2^32"
         );
+    };
+
+    /// The modulus. Unlike [modulus](Self::modulus), no instance is needed.
+    pub const MODULUS: u64 = MOD;
+
+    /// Creates a modular integer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::SMint;
+    ///
+    /// assert_eq!(SMint::<7>::new(10).value(), 3);
+    /// ```
+    ///
+    /// The modulus is checked at compile time.
+    ///
+    /// ```compile_fail
+    /// use mod_int::SMint;
+    ///
+    /// let zero_modulus = SMint::<0>::new(10);
+    /// ```
+    pub const fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_MODULUS;
 
         Self { value: value % MOD }
     }
 
+    /// Creates a modular integer without reduction.
+    ///
+    /// The caller must guarantee `value < MOD`.
+    /// Otherwise the result is not memory-unsafe, but any arithmetic on it yields incorrect results.
+    /// The guarantee is checked only in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::SMint;
+    ///
+    /// type Mint = SMint<998_244_353>;
+    ///
+    /// let x = Mint::from_raw(Mint::MODULUS - 1);
+    /// assert_eq!(x + Mint::from_raw(2), Mint::new(1));
+    /// ```
+    pub const fn from_raw(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_MODULUS;
+        debug_assert!(value < MOD);
+
+        Self { value }
+    }
+
     /// Returns the value.
     pub const fn value(&self) -> u64 {
         self.value
@@ -37,8 +89,45 @@ impl<const MOD: u64> SMint<MOD> {
         MOD
     }
 
+    /// Returns `(value, modulus)` for diagnostics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::SMint;
+    ///
+    /// let x = SMint::<998_244_353>::new(1 << 40);
+    /// assert_eq!(x.debug_repr(), (x.value(), 998_244_353));
+    /// assert_eq!(format!("{}", x), x.value().to_string());
+    /// ```
+    pub const fn debug_repr(&self) -> (u64, u64) {
+        (self.value, MOD)
+    }
+
     /// Raises `self` to the power of `exp`, using exponentiation by squaring.
-    pub fn pow(mut self, mut exp: u32) -> Self {
+    pub fn pow(self, exp: u32) -> Self {
+        self.pow_u64(u64::from(exp))
+    }
+
+    /// Same as [pow](Self::pow), but accepts 64-bit exponents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::SMint;
+    ///
+    /// type Mint = SMint<998_244_353>;
+    ///
+    /// // Fermat's little theorem
+    /// let exp = (Mint::MODULUS - 1) * 10 + 3;
+    /// assert!(exp > u32::MAX as u64);
+    /// assert_eq!(Mint::new(2).pow_u64(exp), Mint::new(8));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log `exp`)
+    pub fn pow_u64(mut self, mut exp: u64) -> Self {
         let mut res = Self::new(1);
         while exp > 0 {
             if exp & 1 == 1 {
@@ -51,6 +140,30 @@ impl<const MOD: u64> SMint<MOD> {
         res
     }
 
+    /// Precomputes powers of `self` for [`PowTable::pow`], which is faster than [`SMint::pow_u64`]
+    /// when the same base is raised to many exponents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::SMint;
+    ///
+    /// type Mint = SMint<1_000_000_007>;
+    ///
+    /// let table = Mint::new(3).pow_precompute();
+    ///
+    /// assert_eq!(table.pow(0), Mint::new(1));
+    /// assert_eq!(table.pow(20), Mint::new(3).pow(20));
+    /// assert_eq!(table.pow(u64::MAX), Mint::new(3).pow_u64(u64::MAX));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1), but 256 multiplications
+    pub fn pow_precompute(self) -> PowTable<Self> {
+        PowTable::new(self, Self::new(1))
+    }
+
     /// Returns the inverse of `self` if exists.
     pub const fn inv(mut self) -> Option<Self> {
         if let Some((inv, 1)) = inv_gcd(self.value, MOD) {
@@ -60,6 +173,22 @@ impl<const MOD: u64> SMint<MOD> {
 
         None
     }
+
+    /// Returns `self / rhs`, or `None` if `rhs` has no inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mod_int::SMint;
+    ///
+    /// type Mint = SMint<12>;
+    ///
+    /// assert_eq!(Mint::new(3).checked_div(Mint::new(5)), Some(Mint::new(3)));
+    /// assert_eq!(Mint::new(4).checked_div(Mint::new(2)), None);
+    /// ```
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs.inv()?)
+    }
 }
 
 impl<const MOD: u64> Debug for SMint<MOD> {
@@ -71,12 +200,28 @@ impl<const MOD: u64> Debug for SMint<MOD> {
     }
 }
 
+/// Shows the canonical value in `[0, MOD)` only, respecting flags such as width and fill.
 impl<const MOD: u64> Display for SMint<MOD> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value())
+        Display::fmt(&self.value, f)
     }
 }
 
+macro_rules! from_int_impl {
+    ( $( $t:ty )* ) => {$(
+        impl<const MOD: u64> From<$t> for SMint<MOD> {
+            /// Reduces `value` into `[0, MOD)`, so that negative values wrap around.
+            #[inline]
+            fn from(value: $t) -> Self {
+                // every type here fits in `i128`
+                Self::from_raw((value as i128).rem_euclid(MOD as i128) as u64)
+            }
+        }
+    )*};
+}
+
+from_int_impl! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 usize }
+
 impl<const MOD: u64> Sum for SMint<MOD> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(Self::new(0), |acc, x| acc + x)
@@ -92,6 +237,7 @@ impl<const MOD: u64> Product for SMint<MOD> {
 forward_ref_mint_binop!( impl<const MOD: u64> Add, add for SMint<MOD> );
 forward_ref_mint_binop!( impl<const MOD: u64> Sub, sub for SMint<MOD> );
 forward_ref_mint_binop!( impl<const MOD: u64> Mul, mul for SMint<MOD> );
+forward_ref_mint_binop!( impl<const MOD: u64> Div, div for SMint<MOD> );
 
 impl<const MOD: u64> Add for SMint<MOD> {
     type Output = Self;
@@ -126,9 +272,24 @@ impl<const MOD: u64> Mul for SMint<MOD> {
     }
 }
 
+impl<const MOD: u64> Div for SMint<MOD> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` has no inverse. See [`checked_div`](SMint::checked_div).
+    #[inline]
+    fn div(mut self, rhs: Self) -> Self::Output {
+        self /= rhs;
+
+        self
+    }
+}
+
 forward_ref_mint_op_assign!( impl<const MOD: u64> AddAssign, add_assign for SMint<MOD> );
 forward_ref_mint_op_assign!( impl<const MOD: u64> SubAssign, sub_assign for SMint<MOD> );
 forward_ref_mint_op_assign!( impl<const MOD: u64> MulAssign, mul_assign for SMint<MOD> );
+forward_ref_mint_op_assign!( impl<const MOD: u64> DivAssign, div_assign for SMint<MOD> );
 
 impl<const MOD: u64> AddAssign for SMint<MOD> {
     #[inline]
@@ -151,6 +312,19 @@ impl<const MOD: u64> MulAssign for SMint<MOD> {
     }
 }
 
+impl<const MOD: u64> DivAssign for SMint<MOD> {
+    /// # Panics
+    ///
+    /// Panics if `rhs` has no inverse. See [`checked_div`](SMint::checked_div).
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        match rhs.inv() {
+            Some(inv) => self.value = self.value * inv.value % MOD,
+            None => panic!("{} has no inverse modulo {}", rhs.value, MOD),
+        }
+    }
+}
+
 forward_ref_mint_unop!( impl<const MOD: u64> Neg, neg for SMint<MOD> );
 
 impl<const MOD: u64> Neg for SMint<MOD> {
@@ -168,6 +342,8 @@ impl<const MOD: u64> Neg for SMint<MOD> {
 
 #[cfg(test)]
 mod test {
+    use test_utils::xorshift;
+
     use super::*;
 
     #[test]
@@ -191,4 +367,132 @@ mod test {
         let m = m * SMint::new(1_000_000_000);
         assert_eq!(m.inv(), None)
     }
+
+    #[test]
+    fn from_raw_in_range() {
+        type Mint = SMint<1_000_000_007>;
+
+        for x in [0, 1, 2, 12345, Mint::MODULUS / 2, Mint::MODULUS - 1] {
+            let raw = Mint::from_raw(x);
+            assert_eq!(raw.value(), x);
+            assert_eq!(raw, Mint::new(x));
+
+            for y in [0, 1, 999, Mint::MODULUS - 1] {
+                assert_eq!(raw + Mint::from_raw(y), Mint::new(x + y));
+                assert_eq!(raw * Mint::from_raw(y), Mint::new(x * y));
+                assert_eq!(raw - Mint::from_raw(y), Mint::new(x + Mint::MODULUS - y));
+            }
+        }
+        assert_eq!(Mint::MODULUS, Mint::new(0).modulus());
+    }
+
+    #[test]
+    fn div_composite() {
+        const MOD: u64 = 2 * 3 * 7;
+        type Mint = SMint<MOD>;
+
+        for a in 0..MOD {
+            for b in 0..MOD {
+                let (a, b) = (Mint::new(a), Mint::new(b));
+                let coprime = (1..MOD).any(|x| b.value() * x % MOD == 1);
+                assert_eq!(a.checked_div(b).is_some(), coprime);
+
+                if let Some(q) = a.checked_div(b) {
+                    assert_eq!(q * b, a);
+                    assert_eq!(a / b, q);
+
+                    let mut c = a;
+                    c /= b;
+                    assert_eq!(c, q);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "14 has no inverse modulo 42")]
+    fn div_by_non_invertible() {
+        let _ = SMint::<42>::new(1) / SMint::new(14);
+    }
+
+    #[test]
+    fn from_signed_and_unsigned() {
+        type Mint = SMint<998_244_353>;
+
+        assert_eq!(Mint::from(-1_i64), Mint::new(Mint::MODULUS - 1));
+        assert_eq!(Mint::from(-1_i8), Mint::new(Mint::MODULUS - 1));
+        assert_eq!(Mint::from(0_i32), Mint::new(0));
+        assert_eq!(Mint::from(-(Mint::MODULUS as i64)), Mint::new(0));
+        assert_eq!(Mint::from(i64::MIN), -Mint::new(i64::MIN.unsigned_abs()));
+        assert_eq!(Mint::from(u64::MAX), Mint::new(u64::MAX));
+        assert_eq!(Mint::from(3 * Mint::MODULUS as usize + 5), Mint::new(5));
+
+        for v in -100_i64..100 {
+            let x = Mint::from(v);
+            assert!(x.value() < Mint::MODULUS);
+            assert_eq!(x + Mint::from(-v), Mint::new(0));
+            assert_eq!(Mint::from(v as i32), x);
+            assert_eq!(SMint::<7>::from(v).value(), v.rem_euclid(7) as u64);
+        }
+    }
+
+    #[test]
+    fn display_value_only() {
+        type Mint = SMint<998_244_353>;
+
+        for x in [0, 1, 998_244_352, 998_244_353, 1 << 40, u64::MAX] {
+            let x = Mint::new(x);
+            assert_eq!(format!("{}", x), x.value().to_string());
+            assert_eq!(format!("{:>12}", x), format!("{:>12}", x.value()));
+            assert_eq!(x.debug_repr(), (x.value(), Mint::MODULUS));
+        }
+        assert_eq!(format!("{}", -Mint::new(1)), "998244352");
+        assert_eq!(
+            format!("{:?}", SMint::<7>::new(10)),
+            "SMint { value: 3, modulus: 7 }"
+        );
+    }
+
+    #[test]
+    fn pow_u64_and_table() {
+        let mut next = xorshift(0x3c6e_f372);
+
+        type Mint = SMint<998_244_353>;
+
+        for base in [0, 1, 2, 3, 998_244_352, next(), next()] {
+            let base = Mint::new(base);
+            let table = base.pow_precompute();
+
+            // repeated multiplication
+            let mut expected = Mint::new(1);
+            for exp in 0..300 {
+                assert_eq!(base.pow_u64(exp), expected);
+                assert_eq!(base.pow(exp as u32), expected);
+                assert_eq!(table.pow(exp), expected);
+                expected *= base;
+            }
+
+            for _ in 0..50 {
+                let exp = next();
+                // base^exp = (base^(2^32))^hi * base^lo
+                let (hi, lo) = ((exp >> 32) as u32, exp as u32);
+                let expected = base.pow(1 << 16).pow(1 << 16).pow(hi) * base.pow(lo);
+                assert_eq!(base.pow_u64(exp), expected);
+                assert_eq!(table.pow(exp), expected);
+            }
+        }
+
+        // Fermat's little theorem with exponents above `u32::MAX`
+        let phi = Mint::MODULUS - 1;
+        for k in [5, 1 << 20, u64::MAX / phi] {
+            assert!(phi * k > u32::MAX as u64);
+            assert_eq!(Mint::new(12_345).pow_u64(phi * k), Mint::new(1));
+            assert_eq!(
+                Mint::new(12_345).pow_precompute().pow(phi * k),
+                Mint::new(1)
+            );
+        }
+        assert_eq!(Mint::new(0).pow_u64(u64::MAX), Mint::new(0));
+        assert_eq!(SMint::<1>::new(0).pow_u64(0), SMint::new(0));
+    }
 }
@@ -0,0 +1,285 @@
+//! Fully persistent array by path copying.
+use std::fmt::Debug;
+
+/// Fully persistent array, where every version can be read and updated.
+///
+/// Each [`set`](PersistentArray::set) creates a new version from the given one and leaves the latter untouched.
+/// Versions are numbered in the order of creation, and the initial one is `0`.
+///
+/// # Example
+///
+/// ```
+/// use persistent_array::PersistentArray;
+///
+/// let mut array = PersistentArray::from_iter([0, 1, 2, 3]);
+/// let v1 = array.set(0, 2, 20);
+/// let v2 = array.set(v1, 0, 10);
+/// // branch from the initial version
+/// let v3 = array.set(0, 3, 30);
+///
+/// assert_eq!(array.to_vec(0), [0, 1, 2, 3]);
+/// assert_eq!(array.to_vec(v2), [10, 1, 20, 3]);
+/// assert_eq!(array.to_vec(v3), [0, 1, 2, 30]);
+/// assert_eq!(array.num_versions(), 4);
+/// ```
+///
+/// # Performance note
+///
+/// | [from_iter](PersistentArray::from_iter) | [get](PersistentArray::get) | [set](PersistentArray::set)        |
+/// |-----------------------------------------|-----------------------------|------------------------------------|
+/// | *O*(*N*)                                | *O*(log *N*)                | *O*(log *N*) time and extra space  |
+#[derive(Clone)]
+pub struct PersistentArray<T> {
+    nodes: Vec<Node<T>>,
+    /// `roots[v]` is the root of version `v`
+    roots: Vec<usize>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Node<T> {
+    Leaf(T),
+    Branch(usize, usize),
+}
+
+impl<T> PersistentArray<T> {
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of versions, including the initial one.
+    pub fn num_versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns the `i`-th element of the given `version`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` or `i` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    pub fn get(&self, version: usize, i: usize) -> &T {
+        assert!(i < self.len, "index {} out of bounds", i);
+
+        let (mut p, mut l, mut r) = (self.roots[version], 0, self.len);
+        loop {
+            match &self.nodes[p] {
+                Node::Leaf(value) => return value,
+                &Node::Branch(left, right) => {
+                    let mid = (l + r) / 2;
+                    if i < mid {
+                        (p, r) = (left, mid)
+                    } else {
+                        (p, l) = (right, mid)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Creates a new version by replacing the `i`-th element of the given `version` with `value`,
+    /// then returns the new version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` or `i` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    pub fn set(&mut self, version: usize, i: usize, value: T) -> usize {
+        assert!(i < self.len, "index {} out of bounds", i);
+
+        // (node, goes left) from the root
+        let mut path = Vec::with_capacity(self.len.ilog2() as usize + 1);
+        let (mut p, mut l, mut r) = (self.roots[version], 0, self.len);
+        while let Node::Branch(left, right) = self.nodes[p] {
+            let mid = (l + r) / 2;
+            if i < mid {
+                path.push((p, true));
+                (p, r) = (left, mid)
+            } else {
+                path.push((p, false));
+                (p, l) = (right, mid)
+            }
+        }
+
+        // copy the path
+        let mut child = self.nodes.len();
+        self.nodes.push(Node::Leaf(value));
+        while let Some((p, goes_left)) = path.pop() {
+            let Node::Branch(left, right) = self.nodes[p] else {
+                unreachable!()
+            };
+            let node = if goes_left {
+                Node::Branch(child, right)
+            } else {
+                Node::Branch(left, child)
+            };
+            child = self.nodes.len();
+            self.nodes.push(node);
+        }
+        self.roots.push(child);
+
+        self.roots.len() - 1
+    }
+
+    /// Creates a new version which is identical to the given `version`, then returns the new version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn duplicate(&mut self, version: usize) -> usize {
+        self.roots.push(self.roots[version]);
+        self.roots.len() - 1
+    }
+
+    /// Returns an iterator over elements of the given `version`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*) in total
+    pub fn iter(&self, version: usize) -> impl Iterator<Item = &T> {
+        // in-order traversal
+        let mut stack = Vec::from_iter((self.len > 0).then_some(self.roots[version]));
+        std::iter::from_fn(move || loop {
+            match &self.nodes[stack.pop()?] {
+                Node::Leaf(value) => return Some(value),
+                &Node::Branch(left, right) => stack.extend([right, left]),
+            }
+        })
+    }
+
+    pub fn to_vec(&self, version: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        Vec::from_iter(self.iter(version).cloned())
+    }
+
+    /// Builds a perfectly balanced tree over `values[l..r]` and returns its root.
+    fn build(
+        nodes: &mut Vec<Node<T>>,
+        values: &mut impl Iterator<Item = T>,
+        l: usize,
+        r: usize,
+    ) -> usize {
+        let node = if r - l == 1 {
+            Node::Leaf(values.next().unwrap())
+        } else {
+            let mid = (l + r) / 2;
+            let left = Self::build(nodes, values, l, mid);
+            let right = Self::build(nodes, values, mid, r);
+            Node::Branch(left, right)
+        };
+        nodes.push(node);
+
+        nodes.len() - 1
+    }
+}
+
+impl<T: Debug> Debug for PersistentArray<T> {
+    /// Shows the latest version.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersistentArray")
+            .field("latest", &Vec::from_iter(self.iter(self.roots.len() - 1)))
+            .field("num_versions", &self.roots.len())
+            .finish()
+    }
+}
+
+impl<T> FromIterator<T> for PersistentArray<T> {
+    /// Creates the initial version `0`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values = Vec::from_iter(iter);
+        let len = values.len();
+        let mut nodes = Vec::with_capacity(2 * len);
+        let root = if len == 0 {
+            // never accessed
+            !0
+        } else {
+            Self::build(&mut nodes, &mut values.into_iter(), 0, len)
+        };
+
+        Self {
+            nodes,
+            roots: vec![root],
+            len,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for PersistentArray<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_iter(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn random_branches() {
+        let mut next = xorshift(0x0123_4567_89ab_cdef);
+
+        for n in [1, 2, 3, 7, 16, 33] {
+            let mut array = PersistentArray::from_iter(0..n as u64);
+            let mut snapshots = vec![Vec::from_iter(0..n as u64)];
+            for _ in 0..300 {
+                let version = next() as usize % snapshots.len();
+                let i = next() as usize % n;
+                if next() % 5 == 0 {
+                    assert_eq!(array.duplicate(version), snapshots.len());
+                    snapshots.push(snapshots[version].clone());
+                } else {
+                    let value = next() % 1000;
+                    assert_eq!(array.set(version, i, value), snapshots.len());
+                    let mut snapshot = snapshots[version].clone();
+                    snapshot[i] = value;
+                    snapshots.push(snapshot);
+                }
+                assert_eq!(array.get(version, i), &snapshots[version][i]);
+            }
+
+            assert_eq!(array.num_versions(), snapshots.len());
+            for (version, snapshot) in snapshots.iter().enumerate() {
+                assert_eq!(&array.to_vec(version), snapshot);
+                for (i, value) in snapshot.iter().enumerate() {
+                    assert_eq!(array.get(version, i), value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let array = PersistentArray::<()>::from_iter(None);
+        assert_eq!(array.len(), 0);
+        assert_eq!(array.iter(0).count(), 0);
+        assert_eq!(
+            format!("{:?}", array),
+            "PersistentArray { latest: [], num_versions: 1 }"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        PersistentArray::from_iter([1, 2, 3]).get(0, 3);
+    }
+}
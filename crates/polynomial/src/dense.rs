@@ -0,0 +1,229 @@
+use std::ops::{Add, Mul};
+
+use math_traits::Field;
+
+/// A univariate polynomial whose coefficients are stored in ascending order of degree.
+///
+/// Trailing zeros are always trimmed, so that two polynomials are equal if and only if their coefficients are equal.
+///
+/// # Example
+///
+/// ```
+/// use mod_int::SMint;
+/// use polynomial::Polynomial;
+///
+/// type Mint = SMint<998_244_353>;
+///
+/// // 1 + 2x
+/// let f = Polynomial::from(vec![Mint::new(1), Mint::new(2)]);
+/// // 3 + x^2
+/// let g = Polynomial::from(vec![Mint::new(3), Mint::new(0), Mint::new(1)]);
+///
+/// assert_eq!(f.eval(&Mint::new(5)), Mint::new(11));
+/// assert_eq!((&f + &g).coeffs(), [Mint::new(4), Mint::new(2), Mint::new(1)]);
+/// // 3 + 6x + x^2 + 2x^3
+/// assert_eq!((&f * &g).degree(), Some(3));
+/// assert_eq!(g.derivative().coeffs(), [Mint::new(0), Mint::new(2)]);
+/// ```
+///
+/// # Performance note
+///
+/// | `+`/[derivative](Polynomial::derivative)/[eval](Polynomial::eval) | `*`          |
+/// |-------------------------------------------------------------------|--------------|
+/// | *O*(*N*)                                                          | *O*(*N* *M*) |
+///
+/// * *N* and *M* are the degrees of the operands.
+/// * Multiplication is naive. For NTT-friendly moduli such as 998244353, it can be done in *O*((*N* + *M*) log (*N* + *M*)) by NTT.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<F: Field + Clone + PartialEq> {
+    /// no trailing zeros
+    coeffs: Vec<F>,
+}
+
+impl<F: Field + Clone + PartialEq> Polynomial<F> {
+    /// Removes trailing zeros.
+    fn normalize(&mut self) {
+        let zero = F::zero();
+        while self.coeffs.last() == Some(&zero) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    /// Returns the coefficients in ascending order of degree, without trailing zeros.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Returns the degree of `self`, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Returns the value at `x`, using Horner's method.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn eval(&self, x: &F) -> F {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, c| acc.mul(x).add(c))
+    }
+
+    /// Returns the formal derivative.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn derivative(&self) -> Self {
+        let mut k = F::zero();
+        let coeffs = Vec::from_iter(self.coeffs.iter().skip(1).map(|c| {
+            k = k.add(&F::one());
+            c.mul(&k)
+        }));
+
+        // `k * c` may vanish in positive characteristic
+        let mut res = Self { coeffs };
+        res.normalize();
+        res
+    }
+}
+
+impl<F: Field + Clone + PartialEq> From<Vec<F>> for Polynomial<F> {
+    /// Creates a polynomial from coefficients in ascending order of degree.
+    fn from(coeffs: Vec<F>) -> Self {
+        let mut res = Self { coeffs };
+        res.normalize();
+        res
+    }
+}
+
+impl<F: Field + Clone + PartialEq> Add for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut res = long.clone();
+        for (a, b) in res.coeffs.iter_mut().zip(&short.coeffs) {
+            *a = a.add(b)
+        }
+        // leading terms may cancel out
+        res.normalize();
+        res
+    }
+}
+
+impl<F: Field + Clone + PartialEq> Add for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<F: Field + Clone + PartialEq> Mul for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return Polynomial::zero();
+        }
+
+        let mut coeffs = Vec::from_iter(
+            std::iter::repeat_with(F::zero).take(self.coeffs.len() + rhs.coeffs.len() - 1),
+        );
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].add(&a.mul(b))
+            }
+        }
+
+        // zero divisors may appear for non-field coefficients, e.g. composite moduli
+        let mut res = Polynomial { coeffs };
+        res.normalize();
+        res
+    }
+}
+
+impl<F: Field + Clone + PartialEq> Mul for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mod_int::SMint;
+    use test_utils::xorshift;
+
+    use super::*;
+
+    type Mint = SMint<998_244_353>;
+
+    fn poly(coeffs: &[i64]) -> Polynomial<Mint> {
+        Polynomial::from(Vec::from_iter(coeffs.iter().map(|&c| Mint::from(c))))
+    }
+
+    #[test]
+    fn difference_of_squares() {
+        let f = poly(&[1, 1]) * poly(&[1, -1]);
+        assert_eq!(f, poly(&[1, 0, -1]));
+        assert_eq!(f.degree(), Some(2));
+
+        for x in -20_i64..20 {
+            assert_eq!(f.eval(&Mint::from(x)), Mint::from(1 - x * x), "x = {x}");
+        }
+        assert_eq!(f.derivative(), poly(&[0, -2]));
+        assert_eq!(f.derivative().derivative().derivative(), Polynomial::zero());
+    }
+
+    #[test]
+    fn naive_coefficients() {
+        let mut next = xorshift(0x0bad_cafe);
+
+        for _ in 0..50 {
+            let a = Vec::from_iter((0..next() % 8).map(|_| next() as i64 % 10 - 5));
+            let b = Vec::from_iter((0..next() % 8).map(|_| next() as i64 % 10 - 5));
+            let (f, g) = (poly(&a), poly(&b));
+
+            for x in -5_i64..5 {
+                let x = Mint::from(x);
+                assert_eq!((&f + &g).eval(&x), f.eval(&x) + g.eval(&x));
+                assert_eq!((&f * &g).eval(&x), f.eval(&x) * g.eval(&x));
+            }
+            assert_eq!(
+                (&f * &g).degree(),
+                f.degree().zip(g.degree()).map(|(n, m)| n + m)
+            );
+            assert_eq!(
+                Polynomial::from(Vec::from_iter(f.coeffs().iter().map(|&c| -c))) + f.clone(),
+                Polynomial::zero()
+            );
+        }
+    }
+
+    #[test]
+    fn vanishing_derivative() {
+        // x^7 + 2 over integers modulo 7
+        let mut coeffs = vec![SMint::<7>::new(0); 8];
+        coeffs[0] = SMint::new(2);
+        coeffs[7] = SMint::new(1);
+        let f = Polynomial::from(coeffs);
+        assert_eq!(f.degree(), Some(7));
+        assert_eq!(f.derivative(), Polynomial::zero());
+        assert_eq!(f.derivative().degree(), None);
+    }
+}
@@ -0,0 +1,4 @@
+//! Univariate polynomials over a [`Field`](math_traits::Field).
+mod dense;
+
+pub use dense::Polynomial;
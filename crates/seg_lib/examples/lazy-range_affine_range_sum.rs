@@ -11,7 +11,7 @@ fn main() {
     input! { n: usize, q: usize, a: [u64; n], }
 
     let mut lst =
-        LazySegmentTree::<Affine>::from_iter(a.into_iter().map(|a| SUM::new(a)));
+        LazySegmentTree::<Affine>::from_iter(a.into_iter().map(Sum::new));
 
     for _ in 0..q {
         input! { flag: u8, }
@@ -31,12 +31,12 @@ fn main() {
 }
 
 #[derive(Debug, Clone)]
-struct SUM {
+struct Sum {
     sum: Mint,
     size: Mint,
 }
 
-impl SUM {
+impl Sum {
     fn new(value: u64) -> Self {
         Self {
             sum: SMint::new(value),
@@ -45,7 +45,7 @@ impl SUM {
     }
 }
 
-impl Monoid for SUM {
+impl Monoid for Sum {
     const IS_COMMUTATIVE: bool = true;
 
     fn identity() -> Self {
@@ -79,7 +79,7 @@ impl Affine {
 }
 
 impl MonoidAct for Affine {
-    type Arg = SUM;
+    type Arg = Sum;
     const IS_COMMUTATIVE: bool = false;
 
     fn identity() -> Self {
@@ -87,7 +87,7 @@ impl MonoidAct for Affine {
     }
 
     fn apply(&self, arg: &Self::Arg) -> Self::Arg {
-        SUM {
+        Sum {
             sum: self.tilt * arg.sum + self.offset * arg.size,
             size: arg.size,
         }
@@ -8,7 +8,7 @@ fn main() {
     input! { n: usize, q: usize, a: [u64; n], }
 
     // test `from`
-    let mut seg_tree = SegmentTree::from(Vec::from_iter(a.into_iter().map(|a| M(a))));
+    let mut seg_tree = SegmentTree::from(Vec::from_iter(a.into_iter().map(M)));
 
     for _ in 0..q {
         input! { flag: u8, }
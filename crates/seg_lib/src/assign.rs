@@ -1,6 +1,6 @@
 use std::ops::RangeBounds;
 
-use super::Monoid;
+use super::{Monoid, MonoidAct};
 
 /// A segment tree specialized for efficiently assigning functions to consecutive elements
 /// and composing them over a range.
@@ -28,6 +28,8 @@ pub struct AssignSegmentTree<F: Monoid + Clone> {
     lazy_map: Box<[usize]>,
     /// `[(T, 0), (T, 1), .., (T, d)|(U, 0), .., (U, d)|..|(V, 0), .., (V, d)]`, where `(T, n)` represents `T^(2^n)`
     lazy_pow: Vec<F>,
+    /// `lazy_pow` is cleared once its length reaches this value. Defaults to `data.len()`.
+    compaction_threshold: usize,
     /// Number of `data`, excluding at most one extended identity element.
     len: usize,
     /// Height of `lazy_map`
@@ -177,6 +179,12 @@ impl<F: Monoid + Clone> AssignSegmentTree<F> {
         prev
     }
 
+    /// Assigns `act` to each element in the given `range`.
+    ///
+    /// Each call appends *O*(log *N*) powers of `act` to an internal buffer, which is cleared
+    /// by a rebuild of *O*(*N*) time once its length reaches the threshold, the number of nodes by default.
+    /// Hence the buffer holds *O*(*N*) elements at any time, and the amortized cost is still *O*(log *N*).
+    /// Use [`AssignSegmentTree::set_compaction_threshold`] or [`AssignSegmentTree::compact`] to release them earlier.
     pub fn assign<R>(&mut self, range: R, act: F)
     where
         R: RangeBounds<usize>,
@@ -232,7 +240,7 @@ impl<F: Monoid + Clone> AssignSegmentTree<F> {
         }
 
         // update `data`
-        if self.lazy_pow.len() < self.data.len() {
+        if self.lazy_pow.len() < self.compaction_threshold {
             for d in 1..=self.lazy_height {
                 if (l >> d) << d != l {
                     self.update(l >> d);
@@ -242,11 +250,134 @@ impl<F: Monoid + Clone> AssignSegmentTree<F> {
                 }
             }
         } else {
-            self.propagate_all();
-            self.update_all();
-            self.lazy_pow.clear();
+            self.compact();
         }
     }
+
+    /// Applies all pending assignments and clears the internal buffer of powers of assigned elements.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn compact(&mut self) {
+        self.propagate_all();
+        self.update_all();
+        self.lazy_pow.clear();
+    }
+
+    /// Sets the length at which the internal buffer of [`AssignSegmentTree::assign`] is cleared,
+    /// and clears it immediately if it is already that long.
+    ///
+    /// A smaller threshold saves memory at the cost of more frequent rebuilds of *O*(*N*) time.
+    /// Since a rebuild happens every *O*(threshold / log *N*) calls, the amortized cost of `assign` is
+    /// *O*(log *N* + *N* log *N* / threshold), which stays *O*(log *N*) only if the threshold is *Ω*(*N*).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{AssignSegmentTree, Min};
+    ///
+    /// let mut seg_tree = AssignSegmentTree::from(vec![Min(5); 1000]);
+    /// seg_tree.set_compaction_threshold(100);
+    /// for i in 0..1000 {
+    ///     seg_tree.assign(i / 2..i, Min(i as i32));
+    /// }
+    /// assert_eq!(seg_tree.composite(..), Min(1));
+    /// ```
+    pub fn set_compaction_threshold(&mut self, threshold: usize) {
+        self.compaction_threshold = threshold;
+        if self.lazy_pow.len() >= threshold {
+            self.compact();
+        }
+    }
+
+    /// Returns the elements after all assignments.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn into_vec(mut self) -> Vec<F> {
+        self.propagate_all();
+
+        // discard buffer and the extended identity element
+        let mut data = self.data.into_vec().split_off(self.lazy_map.len());
+        data.truncate(self.len);
+        data
+    }
+}
+
+impl<F: Monoid + MonoidAct + Clone> AssignSegmentTree<F> {
+    /// Returns the result of applying the composite over the given `range` to `initial`,
+    /// that is, `self.composite(range).apply(initial)`.
+    ///
+    /// The composite is taken by [Monoid::binary_operation] in the order of indices,
+    /// and only [MonoidAct::apply] is used to evaluate it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{AssignSegmentTree, Monoid, MonoidAct, RangeSum};
+    ///
+    /// /// `x -> ax + b`
+    /// #[derive(Clone)]
+    /// struct Affine(u64, u64);
+    ///
+    /// impl Monoid for Affine {
+    ///     fn identity() -> Self {
+    ///         Self(1, 0)
+    ///     }
+    ///
+    ///     /// `self` then `rhs`
+    ///     fn binary_operation(&self, rhs: &Self) -> Self {
+    ///         Self(self.0 * rhs.0, self.1 * rhs.0 + rhs.1)
+    ///     }
+    /// }
+    ///
+    /// impl MonoidAct for Affine {
+    ///     type Arg = RangeSum<u64>;
+    ///
+    ///     const IS_COMMUTATIVE: bool = false;
+    ///
+    ///     fn identity() -> Self {
+    ///         Self(1, 0)
+    ///     }
+    ///
+    ///     /// `rhs` then `self`
+    ///     fn composite(&self, rhs: &Self) -> Self {
+    ///         rhs.binary_operation(self)
+    ///     }
+    ///
+    ///     fn apply(&self, arg: &Self::Arg) -> Self::Arg {
+    ///         RangeSum {
+    ///             sum: self.0 * arg.sum + self.1 * arg.len,
+    ///             len: arg.len,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut seg_tree = AssignSegmentTree::from(vec![Affine(2, 1), Affine(3, 0), Affine(1, 5)]);
+    /// // 3 * (2 * 10 + 1) + 5
+    /// assert_eq!(seg_tree.eval(.., &RangeSum::new(10)).sum, 68);
+    ///
+    /// seg_tree.assign(..2, Affine(1, 1));
+    /// // two elements summing to 10, each shifted by 1 and then by 5
+    /// let sum = seg_tree.eval(1.., &RangeSum { sum: 10, len: 2 }).sum;
+    /// assert_eq!(sum, 10 + 2 * 1 + 2 * 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given `range` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    pub fn eval<R>(&mut self, range: R, initial: &<F as MonoidAct>::Arg) -> <F as MonoidAct>::Arg
+    where
+        R: RangeBounds<usize>,
+    {
+        self.composite(range).apply(initial)
+    }
 }
 
 impl<F: Monoid + Clone> From<Vec<F>> for AssignSegmentTree<F> {
@@ -265,6 +396,7 @@ impl<F: Monoid + Clone> From<Vec<F>> for AssignSegmentTree<F> {
             data,
             lazy_map: vec![Self::NULL_ID; buf_len].into_boxed_slice(),
             lazy_pow: Vec::with_capacity(buf_len + len),
+            compaction_threshold: buf_len + len + len % 2,
             len,
             lazy_height: buf_len.trailing_zeros(),
         };
@@ -273,3 +405,222 @@ impl<F: Monoid + Clone> From<Vec<F>> for AssignSegmentTree<F> {
         res
     }
 }
+
+/// Yields `(index, element)` in the order of indices.
+impl<F: Monoid + Clone> IntoIterator for AssignSegmentTree<F> {
+    type Item = (usize, F);
+    type IntoIter = std::iter::Enumerate<<Vec<F> as IntoIterator>::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter().enumerate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    /// `x -> ax + b` over integers modulo 998244353
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Affine(u64, u64);
+
+    impl Monoid for Affine {
+        fn identity() -> Self {
+            Self(1, 0)
+        }
+
+        /// `self` then `rhs`
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            const MOD: u64 = 998_244_353;
+            Self(self.0 * rhs.0 % MOD, (self.1 * rhs.0 + rhs.1) % MOD)
+        }
+    }
+
+    fn naive_composite(values: &[Affine]) -> Affine {
+        values
+            .iter()
+            .fold(Affine::identity(), |acc, v| acc.binary_operation(v))
+    }
+
+    #[test]
+    fn compact() {
+        let n = 11;
+        let mut values = Vec::from_iter((0..n as u64).map(|i| Affine(i + 2, i)));
+        let mut seg_tree = AssignSegmentTree::from(values.clone());
+
+        for (k, (l, r)) in [(0, 11), (2, 9), (3, 4), (5, 11), (1, 6)].into_iter().enumerate() {
+            let act = Affine(k as u64 + 3, 2 * k as u64 + 1);
+            seg_tree.assign(l..r, act);
+            values[l..r].fill(act);
+        }
+        assert!(!seg_tree.lazy_pow.is_empty());
+
+        seg_tree.compact();
+        assert_eq!(seg_tree.lazy_pow.len(), 0);
+        for l in 0..=n {
+            for r in l..=n {
+                assert_eq!(seg_tree.composite(l..r), naive_composite(&values[l..r]));
+            }
+        }
+
+        // still works after compaction
+        seg_tree.assign(4..10, Affine(7, 7));
+        values[4..10].fill(Affine(7, 7));
+        for l in 0..=n {
+            for r in l..=n {
+                assert_eq!(seg_tree.composite(l..r), naive_composite(&values[l..r]));
+            }
+        }
+    }
+
+    /// Assignment of a constant `c`, that is `x -> c`, or the identity map for `None`.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Constant(Option<i64>);
+
+    impl Monoid for Constant {
+        fn identity() -> Self {
+            Self(None)
+        }
+
+        /// `self` then `rhs`
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(rhs.0.or(self.0))
+        }
+    }
+
+    impl MonoidAct for Constant {
+        type Arg = Sum;
+
+        const IS_COMMUTATIVE: bool = false;
+
+        fn identity() -> Self {
+            Self(None)
+        }
+
+        /// `rhs` then `self`
+        fn composite(&self, rhs: &Self) -> Self {
+            rhs.binary_operation(self)
+        }
+
+        fn apply(&self, arg: &Self::Arg) -> Self::Arg {
+            match self.0 {
+                Some(c) => Sum {
+                    sum: c * arg.len,
+                    len: arg.len,
+                },
+                None => arg.clone(),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Sum {
+        sum: i64,
+        len: i64,
+    }
+
+    impl Monoid for Sum {
+        const IS_COMMUTATIVE: bool = true;
+
+        fn identity() -> Self {
+            Self { sum: 0, len: 0 }
+        }
+
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self {
+                sum: self.sum + rhs.sum,
+                len: self.len + rhs.len,
+            }
+        }
+    }
+
+    #[test]
+    fn eval_matches_composite() {
+        let n = 13;
+        let mut values = Vec::from_iter((0..n).map(|i| Constant((i % 3 != 0).then_some(i as i64))));
+        let mut seg_tree = AssignSegmentTree::from(values.clone());
+
+        for (k, (l, r)) in [(0, 13), (2, 9), (3, 4), (5, 13), (1, 6), (7, 8)]
+            .into_iter()
+            .enumerate()
+        {
+            let act = Constant((k % 2 == 0).then_some(10 * k as i64));
+            seg_tree.assign(l..r, act);
+            values[l..r].fill(act);
+
+            for l in 0..=n {
+                for r in l..=n {
+                    let initial = Sum { sum: 3, len: 2 };
+                    let composite = values[l..r]
+                        .iter()
+                        .fold(<Constant as Monoid>::identity(), |acc, v| {
+                            acc.binary_operation(v)
+                        });
+                    assert_eq!(seg_tree.eval(l..r, &initial), composite.apply(&initial));
+                    assert_eq!(
+                        seg_tree.eval(l..r, &initial),
+                        seg_tree.composite(l..r).apply(&initial)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_lazy_pow() {
+        let mut next = xorshift(0x1bad_b002);
+
+        let n = 100;
+        let mut values = Vec::from_iter((0..n as u64).map(|i| Affine(i + 2, i)));
+        let mut seg_tree = AssignSegmentTree::from(values.clone());
+        let default = seg_tree.compaction_threshold;
+        assert_eq!(default, seg_tree.data.len());
+
+        for threshold in [default, 40, 1, 0] {
+            seg_tree.set_compaction_threshold(threshold);
+            for _ in 0..1000 {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                let act = Affine(next() % 100, next() % 100);
+                seg_tree.assign(l..r, act);
+                values[l..r].fill(act);
+
+                assert!(seg_tree.lazy_pow.len() < threshold.max(1));
+
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                assert_eq!(seg_tree.composite(l..r), naive_composite(&values[l..r]));
+            }
+        }
+    }
+
+    #[test]
+    fn into_vec_matches_get() {
+        for n in [1, 2, 7, 8, 13] {
+            let mut values = Vec::from_iter((0..n as u64).map(|i| Affine(i + 2, i)));
+            let mut seg_tree = AssignSegmentTree::from(values.clone());
+
+            for (k, (l, r)) in [(0, n), (2, 9), (3, 4), (5, 13), (1, 6), (7, 8)]
+                .into_iter()
+                .enumerate()
+            {
+                let (l, r) = (l.min(n), r.min(n));
+                let act = Affine(k as u64 + 3, 2 * k as u64 + 1);
+                seg_tree.assign(l..r, act);
+                values[l..r].fill(act);
+            }
+
+            // take the clones before `get` propagates the pending assignments
+            let (pending_vec, pending_iter) = (seg_tree.clone(), seg_tree.clone());
+            assert_eq!(pending_vec.into_vec(), values);
+            assert!(pending_iter
+                .into_iter()
+                .eq(values.iter().copied().enumerate()));
+            for (i, v) in values.iter().enumerate() {
+                assert_eq!(seg_tree.get(i), v);
+            }
+        }
+    }
+}
@@ -172,17 +172,35 @@ impl<T: Monoid> DualSegmentTree<T> {
         self.len
     }
 
-    /// Returns the results of updates.
+    /// Pushes all pending operations down to the leaves, then returns the results of updates.
+    ///
+    /// Inner nodes are reset to the identity, so that subsequent [`point_query`](Self::point_query)
+    /// does not combine them again. This is useful to materialize elements once after many updates.
     ///
     /// # Time complexity
     ///
     /// *O*(*N*)
-    pub fn into_vec(mut self) -> Vec<T> {
+    pub fn apply_all(&mut self) -> &[T] {
+        // nodes in `[lazy.len() / 2, buf_len)` have no children and are never updated
         for i in 1..self.lazy.len() >> 1 {
             self.propagate(i);
         }
 
-        self.lazy.into_vec().split_off(self.buf_len)
+        &self.lazy[self.buf_len..self.buf_len + self.len]
+    }
+
+    /// Returns the results of updates.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.apply_all();
+
+        // discard buffer
+        let mut data = self.lazy.into_vec().split_off(self.buf_len);
+        data.truncate(self.len);
+        data
     }
 }
 
@@ -194,3 +212,114 @@ impl<T: Monoid> IntoIterator for DualSegmentTree<T> {
         self.into_vec().into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Xor(u32);
+
+    impl Monoid for Xor {
+        const IS_COMMUTATIVE: bool = true;
+
+        fn identity() -> Self {
+            Self(0)
+        }
+
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0 ^ rhs.0)
+        }
+    }
+
+    /// Same as [Xor] but keeps the default `IS_COMMUTATIVE`, so it always propagates.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct XorWithPropagation(u32);
+
+    impl Monoid for XorWithPropagation {
+        fn identity() -> Self {
+            Self(0)
+        }
+
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0 ^ rhs.0)
+        }
+    }
+
+    #[test]
+    fn commutative_shortcut() {
+        for n in [1, 2, 7, 8, 13] {
+            let mut lhs = DualSegmentTree::<Xor>::new(n);
+            let mut rhs = DualSegmentTree::<XorWithPropagation>::new(n);
+
+            let mut x = 1_u32;
+            for l in 0..n {
+                for r in l..=n {
+                    x = x.wrapping_mul(0x9e37_79b9).wrapping_add(7);
+                    lhs.range_update(l..r, Xor(x));
+                    rhs.range_update(l..r, XorWithPropagation(x));
+
+                    if (l + r) % 3 == 0 {
+                        lhs.point_update(l, Xor(x >> 3));
+                        rhs.point_update(l, XorWithPropagation(x >> 3));
+                    }
+                    for i in 0..n {
+                        assert_eq!(lhs.point_query(i).0, rhs.point_query(i).0);
+                    }
+                }
+            }
+
+            let lhs = Vec::from_iter(lhs.into_iter().map(|v| v.0));
+            let rhs = Vec::from_iter(rhs.into_iter().map(|v| v.0));
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn apply_all_matches_point_query() {
+        /// `x -> a * x + b` modulo 2^32, where `f.binary_operation(g)` applies `g` first (reversed order)
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Affine(u32, u32);
+
+        impl Monoid for Affine {
+            fn identity() -> Self {
+                Self(1, 0)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(
+                    self.0.wrapping_mul(rhs.0),
+                    self.0.wrapping_mul(rhs.1).wrapping_add(self.1),
+                )
+            }
+        }
+
+        let mut next = xorshift(0xa54f_f53a);
+
+        for n in [1, 2, 7, 8, 13] {
+            let mut dst = DualSegmentTree::<Affine>::new(n);
+            let mut expected = vec![Affine::identity(); n];
+            // updates after `apply_all` still work
+            for _ in 0..3 {
+                for _ in 0..200 {
+                    let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                    let elem = Affine(next() as u32, next() as u32);
+                    dst.range_update(l.min(r)..l.max(r), elem);
+                    for v in &mut expected[l.min(r)..l.max(r)] {
+                        *v = elem.binary_operation(v)
+                    }
+                }
+
+                assert_eq!(dst.apply_all(), expected);
+                let identity = Affine::identity();
+                assert!(dst.lazy[1..dst.buf_len].iter().all(|v| *v == identity));
+                for (i, v) in expected.iter().enumerate() {
+                    assert_eq!(&dst.point_query(i), v);
+                }
+            }
+            assert_eq!(dst.into_vec(), expected);
+        }
+    }
+}
@@ -97,6 +97,61 @@ impl<T: Monoid + Clone> DynamicSegmentTree<T> {
         }
     }
 
+    /// Returns an iterator over `(index, value)` pairs set by [`point_set`](Self::point_set),
+    /// in ascending order of indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{DynamicSegmentTree, Min};
+    ///
+    /// let mut seg = DynamicSegmentTree::new(-100..100);
+    /// seg.point_set(50, Min(3_i32));
+    /// seg.point_set(-20, Min(1));
+    /// seg.point_set(7, Min(4));
+    /// seg.point_set(50, Min(5));
+    ///
+    /// let pairs = Vec::from_iter(seg.iter().map(|(i, v)| (i, v.0)));
+    /// assert_eq!(pairs, [(-20, 1), (7, 4), (50, 5)]);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*) in total, where *N* is the number of stored indices
+    pub fn iter(&self) -> impl Iterator<Item = (isize, &T)> + '_ {
+        // in-order traversal of the arena
+        let mut stack = Vec::with_capacity(self.reusable_buf.capacity());
+        let mut next = if self.arena.is_empty() { None } else { Some(0) };
+        std::iter::from_fn(move || {
+            while let Some(p) = next {
+                stack.push(p);
+                next = self.arena[p].get_left();
+            }
+
+            let p = stack.pop()?;
+            next = self.arena[p].get_right();
+            Some((self.arena[p].index, &self.arena[p].value))
+        })
+    }
+
+    /// Returns the result of combining elements over the given `range`.
+    ///
+    /// The range is clamped to the domain given on construction,
+    /// that is, the portions outside of the domain contribute [`Monoid::identity`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{DynamicSegmentTree, Min};
+    ///
+    /// let mut seg = DynamicSegmentTree::new(0..10);
+    /// seg.point_set(0, Min(3));
+    /// seg.point_set(9, Min(5));
+    ///
+    /// assert_eq!(seg.range_query(-100..1), Min(3));
+    /// assert_eq!(seg.range_query(5..=100), Min(5));
+    /// assert_eq!(seg.range_query(10..20), Min(i32::MAX));
+    /// ```
     pub fn range_query<R>(&mut self, range: R) -> T
     where
         R: RangeBounds<isize>,
@@ -110,12 +165,14 @@ impl<T: Monoid + Clone> DynamicSegmentTree<T> {
             std::ops::Bound::Included(l) => *l,
             std::ops::Bound::Excluded(l) => l + 1,
             std::ops::Bound::Unbounded => start,
-        };
+        }
+        .max(start);
         let r = match range.end_bound() {
             std::ops::Bound::Included(r) => r + 1,
             std::ops::Bound::Excluded(r) => *r,
             std::ops::Bound::Unbounded => end,
-        };
+        }
+        .min(end);
 
         if l == start && r == end {
             return self.arena[0].product.clone();
@@ -369,3 +426,77 @@ impl<T: Clone> Node<T> {
         self.right = right
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Sum(i64);
+
+    impl Monoid for Sum {
+        const IS_COMMUTATIVE: bool = true;
+
+        fn identity() -> Self {
+            Self(0)
+        }
+
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    #[test]
+    fn iter_random() {
+        let mut next = xorshift(0xdead_beef);
+
+        for _ in 0..50 {
+            let (start, end) = (-1_000_000, 1_000_000);
+            let mut seg = DynamicSegmentTree::new(start..end);
+            let mut reference = BTreeMap::new();
+            assert_eq!(seg.iter().count(), 0);
+
+            for _ in 0..next() % 200 {
+                let i = start + (next() % (end - start) as u64) as isize;
+                let value = next() as i64 % 1000;
+                seg.point_set(i, Sum(value));
+                reference.insert(i, value);
+            }
+
+            let pairs = Vec::from_iter(seg.iter().map(|(i, v)| (i, v.0)));
+            assert_eq!(pairs, Vec::from_iter(reference.into_iter()));
+        }
+    }
+
+    #[test]
+    fn range_query_out_of_domain() {
+        let mut next = xorshift(0xfeed_face);
+
+        for (start, end) in [(-10, 10), (0, 1), (5, 37), (-64, 0)] {
+            let mut seg = DynamicSegmentTree::new(start..end);
+            let mut values = vec![0; (end - start) as usize];
+            for _ in 0..20 {
+                let i = start + (next() % (end - start) as u64) as isize;
+                let value = (next() % 100) as i64;
+                seg.point_set(i, Sum(value));
+                values[(i - start) as usize] = value;
+            }
+
+            let brute = |l: isize, r: isize| -> i64 {
+                let (l, r) = (l.max(start), r.min(end));
+                (l..r).map(|i| values[(i - start) as usize]).sum()
+            };
+            for l in start - 20..end + 20 {
+                for r in l..end + 20 {
+                    assert_eq!(seg.range_query(l..r), Sum(brute(l, r)), "{l}..{r}");
+                }
+                assert_eq!(seg.range_query(l..), Sum(brute(l, end)), "{l}..");
+                assert_eq!(seg.range_query(..l), Sum(brute(start, l)), "..{l}");
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use std::ops::RangeBounds;
+use std::{
+    fmt::Debug,
+    ops::{Range, RangeBounds},
+};
 
 use super::{Monoid, MonoidAct};
 
@@ -190,13 +193,57 @@ impl<F: MonoidAct + Clone> LazySegmentTree<F> {
     pub fn range_update<R>(&mut self, range: R, act: F)
     where
         R: RangeBounds<usize>,
+    {
+        self.range_update_indexed(range, |_| act.clone())
+    }
+
+    /// Updates elements in the given `range` with acts built for each canonical segment.
+    ///
+    /// The range is decomposed into *O*(log *N*) disjoint segments, each of which corresponds to a node.
+    /// `f` is called exactly once per segment, in no particular order, with the range of indices it covers,
+    /// and the returned act is applied to every element in the segment.
+    /// Since pending acts are propagated to children as they are, an act must not depend on the position
+    /// of each element within the segment. Store positions in [`MonoidAct::Arg`] instead if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{LazySegmentTree, RangeAddRangeMin, Min};
+    ///
+    /// let mut lst = LazySegmentTree::<RangeAddRangeMin<i32>>::from_iter([Min(0); 10]);
+    /// let mut segments = Vec::new();
+    /// lst.range_update_indexed(1..9, |segment| {
+    ///     // adds the length of the segment
+    ///     let len = segment.len() as i32;
+    ///     segments.push(segment);
+    ///     RangeAddRangeMin(len)
+    /// });
+    ///
+    /// segments.sort_unstable_by_key(|segment| segment.start);
+    /// assert_eq!(segments, [1..2, 2..4, 4..8, 8..9]);
+    /// assert_eq!(lst.range_query(2..4), Min(2));
+    /// assert_eq!(lst.range_query(..), Min(0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given `range` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*), calling `f` *O*(log *N*) times
+    pub fn range_update_indexed<R, G>(&mut self, range: R, mut f: G)
+    where
+        R: RangeBounds<usize>,
+        G: FnMut(Range<usize>) -> F,
     {
         let (l, r) = self.inner_range(range);
         if l >= r {
             return;
         }
         if l + 1 == r {
-            self.point_update(l - self.lazy.len(), act);
+            let i = l - self.lazy.len();
+            self.point_update(i, f(i..i + 1));
             return;
         }
 
@@ -217,22 +264,25 @@ impl<F: MonoidAct + Clone> LazySegmentTree<F> {
             }
         }
 
-        // apply `act` in a lazy way
+        // apply acts in a lazy way
         {
             let (mut l, mut r) = (l, r);
             l >>= l.trailing_zeros();
             r >>= r.trailing_zeros();
             if l == r {
+                let act = f(self.node_range(l));
                 self.push(l, act);
             } else {
                 while l != r {
                     if l >= r {
-                        self.push(l, act.clone());
+                        let act = f(self.node_range(l));
+                        self.push(l, act);
                         l += 1;
                         l >>= l.trailing_zeros();
                     } else {
                         r -= 1;
-                        self.push(r, act.clone());
+                        let act = f(self.node_range(r));
+                        self.push(r, act);
                         r >>= r.trailing_zeros();
                     }
                 }
@@ -250,9 +300,86 @@ impl<F: MonoidAct + Clone> LazySegmentTree<F> {
             }
         }
     }
+
+    /// Returns the range of indices of elements under `data[i]`.
+    #[inline]
+    fn node_range(&self, i: usize) -> Range<usize> {
+        let height = self.lazy_height - i.ilog2();
+        (i << height) - self.lazy.len()..((i + 1) << height) - self.lazy.len()
+    }
 }
 
 impl<F: MonoidAct + Clone> LazySegmentTree<F> {
+    /// Returns the largest `r` such that `pred(range_query(l..r))` holds,
+    /// assuming that `pred` is monotone, that is, once it becomes `false`, it never returns to `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{LazySegmentTree, RangeAffineRangeSum, RangeSum};
+    ///
+    /// let mut lst = LazySegmentTree::<RangeAffineRangeSum<i64>>::from_iter([3, 1, 4, 1, 5].map(RangeSum::new));
+    /// lst.range_update(1..3, RangeAffineRangeSum::new(1, 2)); // [3, 3, 6, 1, 5]
+    ///
+    /// assert_eq!(lst.max_right(0, |v| v.sum <= 12), 3);
+    /// assert_eq!(lst.max_right(1, |v| v.sum <= 100), 5);
+    /// assert_eq!(lst.max_right(2, |v| v.sum < 6), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `l` is out of bounds or `pred(identity)` is `false`.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    pub fn max_right<P>(&mut self, l: usize, pred: P) -> usize
+    where
+        P: Fn(&<F as MonoidAct>::Arg) -> bool,
+    {
+        assert!(l <= self.len, "index {} out of bounds", l);
+        let mut acc = <F as MonoidAct>::Arg::identity();
+        assert!(pred(&acc), "`pred` should hold for the identity element");
+        if l == self.len {
+            return self.len;
+        }
+
+        let mut l = self.inner_index(l);
+        // apply pending acts
+        for d in (1..=self.lazy_height).rev() {
+            self.propagate(l >> d);
+        }
+
+        loop {
+            l >>= l.trailing_zeros();
+
+            // nodes beyond `data` cover no elements
+            if let Some(node) = self.data.get(l) {
+                let next = acc.binary_operation(node);
+                if !pred(&next) {
+                    // descend to the leftmost leaf which breaks `pred`
+                    while l < self.lazy.len() {
+                        self.propagate(l);
+                        l <<= 1;
+                        let next = acc.binary_operation(&self.data[l]);
+                        if pred(&next) {
+                            acc = next;
+                            l += 1;
+                        }
+                    }
+
+                    return l - self.lazy.len();
+                }
+                acc = next;
+            }
+
+            l += 1;
+            if l.is_power_of_two() {
+                return self.len;
+            }
+        }
+    }
+
     /// Creates a new [LazySegmentTree] instance initialized with identity elements.
     ///
     /// Use [LazySegmentTree::from] for custom initial elements for better performance.
@@ -266,6 +393,38 @@ impl<F: MonoidAct + Clone> LazySegmentTree<F> {
         Self::from_iter(std::iter::repeat_with(<F as MonoidAct>::Arg::identity).take(n))
     }
 
+    /// Creates a new [LazySegmentTree] instance from run-length encoded elements,
+    /// where `(value, count)` represents `count` copies of `value`.
+    ///
+    /// Runs are expanded into leaves, so the memory usage is proportional to the total length.
+    /// If range updates are not needed and most elements are identities, [DynamicSegmentTree](crate::DynamicSegmentTree) is more suitable,
+    /// since it allocates nodes only for the elements which are set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{LazySegmentTree, Min, RangeAddRangeMin};
+    ///
+    /// let runs = vec![(Min(5), 3), (Min(1), 0), (Min(2), 2)];
+    /// let mut lst = LazySegmentTree::<RangeAddRangeMin<i32>>::from_runs(runs);
+    /// assert_eq!(lst.len(), 5);
+    /// assert_eq!(lst.range_query(..3), Min(5));
+    /// assert_eq!(lst.range_query(2..), Min(2));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*), where *N* is the total length
+    pub fn from_runs(runs: Vec<(<F as MonoidAct>::Arg, usize)>) -> Self {
+        let len = runs.iter().map(|(_, count)| count).sum();
+        let mut data = Vec::with_capacity(len);
+        for (value, count) in runs {
+            data.extend(std::iter::repeat(value).take(count))
+        }
+
+        Self::from_iter(data)
+    }
+
     /// Returns the number of elements.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
@@ -278,13 +437,57 @@ impl<F: MonoidAct + Clone> LazySegmentTree<F> {
     ///
     /// *O*(*N*)
     pub fn into_vec(mut self) -> Vec<<F as MonoidAct>::Arg> {
-        // propagate all pending acts
+        self.propagate_all();
+
+        // discard buffer
+        let mut data = self.data.into_vec().split_off(self.lazy.len());
+        data.truncate(self.len);
+        data
+    }
+
+    /// Returns the results of updates without consuming `self`.
+    ///
+    /// All pending acts are applied in place, so that subsequent operations do not need to propagate them again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{LazySegmentTree, RangeAffineRangeSum, RangeSum};
+    ///
+    /// let mut lst = LazySegmentTree::<RangeAffineRangeSum<i64>>::from_iter((0..4).map(RangeSum::new));
+    /// lst.range_update(1..3, RangeAffineRangeSum::new(2, 1));
+    ///
+    /// let snapshot = Vec::from_iter(lst.to_vec().into_iter().map(|v| v.sum));
+    /// assert_eq!(snapshot, [0, 3, 5, 3]);
+    /// assert_eq!(lst.range_query(..).sum, 11);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn to_vec(&mut self) -> Vec<<F as MonoidAct>::Arg> {
+        self.propagate_all();
+
+        let offset = self.lazy.len();
+        self.data[offset..offset + self.len].to_vec()
+    }
+
+    fn propagate_all(&mut self) {
         for i in 1..self.data.len() >> 1 {
             self.propagate(i);
         }
+    }
+}
 
-        // discard buffer
-        self.data.into_vec().split_off(self.lazy.len())
+impl<F: MonoidAct + Clone> Debug for LazySegmentTree<F>
+where
+    <F as MonoidAct>::Arg: Debug,
+{
+    /// Shows elements with all pending acts applied. `self` is left untouched.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazySegmentTree")
+            .field("data", &self.clone().into_vec())
+            .finish()
     }
 }
 
@@ -309,7 +512,7 @@ impl<F: MonoidAct + Clone> FromIterator<<F as MonoidAct>::Arg> for LazySegmentTr
         } else {
             let vec = Vec::from_iter(iter);
             let len = vec.len();
-            let buf_len = min.next_power_of_two();
+            let buf_len = len.next_power_of_two();
             let data = Vec::from_iter(
                 std::iter::repeat_with(<F as MonoidAct>::Arg::identity)
                     .take(buf_len)
@@ -335,3 +538,285 @@ impl<F: MonoidAct + Clone> FromIterator<<F as MonoidAct>::Arg> for LazySegmentTr
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Sum {
+        sum: i64,
+        len: i64,
+    }
+
+    impl Monoid for Sum {
+        const IS_COMMUTATIVE: bool = true;
+
+        fn identity() -> Self {
+            Self { sum: 0, len: 0 }
+        }
+
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self {
+                sum: self.sum + rhs.sum,
+                len: self.len + rhs.len,
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct Add(i64);
+
+    impl MonoidAct for Add {
+        type Arg = Sum;
+
+        const IS_COMMUTATIVE: bool = true;
+
+        fn identity() -> Self {
+            Self(0)
+        }
+
+        fn composite(&self, rhs: &Self) -> Self {
+            Self(self.0 + rhs.0)
+        }
+
+        fn apply(&self, arg: &Self::Arg) -> Self::Arg {
+            Sum {
+                sum: arg.sum + self.0 * arg.len,
+                len: arg.len,
+            }
+        }
+    }
+
+    #[test]
+    fn debug_shows_propagated_values() {
+        let mut expected = vec![0; 11];
+        let mut lst =
+            LazySegmentTree::<Add>::from_iter(expected.iter().map(|&sum| Sum { sum, len: 1 }));
+        for (l, r, x) in [(0, 11, 1), (2, 7, 10), (5, 6, 100), (6, 11, -3)] {
+            lst.range_update(l..r, Add(x));
+            expected[l..r].iter_mut().for_each(|v| *v += x);
+        }
+
+        let expected = format!(
+            "LazySegmentTree {{ data: {:?} }}",
+            Vec::from_iter(expected.iter().map(|&sum| Sum { sum, len: 1 }))
+        );
+        assert_eq!(format!("{:?}", lst), expected);
+        // formatting does not flush pending acts of the original tree
+        assert_eq!(format!("{:?}", lst), expected);
+        assert_eq!(lst.range_query(..).sum, 11 + 50 + 100 - 15);
+    }
+
+    #[test]
+    fn to_vec_keeps_tree_usable() {
+        let mut next = xorshift(0x2545_f491);
+
+        for n in [1, 2, 3, 8, 13] {
+            let mut naive = vec![0_i64; n];
+            let mut lst =
+                LazySegmentTree::<Add>::from_iter(naive.iter().map(|&sum| Sum { sum, len: 1 }));
+            for _ in 0..100 {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                match next() % 3 {
+                    0 => {
+                        let x = next() as i64 % 10;
+                        lst.range_update(l..r, Add(x));
+                        naive[l..r].iter_mut().for_each(|v| *v += x);
+                    }
+                    1 => assert_eq!(lst.range_query(l..r).sum, naive[l..r].iter().sum::<i64>()),
+                    _ => {
+                        let snapshot = lst.to_vec();
+                        assert_eq!(
+                            snapshot,
+                            Vec::from_iter(naive.iter().map(|&sum| Sum { sum, len: 1 }))
+                        );
+                        assert_eq!(lst.len(), n);
+                        assert_eq!(lst.clone().into_vec(), snapshot);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_right_matches_linear_scan() {
+        let mut next = xorshift(0x8bad_f00d);
+
+        for n in [1, 2, 3, 8, 13, 32] {
+            let mut naive = Vec::from_iter((0..n).map(|_| (next() % 5) as i64));
+            let mut lst =
+                LazySegmentTree::<Add>::from_iter(naive.iter().map(|&sum| Sum { sum, len: 1 }));
+            for _ in 0..200 {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                if next() % 2 == 0 {
+                    // keep elements non-negative so that prefix sums are monotone
+                    let x = (next() % 4) as i64;
+                    lst.range_update(l..r, Add(x));
+                    naive[l..r].iter_mut().for_each(|v| *v += x);
+                } else {
+                    let cap = (next() % (8 * n as u64 + 10)) as i64;
+                    let mut expected = l;
+                    let mut sum = 0;
+                    while expected < n && sum + naive[expected] <= cap {
+                        sum += naive[expected];
+                        expected += 1;
+                    }
+                    assert_eq!(lst.max_right(l, |v| v.sum <= cap), expected);
+                    assert_eq!(lst.max_right(l, |v| v.len <= (r - l) as i64), r);
+                }
+            }
+            assert_eq!(lst.max_right(n, |_| true), n);
+            assert_eq!(lst.max_right(0, |_| true), n);
+        }
+    }
+
+    #[test]
+    fn from_runs_matches_expansion() {
+        let mut next = xorshift(0x0bad_cafe);
+
+        for _ in 0..50 {
+            let runs = Vec::from_iter((0..next() % 10).map(|_| {
+                let value = (next() % 100) as i64;
+                (Sum { sum: value, len: 1 }, (next() % 8) as usize)
+            }));
+            let n = runs.iter().map(|(_, count)| count).sum();
+
+            // `flat_map` does not have an exact size hint
+            let mut expected = LazySegmentTree::<Add>::from_iter(
+                runs.iter()
+                    .flat_map(|(value, count)| std::iter::repeat(value.clone()).take(*count)),
+            );
+            let mut lst = LazySegmentTree::<Add>::from_runs(runs);
+            assert_eq!(lst.len(), n);
+            for _ in 0..20 {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                let x = (next() % 10) as i64;
+                lst.range_update(l..r, Add(x));
+                expected.range_update(l..r, Add(x));
+                assert_eq!(lst.range_query(l..), expected.range_query(l..));
+            }
+            assert_eq!(lst.into_vec(), expected.into_vec());
+        }
+    }
+
+    #[test]
+    fn range_update_indexed_arithmetic_progression() {
+        /// `(sum of values, number of elements, sum of indices)`
+        #[derive(Debug, Clone, PartialEq)]
+        struct IndexedSum(i64, i64, i64);
+
+        impl Monoid for IndexedSum {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0, 0, 0)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+            }
+        }
+
+        /// adds `c0 + c1 * i` to the `i`-th element
+        #[derive(Debug, Clone, PartialEq)]
+        struct AddLinear(i64, i64);
+
+        impl MonoidAct for AddLinear {
+            type Arg = IndexedSum;
+
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0, 0)
+            }
+
+            fn composite(&self, rhs: &Self) -> Self {
+                Self(self.0 + rhs.0, self.1 + rhs.1)
+            }
+
+            fn apply(&self, arg: &Self::Arg) -> Self::Arg {
+                IndexedSum(arg.0 + self.0 * arg.1 + self.1 * arg.2, arg.1, arg.2)
+            }
+        }
+
+        let mut next = xorshift(0x5eed_1234);
+
+        /// canonical decomposition of `[l, r)` into the nodes of a perfect binary tree
+        fn decompose(n: usize, l: usize, r: usize) -> Vec<Range<usize>> {
+            let size = n.next_power_of_two();
+            let (mut l, mut r, mut height) = (l + size, r + size, 0);
+            let mut segments = Vec::new();
+            while l < r {
+                if l & 1 == 1 {
+                    segments.push((l << height) - size..((l + 1) << height) - size);
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    segments.push((r << height) - size..((r + 1) << height) - size);
+                }
+                (l, r, height) = (l >> 1, r >> 1, height + 1);
+            }
+            segments.sort_unstable_by_key(|segment| segment.start);
+            segments
+        }
+
+        for n in [1, 2, 3, 8, 13, 32] {
+            let init = Vec::from_iter((0..n).map(|i| IndexedSum(0, 1, i as i64)));
+            let mut lhs = LazySegmentTree::<AddLinear>::from_iter(init.iter().cloned());
+            let mut rhs = LazySegmentTree::<AddLinear>::from_iter(init);
+            for _ in 0..100 {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                // adds `a + d * (i - l)` to the `i`-th element
+                let (a, d) = ((next() % 10) as i64, (next() % 10) as i64 - 5);
+
+                let mut covered = Vec::new();
+                lhs.range_update_indexed(l..r, |segment| {
+                    covered.push(segment);
+                    AddLinear(a - d * l as i64, d)
+                });
+                for i in l..r {
+                    rhs.point_update(i, AddLinear(a + d * (i - l) as i64, 0));
+                }
+
+                // segments partition the range
+                covered.sort_unstable_by_key(|segment| segment.start);
+                assert!(covered.len() <= 2 * (n.ilog2() as usize + 1));
+                assert_eq!(covered.first().map_or(r, |s| s.start), l);
+                assert_eq!(covered.last().map_or(l, |s| s.end), r);
+                assert!(covered.windows(2).all(|w| w[0].end == w[1].start));
+
+                // the act depends on the segment itself
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                let mut covered = Vec::new();
+                lhs.range_update_indexed(l..r, |segment| {
+                    let c = (segment.start + 2 * segment.len()) as i64;
+                    covered.push(segment);
+                    AddLinear(c, 0)
+                });
+                covered.sort_unstable_by_key(|segment| segment.start);
+                let segments = decompose(n, l, r);
+                assert_eq!(covered, segments);
+                for segment in segments {
+                    let c = (segment.start + 2 * segment.len()) as i64;
+                    for i in segment {
+                        rhs.point_update(i, AddLinear(c, 0));
+                    }
+                }
+
+                let (ql, qr) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (ql, qr) = (ql.min(qr), ql.max(qr));
+                assert_eq!(lhs.range_query(ql..qr), rhs.range_query(ql..qr));
+            }
+            assert_eq!(lhs.into_vec(), rhs.into_vec());
+        }
+    }
+}
@@ -10,10 +10,36 @@
 //! | [AssignSegmentTree] | *O*(log *N*) | *O*(log *N*) | *O*(log *N*) | *O*(log *N*) |
 //!
 //! * *N* is the number of elements.
+//!
+//! # Ready-made monoids
+//!
+//! The wrappers in [`math_traits::monoids`] implement [Monoid] as well.
+//! [Min] and [Gcd] are re-exported for convenience.
+//!
+//! ```
+//! use math_traits::monoids::Add;
+//! use seg_lib::{Gcd, Min, SegmentTree};
+//!
+//! let mut seg_tree = SegmentTree::from(vec![Min(3), Min(1)]);
+//! assert_eq!(seg_tree.range_query(..), Min(1));
+//! seg_tree.point_update(1, Min(5));
+//! assert_eq!(seg_tree.range_query(..), Min(3));
+//! assert_eq!(seg_tree.range_query(1..1), Min(i32::MAX));
+//!
+//! let mut seg_tree = SegmentTree::from(vec![Gcd(12_i64), Gcd(-18), Gcd(0), Gcd(8)]);
+//! assert_eq!(seg_tree.range_query(..2), Gcd(6));
+//! assert_eq!(seg_tree.range_query(1..=2), Gcd(18));
+//! seg_tree.point_update(3, Gcd(9));
+//! assert_eq!(seg_tree.range_query(..), Gcd(3));
+//!
+//! let seg_tree = SegmentTree::from(vec![Add(3_u64), Add(1), Add(4)]);
+//! assert_eq!(seg_tree.range_query(1..), Add(5));
+//! ```
 mod assign;
 mod dual;
 mod dynamic;
 mod lazy;
+mod monoids;
 mod normal;
 mod traits;
 
@@ -21,5 +47,6 @@ pub use assign::AssignSegmentTree;
 pub use dual::DualSegmentTree;
 pub use dynamic::DynamicSegmentTree;
 pub use lazy::LazySegmentTree;
-pub use normal::SegmentTree;
+pub use monoids::{Gcd, Matrix, Min, RangeAddRangeMin, RangeAffineRangeSum, RangeSum};
+pub use normal::{SegmentTree, SegmentTreeBuilder};
 pub use traits::{Monoid, MonoidAct};
@@ -0,0 +1,506 @@
+//! Ready-made monoids and acts.
+
+use std::{
+    iter::{Product, Sum},
+    ops::{Add, Mul},
+};
+
+use super::{Monoid, MonoidAct};
+
+pub use math_traits::monoids::{Gcd, Min};
+
+macro_rules! int_monoids_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for math_traits::monoids::Add<$t> {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl Monoid for math_traits::monoids::Xor<$t> {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl Monoid for math_traits::monoids::Max<$t> {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(<$t>::MIN)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0.max(rhs.0))
+            }
+        }
+    )*};
+}
+
+int_monoids_impl! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+macro_rules! gcd_monoid_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for Gcd<$t> {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                let (mut a, mut b) = (self.0.unsigned_abs(), rhs.0.unsigned_abs());
+                while b != 0 {
+                    (a, b) = (b, a % b)
+                }
+
+                Self(a as $t)
+            }
+        }
+    )*};
+}
+
+gcd_monoid_impl! { i8 i16 i32 i64 i128 isize }
+
+macro_rules! gcd_monoid_unsigned_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for Gcd<$t> {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                let (mut a, mut b) = (self.0, rhs.0);
+                while b != 0 {
+                    (a, b) = (b, a % b)
+                }
+
+                Self(a)
+            }
+        }
+    )*};
+}
+
+gcd_monoid_unsigned_impl! { u8 u16 u32 u64 u128 usize }
+
+/// Sum of a range together with its length, so that [RangeAffineRangeSum] can be applied.
+///
+/// Zero and one are taken as the empty [Sum] and the empty [Product] of `T`.
+/// This works for primitive numbers and for modular integers alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeSum<T> {
+    pub sum: T,
+    pub len: T,
+}
+
+impl<T: Product> RangeSum<T> {
+    /// Creates a range of length 1.
+    pub fn new(value: T) -> Self {
+        Self {
+            sum: value,
+            len: std::iter::empty().product(),
+        }
+    }
+}
+
+impl<T> Monoid for RangeSum<T>
+where
+    T: Clone + Add<Output = T> + Sum,
+{
+    const IS_COMMUTATIVE: bool = true;
+
+    fn identity() -> Self {
+        Self {
+            sum: std::iter::empty().sum(),
+            len: std::iter::empty().sum(),
+        }
+    }
+
+    fn binary_operation(&self, rhs: &Self) -> Self {
+        Self {
+            sum: self.sum.clone() + rhs.sum.clone(),
+            len: self.len.clone() + rhs.len.clone(),
+        }
+    }
+}
+
+/// Affine map `x -> tilt * x + offset` applied to every element of a range.
+///
+/// The sum of a range of length *L* becomes `tilt * sum + offset * L`.
+///
+/// # Examples
+///
+/// ```
+/// use mod_int::SMint;
+/// use seg_lib::{LazySegmentTree, RangeAffineRangeSum, RangeSum};
+///
+/// type Mint = SMint<998_244_353>;
+///
+/// let mut naive = vec![1_u64, 2, 3, 4, 5];
+/// let mut seg_tree = LazySegmentTree::<RangeAffineRangeSum<Mint>>::from_iter(
+///     naive.iter().map(|&a| RangeSum::new(Mint::new(a))),
+/// );
+///
+/// let queries = [(1, 2, 4, 3, 7), (0, 0, 5, 0, 0), (1, 2, 5, 9, 1), (0, 0, 3, 0, 0)];
+/// for (flag, l, r, b, c) in queries {
+///     if flag == 0 {
+///         let expected = naive[l..r].iter().sum::<u64>() % 998_244_353;
+///         assert_eq!(seg_tree.range_query(l..r).sum, Mint::new(expected));
+///     } else {
+///         naive[l..r].iter_mut().for_each(|a| *a = (b * *a + c) % 998_244_353);
+///         seg_tree.range_update(l..r, RangeAffineRangeSum::new(Mint::new(b), Mint::new(c)));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeAffineRangeSum<T> {
+    pub tilt: T,
+    pub offset: T,
+}
+
+impl<T> RangeAffineRangeSum<T> {
+    pub const fn new(tilt: T, offset: T) -> Self {
+        Self { tilt, offset }
+    }
+}
+
+impl<T> MonoidAct for RangeAffineRangeSum<T>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T> + Sum + Product,
+{
+    type Arg = RangeSum<T>;
+
+    const IS_COMMUTATIVE: bool = false;
+
+    fn identity() -> Self {
+        Self {
+            tilt: std::iter::empty().product(),
+            offset: std::iter::empty().sum(),
+        }
+    }
+
+    /// Applies `rhs` first, then `self`.
+    fn composite(&self, rhs: &Self) -> Self {
+        Self {
+            tilt: self.tilt.clone() * rhs.tilt.clone(),
+            offset: self.tilt.clone() * rhs.offset.clone() + self.offset.clone(),
+        }
+    }
+
+    fn apply(&self, arg: &Self::Arg) -> Self::Arg {
+        RangeSum {
+            sum: self.tilt.clone() * arg.sum.clone() + self.offset.clone() * arg.len.clone(),
+            len: arg.len.clone(),
+        }
+    }
+}
+
+/// Adds a constant to every element of a range of [Min].
+///
+/// `T::MAX` is reserved for the identity of [Min] and is never shifted,
+/// so that empty ranges stay empty. Overflows are not handled.
+///
+/// # Examples
+///
+/// ```
+/// use seg_lib::{LazySegmentTree, Min, RangeAddRangeMin};
+///
+/// let mut naive = vec![5_i64, 3, 8, -1, 4, 4];
+/// let mut seg_tree =
+///     LazySegmentTree::<RangeAddRangeMin<i64>>::from_iter(naive.iter().map(|&a| Min(a)));
+///
+/// for (l, r, c) in [(0, 3, 2), (2, 6, -7), (1, 2, 10), (0, 6, 1)] {
+///     naive[l..r].iter_mut().for_each(|a| *a += c);
+///     seg_tree.range_update(l..r, RangeAddRangeMin(c));
+///
+///     for l in 0..=naive.len() {
+///         for r in l..=naive.len() {
+///             let expected = naive[l..r].iter().copied().min().unwrap_or(i64::MAX);
+///             assert_eq!(seg_tree.range_query(l..r), Min(expected));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeAddRangeMin<T>(pub T);
+
+macro_rules! range_add_range_min_impl {
+    ( $( $t:ty )* ) => {$(
+        impl Monoid for Min<$t> {
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(<$t>::MAX)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0.min(rhs.0))
+            }
+        }
+
+        impl MonoidAct for RangeAddRangeMin<$t> {
+            type Arg = Min<$t>;
+
+            const IS_COMMUTATIVE: bool = true;
+
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            fn composite(&self, rhs: &Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+
+            fn apply(&self, arg: &Self::Arg) -> Self::Arg {
+                if arg.0 == <$t>::MAX {
+                    *arg
+                } else {
+                    Min(arg.0 + self.0)
+                }
+            }
+        }
+    )*};
+}
+
+range_add_range_min_impl! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+/// Square matrix of size `N`, where `self.0[i][j]` is the entry in row *i* and column *j*.
+///
+/// The binary operation is the matrix product, which is not commutative.
+/// Zero and one are taken as the empty [Sum] and the empty [Product] of `T`.
+///
+/// # Examples
+///
+/// ```
+/// use mod_int::SMint;
+/// use seg_lib::Matrix;
+///
+/// type Mint = SMint<998_244_353>;
+///
+/// // (F(n + 1), F(n)) = (F(n) + F(n - 1), F(n))
+/// let step = Matrix([[Mint::new(1), Mint::new(1)], [Mint::new(1), Mint::new(0)]]);
+/// let fib = |n: u64| step.pow(n).0[0][1];
+///
+/// assert_eq!(fib(0), Mint::new(0));
+/// assert_eq!(fib(10), Mint::new(55));
+/// assert_eq!(fib(100), Mint::new(494_958_974));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<T, const N: usize>(pub [[T; N]; N]);
+
+impl<T, const N: usize> Matrix<T, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sum + Product,
+{
+    /// Computes the `exp`-th power by repeated squaring.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*<sup>3</sup> log *exp*)
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut res = Self::identity();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = res.binary_operation(&base)
+            }
+            base = base.binary_operation(&base);
+            exp >>= 1;
+        }
+
+        res
+    }
+}
+
+impl<T, const N: usize> Monoid for Matrix<T, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sum + Product,
+{
+    fn identity() -> Self {
+        let mut data = [[std::iter::empty().sum(); N]; N];
+        for (i, row) in data.iter_mut().enumerate() {
+            row[i] = std::iter::empty().product()
+        }
+
+        Self(data)
+    }
+
+    /// Row-by-column product.
+    fn binary_operation(&self, rhs: &Self) -> Self {
+        let mut data = [[std::iter::empty().sum(); N]; N];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = (0..N).map(|k| self.0[i][k] * rhs.0[k][j]).sum()
+            }
+        }
+
+        Self(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::{LazySegmentTree, SegmentTree};
+
+    fn gcd(a: i64, b: i64) -> i64 {
+        if b == 0 {
+            a.abs()
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn range_gcd_with_updates() {
+        let mut values = vec![0_i64, 12, 18, 0, 0, -30, 7, 14, 21, 0, 45, 60, -90];
+        let mut seg_tree = SegmentTree::from_iter(values.iter().map(|&v| Gcd(v)));
+
+        let mut next = xorshift(12345);
+        for _ in 0..200 {
+            let i = next() as usize % values.len();
+            // zeros often appear
+            let v = [0, 6, 9, 10, 15, -25, 35][next() as usize % 7];
+            values[i] = v;
+            seg_tree.point_update(i, Gcd(v));
+
+            for l in 0..=values.len() {
+                for r in l..=values.len() {
+                    let expected = values[l..r].iter().fold(0, |acc, &v| gcd(acc, v));
+                    assert_eq!(seg_tree.range_query(l..r), Gcd(expected));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unsigned() {
+        let seg_tree = SegmentTree::from(vec![Gcd(0_u32), Gcd(24), Gcd(36), Gcd(0), Gcd(60)]);
+        assert_eq!(seg_tree.range_query(..), Gcd(12));
+        assert_eq!(seg_tree.range_query(3..4), Gcd(0));
+        assert_eq!(seg_tree.range_query(3..3), Gcd(0));
+        // idempotent
+        assert_eq!(Gcd(24_u32).binary_operation(&Gcd(24)), Gcd(24));
+    }
+
+    #[test]
+    fn signed_min() {
+        assert_eq!(Gcd(i64::MIN).binary_operation(&Gcd(0)), Gcd(i64::MIN));
+        assert_eq!(
+            Gcd(i64::MIN).binary_operation(&Gcd(i64::MIN)),
+            Gcd(i64::MIN)
+        );
+        assert_eq!(Gcd(i64::MIN).binary_operation(&Gcd(-12)), Gcd(4));
+    }
+
+    #[test]
+    fn range_affine_range_sum_random() {
+        let mut next = xorshift(0xabcd);
+
+        // arithmetic modulo 2^64
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct W(u64);
+        impl Add for W {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+        }
+        impl Mul for W {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_mul(rhs.0))
+            }
+        }
+        impl Sum for W {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(W(0), |acc, x| acc + x)
+            }
+        }
+        impl Product for W {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(W(1), |acc, x| acc * x)
+            }
+        }
+
+        let n = 37;
+        let mut naive = Vec::from_iter((0..n).map(|_| W(next())));
+        let mut seg_tree = LazySegmentTree::<RangeAffineRangeSum<W>>::from_iter(
+            naive.iter().map(|&a| RangeSum::new(a)),
+        );
+        for _ in 0..500 {
+            let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+            let (l, r) = (l.min(r), l.max(r));
+            if next() % 2 == 0 {
+                let (b, c) = (W(next()), W(next()));
+                naive[l..r].iter_mut().for_each(|a| *a = b * *a + c);
+                seg_tree.range_update(l..r, RangeAffineRangeSum::new(b, c));
+            } else {
+                let expected = naive[l..r].iter().copied().sum::<W>();
+                let actual = seg_tree.range_query(l..r);
+                assert_eq!(actual.sum, expected);
+                assert_eq!(actual.len, W((r - l) as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_fibonacci() {
+        type Mint = mod_int::SMint<1_000_000_007>;
+
+        let step = Matrix([[Mint::new(1), Mint::new(1)], [Mint::new(1), Mint::new(0)]]);
+        let (mut a, mut b) = (Mint::new(0), Mint::new(1));
+        let mut power = Matrix::identity();
+        for n in 0..300 {
+            assert_eq!(step.pow(n).0[0][1], a, "F({n})");
+            assert_eq!(power.0[0][1], a, "F({n})");
+            power = power.binary_operation(&step);
+            (a, b) = (b, a + b);
+        }
+    }
+
+    #[test]
+    fn matrix_product_order() {
+        // lower and upper shears do not commute
+        let values = [
+            Matrix([[1_i64, 2], [0, 1]]),
+            Matrix([[1, 0], [3, 1]]),
+            Matrix([[2, 0], [0, 1]]),
+            Matrix([[0, 1], [1, 0]]),
+        ];
+        let seg_tree = SegmentTree::from_iter(values);
+
+        for l in 0..=values.len() {
+            for r in l..=values.len() {
+                let expected = values[l..r]
+                    .iter()
+                    .fold(Matrix::identity(), |acc, m| acc.binary_operation(m));
+                assert_eq!(seg_tree.range_query(l..r), expected);
+            }
+        }
+        assert_ne!(
+            values[0].binary_operation(&values[1]),
+            values[1].binary_operation(&values[0])
+        );
+        assert_eq!(
+            values[0].binary_operation(&values[1]),
+            Matrix([[7, 2], [3, 1]])
+        );
+    }
+}
@@ -10,12 +10,14 @@ use super::Monoid;
 /// ## Basic Usage
 ///
 /// ```
-/// use segment_tree::{Monoid, SegmentTree};
+/// use seg_lib::{Monoid, SegmentTree};
 ///
 /// // range minimum query
 /// struct RMQ(i32);
 ///
 /// impl Monoid for RMQ {
+///     const IS_COMMUTATIVE: bool = true;
+///
 ///     fn identity() -> Self {
 ///         RMQ(i32::MAX)
 ///     }
@@ -43,11 +45,13 @@ use super::Monoid;
 /// generally yields better performance.
 ///
 /// ```
-/// use segment_tree::{Monoid, SegmentTree};
+/// use seg_lib::{Monoid, SegmentTree};
 ///
 /// struct MinMax(i32, i32);
 ///
 /// impl Monoid for MinMax {
+///     const IS_COMMUTATIVE: bool = true;
+///
 ///     fn identity() -> Self {
 ///         Self(i32::MAX, i32::MIN)
 ///     }
@@ -98,6 +102,20 @@ impl<T: Monoid> SegmentTree<T> {
         (self.inner_index(l), self.inner_index(r))
     }
 
+    /// Returns `false` if the leaves under `data[i]` lie at different depths.
+    ///
+    /// Unless the number of elements `n` is a power of two, such nodes cover non-consecutive elements
+    /// and are never used by queries. They are exactly the proper ancestors of `n >> n.trailing_zeros()`,
+    /// so the ancestors of an invalid node are also invalid.
+    #[inline]
+    fn is_valid(&self, i: usize) -> bool {
+        let n = self.data.len() / 2;
+        let c = n >> n.trailing_zeros();
+        let (depth_i, depth_c) = (i.ilog2(), c.ilog2());
+
+        depth_i >= depth_c || c >> (depth_c - depth_i) != i
+    }
+
     /// Returns a reference to a single element.
     ///
     /// # Panics
@@ -109,25 +127,58 @@ impl<T: Monoid> SegmentTree<T> {
         &self.data[i]
     }
 
+    /// Returns all elements as a slice, in order.
+    ///
+    /// The slice is shared, since writing to a leaf directly would leave its ancestors stale.
+    /// Use [`point_update`](Self::point_update) and its variants instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{Min, SegmentTree};
+    ///
+    /// let seg_tree = SegmentTree::from_iter([3, 1, 4].map(Min));
+    /// assert_eq!(seg_tree.leaves(), [Min(3), Min(1), Min(4)]);
+    /// ```
+    #[inline]
+    pub fn leaves(&self) -> &[T] {
+        &self.data[self.data.len() / 2..]
+    }
+
     /// Returns the result of combining elements over the 'given' range.
     ///
     /// # Panics
     ///
     /// Panics if given `range` is out of bounds.
     pub fn range_query<R>(&self, range: R) -> T
+    where
+        R: RangeBounds<usize>,
+    {
+        let mut res = T::identity();
+        self.range_query_into(range, &mut res);
+
+        res
+    }
+
+    /// Combines elements over the given `range` into `acc`, that is, performs `acc <- acc ∘ a[l] ∘ ... ∘ a[r - 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given `range` is out of bounds.
+    pub fn range_query_into<R>(&self, range: R, acc: &mut T)
     where
         R: RangeBounds<usize>,
     {
         let (mut l, mut r) = self.inner_range(range);
 
         if l >= r {
-            return T::identity();
+            return;
         }
 
         // calculate result over [l, r)
         l >>= l.trailing_zeros();
         r >>= r.trailing_zeros();
-        let (mut res_l, mut res_r) = (T::identity(), T::identity());
+        let (mut res_l, mut res_r) = (std::mem::replace(acc, T::identity()), T::identity());
         loop {
             if l >= r {
                 res_l = res_l.binary_operation(&self.data[l]);
@@ -144,7 +195,7 @@ impl<T: Monoid> SegmentTree<T> {
             }
         }
 
-        res_l.binary_operation(&res_r)
+        *acc = res_l.binary_operation(&res_r)
     }
 
     /// Replace the `i`-th element with the given one.
@@ -155,11 +206,82 @@ impl<T: Monoid> SegmentTree<T> {
     pub fn point_update(&mut self, i: usize, element: T) -> T {
         let mut i = self.inner_index(i);
         let old = std::mem::replace(&mut self.data[i], element);
-        // TODO: remove updates on invalid nodes
         while i > 1 {
             i >>= 1;
+            if !self.is_valid(i) {
+                break;
+            }
+            self.data[i] = self.data[i * 2].binary_operation(&self.data[i * 2 + 1])
+        }
+
+        old
+    }
+
+    /// Replace the `i`-th element `a[i]` with `f(a[i])`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{Gcd, SegmentTree};
+    ///
+    /// let mut seg_tree = SegmentTree::from(vec![Gcd(4_u32), Gcd(6), Gcd(10)]);
+    /// seg_tree.point_apply(1, |v| Gcd(v.0 * 3));
+    /// assert_eq!(seg_tree.point_query(1), &Gcd(18));
+    /// assert_eq!(seg_tree.range_query(1..), Gcd(2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given index is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    pub fn point_apply<F>(&mut self, i: usize, f: F)
+    where
+        F: FnOnce(&T) -> T,
+    {
+        let mut i = self.inner_index(i);
+        self.data[i] = f(&self.data[i]);
+        while i > 1 {
+            i >>= 1;
+            if !self.is_valid(i) {
+                break;
+            }
             self.data[i] = self.data[i * 2].binary_operation(&self.data[i * 2 + 1])
         }
+    }
+
+    /// Replace the `i`-th element with the given one, and recomputes its ancestors
+    /// while `should_continue(old, new)` returns `true` for the recomputed value of each ancestor.
+    ///
+    /// If the aggregate of some ancestor is unchanged, the ones above it will not change either.
+    /// Stopping there gives the same result as [SegmentTree::point_update].
+    ///
+    /// # Panics
+    ///
+    /// Panics if given index is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*)
+    pub fn point_update_if<F>(&mut self, i: usize, element: T, should_continue: F) -> T
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut i = self.inner_index(i);
+        let old = std::mem::replace(&mut self.data[i], element);
+        while i > 1 {
+            i >>= 1;
+            if !self.is_valid(i) {
+                break;
+            }
+            let new = self.data[i * 2].binary_operation(&self.data[i * 2 + 1]);
+            let prev = std::mem::replace(&mut self.data[i], new);
+            if !should_continue(&prev, &self.data[i]) {
+                break;
+            }
+        }
 
         old
     }
@@ -181,27 +303,44 @@ impl<T: Monoid> SegmentTree<T> {
         self.data.into_vec().split_off(n)
     }
 
-    #[allow(dead_code)]
-    fn fill<R>(&mut self, range: R, value: T)
+    /// Replace every element in the given `range` with `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seg_lib::{Gcd, SegmentTree};
+    ///
+    /// let mut seg_tree = SegmentTree::from(vec![Gcd(4_u32), Gcd(6), Gcd(10), Gcd(8)]);
+    /// seg_tree.fill(1..3, Gcd(12));
+    /// assert_eq!(seg_tree.into_vec(), [Gcd(4), Gcd(12), Gcd(12), Gcd(8)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given `range` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*K* + log *N*), where *K* is the length of the range.
+    pub fn fill<R>(&mut self, range: R, value: T)
     where
         T: Clone,
         R: RangeBounds<usize>,
     {
-        let (mut l, mut r) = self.inner_range(range);
+        let (l, r) = self.inner_range(range);
+        if l >= r {
+            return;
+        }
         self.data[l..r].fill(value);
 
-        (l, r) = (l / 2, r / 2);
-        while l < r {
-            for i in l..r {
+        // Ancestors of `[l, r)` are recomputed in descending order of indices,
+        // since leaves may lie at different depths unless the length is a power of two.
+        let (mut l, mut r) = (l, r - 1);
+        while l > 1 {
+            (l, r) = (l / 2, (r / 2).min(l - 1));
+            for i in (l..=r).rev() {
                 self.data[i] = self.data[i * 2].binary_operation(&self.data[i * 2 + 1])
             }
-            (l, r) = (l / 2, r / 2);
-        }
-
-        assert_eq!(l, r);
-        while l > 1 {
-            l /= 2;
-            self.data[l] = self.data[l * 2].binary_operation(&self.data[l * 2 + 1])
         }
     }
 }
@@ -250,6 +389,79 @@ impl<T: Monoid> FromIterator<T> for SegmentTree<T> {
     }
 }
 
+/// Builds a [`SegmentTree`] from elements pushed one by one, whose number is not known in advance.
+///
+/// Unlike [`SegmentTree::from_iter`] with an inexact size hint, elements are not collected twice
+/// as long as their number does not exceed the capacity.
+///
+/// # Example
+///
+/// ```
+/// use seg_lib::{Min, SegmentTreeBuilder};
+///
+/// let mut builder = SegmentTreeBuilder::with_capacity(10);
+/// for x in (0..10).filter(|x| x % 3 != 0) {
+///     builder.push(Min(x));
+/// }
+/// let seg_tree = builder.build();
+///
+/// assert_eq!(seg_tree.leaves(), [1, 2, 4, 5, 7, 8].map(Min));
+/// assert_eq!(seg_tree.range_query(2..5), Min(4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentTreeBuilder<T: Monoid> {
+    /// pushed elements, followed by spare capacity for inner nodes
+    data: Vec<T>,
+}
+
+impl<T: Monoid> SegmentTreeBuilder<T> {
+    /// Creates an empty builder with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: Vec::with_capacity(capacity * 2),
+        }
+    }
+
+    /// Appends an element. Inner nodes are not computed until [`build`](Self::build).
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1), amortized
+    pub fn push(&mut self, value: T) {
+        self.data.push(value)
+    }
+
+    /// Returns the number of pushed elements.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Computes all inner nodes at once.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn build(self) -> SegmentTree<T> {
+        let Self { mut data } = self;
+
+        // same layout as `from()`
+        let n = data.len();
+        data.extend(std::iter::repeat_with(T::identity).take(n));
+        data.rotate_right(n);
+        for i in (1..n).rev() {
+            data[i] = data[2 * i].binary_operation(&data[2 * i + 1])
+        }
+
+        SegmentTree {
+            data: data.into_boxed_slice(),
+        }
+    }
+}
+
 impl<T: Monoid> IntoIterator for SegmentTree<T> {
     type Item = T;
     type IntoIter = <Vec<T> as IntoIterator>::IntoIter;
@@ -267,3 +479,217 @@ impl<T: Monoid> Index<usize> for SegmentTree<T> {
         &self.data[i]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Min(i32);
+
+    impl Monoid for Min {
+        const IS_COMMUTATIVE: bool = true;
+
+        fn identity() -> Self {
+            Self(i32::MAX)
+        }
+
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0.min(rhs.0))
+        }
+    }
+
+    #[test]
+    fn range_query_into() {
+        let seg_tree = SegmentTree::from_iter((0..13).map(|i| Min((i * 7 % 13) - 6)));
+        for l in 0..=13 {
+            for r in l..=13 {
+                let mut acc = Min::identity();
+                seg_tree.range_query_into(l..r, &mut acc);
+                assert_eq!(acc, seg_tree.range_query(l..r));
+
+                // fold several ranges into the same accumulator
+                let mut acc = Min(0);
+                seg_tree.range_query_into(..l, &mut acc);
+                seg_tree.range_query_into(r.., &mut acc);
+                assert_eq!(
+                    acc,
+                    Min(0)
+                        .binary_operation(&seg_tree.range_query(..l))
+                        .binary_operation(&seg_tree.range_query(r..))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn leaves_match_point_query() {
+        for n in [1, 2, 5, 8, 13] {
+            let mut seg_tree = SegmentTree::from_iter((0..n).map(|i| Min((i * 7 % 5) as i32)));
+            seg_tree.point_update(n / 2, Min(-1));
+            assert_eq!(seg_tree.leaves().len(), n);
+            for i in 0..n {
+                assert_eq!(&seg_tree.leaves()[i], seg_tree.point_query(i));
+            }
+        }
+    }
+
+    #[test]
+    fn builder_matches_from_vec() {
+        for n in 0..=33 {
+            for capacity in [0, n / 2, n, n + 5] {
+                let values = Vec::from_iter((0..n).map(|i| Min((i * 7 % 11) as i32 - 5)));
+                let mut builder = SegmentTreeBuilder::with_capacity(capacity);
+                for &v in &values {
+                    builder.push(v);
+                }
+                assert_eq!(builder.len(), n);
+
+                let lhs = builder.build();
+                let rhs = SegmentTree::from(values);
+                assert_eq!(lhs.leaves(), rhs.leaves());
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(lhs.range_query(l..r), rhs.range_query(l..r));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn point_update_if_matches_point_update() {
+        for n in [1, 2, 5, 8, 13] {
+            let mut lhs = SegmentTree::from_iter((0..n).map(|i| Min(i as i32)));
+            let mut rhs = lhs.clone();
+
+            let mut x = 7_i32;
+            for k in 0..100 {
+                x = x.wrapping_mul(48271).rem_euclid(1 << 20);
+                let i = k % n;
+                assert_eq!(
+                    lhs.point_update_if(i, Min(x % 50), |old, new| old != new),
+                    rhs.point_update(i, Min(x % 50)),
+                );
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(lhs.range_query(l..r), rhs.range_query(l..r));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn point_apply_matches_point_update() {
+        for n in [1, 2, 5, 8, 13] {
+            let mut lhs = SegmentTree::from_iter((0..n).map(|i| Min(i as i32)));
+            let mut rhs = lhs.clone();
+
+            for k in 0..50 {
+                let i = k * 7 % n;
+                let delta = k as i32 % 5 - 2;
+                lhs.point_apply(i, |v| Min(v.0 + delta));
+                let value = Min(rhs.point_query(i).0 + delta);
+                rhs.point_update(i, value);
+
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(lhs.range_query(l..r), rhs.range_query(l..r));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fill_matches_point_update() {
+        for n in 1..=17 {
+            for l in 0..=n {
+                for r in l..=n {
+                    let mut lhs = SegmentTree::from_iter((0..n).map(|i| Min((i * 5 % 7) as i32)));
+                    let mut rhs = lhs.clone();
+
+                    lhs.fill(l..r, Min(-(l as i32)));
+                    for i in l..r {
+                        rhs.point_update(i, Min(-(l as i32)));
+                    }
+                    for ql in 0..=n {
+                        for qr in ql..=n {
+                            assert_eq!(lhs.range_query(ql..qr), rhs.range_query(ql..qr));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn point_update_if_stops_early() {
+        let mut seg_tree = SegmentTree::from_iter((0..16).map(Min));
+        let count = Cell::new(0);
+        let changed = |old: &Min, new: &Min| {
+            count.set(count.get() + 1);
+            old != new
+        };
+
+        // the sibling `Min(0)` keeps the parent unchanged
+        seg_tree.point_update_if(1, Min(100), changed);
+        assert_eq!(count.get(), 1);
+        assert_eq!(seg_tree.range_query(..), Min(0));
+        assert_eq!(seg_tree.range_query(1..2), Min(100));
+
+        // the new minimum reaches the root
+        count.set(0);
+        seg_tree.point_update_if(15, Min(-1), changed);
+        assert_eq!(count.get(), 4);
+        assert_eq!(seg_tree.range_query(..), Min(-1));
+    }
+
+    #[test]
+    fn point_update_skips_invalid_nodes() {
+        thread_local! {
+            static COUNT: Cell<usize> = Cell::new(0);
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct CountedMin(i32);
+
+        impl Monoid for CountedMin {
+            fn identity() -> Self {
+                Self(i32::MAX)
+            }
+
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                COUNT.with(|count| count.set(count.get() + 1));
+                Self(self.0.min(rhs.0))
+            }
+        }
+
+        for n in [3, 5, 6, 7, 12, 13, 24, 100] {
+            let mut values = Vec::from_iter((0..n).map(|i| CountedMin((i * 7 % 11) as i32)));
+            let mut seg_tree = SegmentTree::from(values.clone());
+
+            let (mut touched, mut full_path) = (0, 0);
+            for k in 0..3 * n {
+                let i = k * 5 % n;
+                values[i] = CountedMin((k * 13 % 17) as i32 - 8);
+
+                COUNT.with(|count| count.set(0));
+                seg_tree.point_update(i, values[i]);
+                touched += COUNT.with(Cell::get);
+                full_path += (n + i).ilog2() as usize;
+
+                let naive = SegmentTree::from(values.clone());
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(seg_tree.range_query(l..r), naive.range_query(l..r));
+                    }
+                }
+            }
+            assert!(touched < full_path, "n = {n}");
+        }
+    }
+}
@@ -1,6 +1,12 @@
 /// Defines a set of elements which forms a monoid
 pub trait Monoid {
-    const IS_COMMUTATIVE: bool;
+    /// Whether [Monoid::binary_operation] is commutative or not.
+    ///
+    /// Defaults to `false`, which is always correct.
+    /// Set `true` for commutative operations such as sum or xor,
+    /// so that some data structures (e.g. [DualSegmentTree](crate::DualSegmentTree)) can skip propagation.
+    /// Setting `true` for a non-commutative operation will produce wrong results.
+    const IS_COMMUTATIVE: bool = false;
 
     /// Returns the identity element.
     fn identity() -> Self;
@@ -0,0 +1,11 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/counting_primes
+
+use fast_io::prelude::{fast_stdin_locked, fast_stdout_locked};
+use sieve_of_eratosthenes::prime_counting_function;
+
+fn main() {
+    let n: u64 = fast_stdin_locked().next_token().unwrap();
+
+    let mut fast_out = fast_stdout_locked();
+    fast_out.fast_writeln(&prime_counting_function(n)).unwrap();
+}
@@ -0,0 +1,195 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Returns an iterator over primes less than or equal to `upper` in ascending order.
+///
+/// Primes are generated on the fly by an incremental sieve on the 2-3-5 wheel.
+/// Unlike [`SieveOfEratosthenes`](crate::SieveOfEratosthenes), no bitset of size *O*(`upper`) is allocated;
+/// only primes `p` with `p * p` not greater than the current candidate are kept in a priority queue of their next multiples.
+/// Such base primes are supplied by another lazy sieve, so that a prime is not stored until its square is reached.
+///
+/// # Example
+///
+/// ```
+/// use sieve_of_eratosthenes::lazy_primes;
+///
+/// assert_eq!(Vec::from_iter(lazy_primes(30)), [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+///
+/// // `upper` only bounds the memory usage
+/// let p = lazy_primes(u64::MAX).nth(9_999);
+/// assert_eq!(p, Some(104_729));
+/// ```
+///
+/// # Time complexity
+///
+/// *O*(*N* log log *N* log π(sqrt(*N*))) to yield all primes up to *N*
+///
+/// # Space complexity
+///
+/// *O*(π(sqrt(*N*))), where *N* is the largest prime yielded so far
+pub fn lazy_primes(upper: u64) -> impl Iterator<Item = u64> {
+    LazyPrimes::new(upper)
+}
+
+/// Primes which divide the wheel.
+const WHEEL_PRIMES: [u64; 3] = [2, 3, 5];
+/// Gaps between integers coprime to 30, starting from 7.
+const WHEEL_GAPS: [u64; 8] = [4, 2, 4, 2, 4, 6, 2, 6];
+
+struct LazyPrimes {
+    upper: u64,
+    /// the number of yielded primes in [`WHEEL_PRIMES`]
+    small: usize,
+    /// the next integer coprime to 30, or `None` on overflow
+    candidate: Option<u64>,
+    /// index of [`WHEEL_GAPS`] for `candidate`
+    wheel: usize,
+    /// `(p * m, p, i)` where `m` is the next multiplier coprime to 30 and `i` is its index of [`WHEEL_GAPS`]
+    multiples: BinaryHeap<Reverse<(u64, u64, usize)>>,
+    /// `(p, i)` for the smallest prime `p` not in `multiples` and its index `i` of [`WHEEL_GAPS`],
+    /// or `None` if `base` is exhausted
+    next_base: Option<(u64, usize)>,
+    /// primes following `next_base`, created when `next_base` is consumed for the first time
+    base: Option<Box<LazyPrimes>>,
+}
+
+impl LazyPrimes {
+    fn new(upper: u64) -> Self {
+        Self {
+            upper,
+            small: 0,
+            candidate: Some(7),
+            wheel: 0,
+            multiples: BinaryHeap::new(),
+            next_base: Some((7, 0)),
+            base: None,
+        }
+    }
+
+    /// Returns the next prime coprime to 30 and its index of [`WHEEL_GAPS`].
+    fn next_coprime(&mut self) -> Option<(u64, usize)> {
+        loop {
+            let n = self.candidate.filter(|&n| n <= self.upper)?;
+            let wheel = self.wheel;
+            self.candidate = n.checked_add(WHEEL_GAPS[wheel]);
+            self.wheel = (wheel + 1) % WHEEL_GAPS.len();
+
+            if let Some((p, i)) = self.next_base.filter(|&(p, _)| p.checked_mul(p) == Some(n)) {
+                // smaller multiples of `p` have another prime factor less than `p`
+                if let Some(m) = (p * WHEEL_GAPS[i])
+                    .checked_add(n)
+                    .filter(|&m| m <= self.upper)
+                {
+                    self.multiples
+                        .push(Reverse((m, p, (i + 1) % WHEEL_GAPS.len())))
+                }
+                self.next_base = self
+                    .base
+                    .get_or_insert_with(|| {
+                        // the base sieve starts just after `7`, which is the first `next_base`
+                        Box::new(LazyPrimes {
+                            candidate: Some(11),
+                            wheel: 1,
+                            ..LazyPrimes::new(self.upper)
+                        })
+                    })
+                    .next_coprime();
+                continue;
+            }
+
+            let mut is_composite = false;
+            while let Some(&Reverse((m, p, i))) = self.multiples.peek() {
+                if m != n {
+                    debug_assert!(m > n);
+                    break;
+                }
+
+                is_composite = true;
+                self.multiples.pop();
+                // multiples greater than `upper` are never checked
+                if let Some(m) = (p * WHEEL_GAPS[i])
+                    .checked_add(m)
+                    .filter(|&m| m <= self.upper)
+                {
+                    self.multiples
+                        .push(Reverse((m, p, (i + 1) % WHEEL_GAPS.len())))
+                }
+            }
+
+            if !is_composite {
+                return Some((n, wheel));
+            }
+        }
+    }
+}
+
+impl Iterator for LazyPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(&p) = WHEEL_PRIMES.get(self.small) {
+            self.small += 1;
+            return (p <= self.upper).then_some(p);
+        }
+
+        self.next_coprime().map(|(p, _)| p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SieveOfEratosthenes;
+
+    #[test]
+    fn compare_with_sieve() {
+        let n = 2_000_000;
+        let all = Vec::from_iter(SieveOfEratosthenes::new(n).into_primes().map(u64::from));
+
+        for upper in 0..300 {
+            assert_eq!(
+                Vec::from_iter(lazy_primes(upper)),
+                Vec::from_iter(all.iter().copied().take_while(|&p| p <= upper)),
+                "upper = {upper}"
+            );
+        }
+        assert_eq!(Vec::from_iter(lazy_primes(n as u64)), all);
+
+        // the first primes do not depend on `upper`
+        for upper in [1_000_000_007, u64::MAX - 1, u64::MAX] {
+            assert!(lazy_primes(upper).take(all.len()).eq(all.iter().copied()));
+        }
+    }
+
+    #[test]
+    fn squares_of_primes() {
+        // `p * p` is the first multiple crossed off by `p`
+        for p in [7_u64, 11, 997, 3_001] {
+            let upper = p * p;
+            let primes = Vec::from_iter(lazy_primes(upper));
+            assert_ne!(primes.last(), Some(&upper));
+            assert_eq!(
+                primes.len(),
+                Vec::from_iter(lazy_primes(upper - 1)).len(),
+                "p = {p}"
+            );
+        }
+    }
+
+    #[test]
+    fn postponed_base_primes() {
+        let all = Vec::from_iter(
+            SieveOfEratosthenes::new(1_000_000)
+                .into_primes()
+                .map(u64::from),
+        );
+
+        let mut primes = LazyPrimes::new(u64::MAX);
+        for &q in &all {
+            assert_eq!(primes.next(), Some(q));
+
+            // primes `p` coprime to 30 such that `p * p <= q`
+            let base = all.iter().skip(3).take_while(|&&p| p * p <= q).count();
+            assert_eq!(primes.multiples.len(), base, "q = {q}");
+        }
+    }
+}
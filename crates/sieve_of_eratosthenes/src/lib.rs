@@ -1,8 +1,20 @@
+mod incremental;
+mod prime_counting;
+mod segmented;
+mod smallest_prime_factor;
+
+pub use incremental::lazy_primes;
+pub use prime_counting::prime_counting_function;
+pub use segmented::primes_in_range;
+pub use smallest_prime_factor::{num_divisors_table, sum_divisors_table, SmallestPrimeFactor};
+
 #[derive(Clone)]
 pub struct SieveOfEratosthenes {
     /// 2 * i + 1
     is_not_prime: Box<[u64]>,
     max: usize,
+    /// `prefix_count[i]` is the number of odd primes in `is_not_prime[..i]`.
+    prefix_count: Box<[u32]>,
 }
 
 impl SieveOfEratosthenes {
@@ -53,9 +65,16 @@ impl SieveOfEratosthenes {
             off_set += chunk.len() * 64;
         }
 
+        let mut prefix_count = Vec::with_capacity(is_not_prime.len() + 1);
+        prefix_count.push(0);
+        for bits in is_not_prime.iter() {
+            prefix_count.push(prefix_count.last().unwrap() + bits.count_zeros())
+        }
+
         Self {
             is_not_prime,
             max: n,
+            prefix_count: prefix_count.into_boxed_slice(),
         }
     }
 
@@ -63,15 +82,144 @@ impl SieveOfEratosthenes {
         i == 2 || (i % 2 == 1 && { self.is_not_prime[i / 2 / 64] & (1 << (i / 2 % 64)) == 0 })
     }
 
+    /// Returns the number of primes less than or equal to `max`.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn count_primes(&self) -> usize {
+        self.prime_pi(self.max)
+    }
+
+    /// Returns the number of primes less than or equal to `x`, that is, π(*x*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is greater than `max`.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn prime_pi(&self, x: usize) -> usize {
+        assert!(x <= self.max, "out of bounds");
+        if x < 2 {
+            return 0;
+        }
+
+        // odd numbers `2 * i + 1` for `i <= k`, and 2
+        let k = (x - 1) / 2;
+        let mask = !0 >> (63 - k % 64);
+        let rest = (!self.is_not_prime[k / 64] & mask).count_ones();
+        (self.prefix_count[k / 64] + rest) as usize + 1
+    }
+
+    /// Returns Euler's totient φ(*i*) for each `i <= max`, where φ(0) is defined as `0`.
+    ///
+    /// φ(*i*) is derived from φ(*i* / *p*), where *p* is the smallest prime factor of *i*.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn totient_table(&self) -> Box<[u32]> {
+        assert!(self.max <= u32::MAX as usize);
+
+        let spf = SmallestPrimeFactor::new(self.max);
+        let mut phi = vec![0; self.max + 1].into_boxed_slice();
+        if self.max >= 1 {
+            phi[1] = 1
+        }
+        for i in 2..=self.max {
+            let p = spf.smallest_prime_factor(i).unwrap();
+            let m = i / p;
+            // φ(pm) = φ(m) p if p | m, φ(m) (p - 1) otherwise
+            phi[i] = phi[m] * if m % p == 0 { p as u32 } else { p as u32 - 1 };
+        }
+
+        phi
+    }
+
+    /// Returns the Möbius function μ(*i*) for each `i <= max`, where μ(0) is defined as `0`.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N* log log *N*)
+    pub fn mobius_table(&self) -> Box<[i8]> {
+        let mut mu = vec![1; self.max + 1].into_boxed_slice();
+        mu[0] = 0;
+        for p in (2..=self.max).filter(|&p| self.is_prime(p)) {
+            for n in (p..=self.max).step_by(p) {
+                mu[n] = -mu[n]
+            }
+            // not square-free
+            for n in (p * p..=self.max).step_by(p * p) {
+                mu[n] = 0
+            }
+        }
+
+        mu
+    }
+
+    /// Returns an iterator over pairs of consecutive primes `(p, q)` up to `max`, in ascending order.
+    ///
+    /// The first pair is `(2, 3)`, and `q - p == 2` for twin primes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sieve_of_eratosthenes::SieveOfEratosthenes;
+    ///
+    /// let sieve = SieveOfEratosthenes::new(20);
+    /// let gaps = Vec::from_iter(sieve.prime_gaps());
+    /// assert_eq!(gaps, [(2, 3), (3, 5), (5, 7), (7, 11), (11, 13), (13, 17), (17, 19)]);
+    ///
+    /// let twins = gaps.iter().filter(|(p, q)| q - p == 2).count();
+    /// assert_eq!(twins, 4);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1) amortized per pair
+    pub fn prime_gaps(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        let mut primes = self.primes();
+        let mut prev = primes.next();
+        primes.map(move |q| {
+            // `prev` holds the previous prime, since `primes` has already yielded one
+            let p = prev.replace(q).unwrap();
+            (p, q)
+        })
+    }
+
+    /// Returns an iterator over primes up to `max` in ascending order, without consuming `self`.
+    pub fn primes(&self) -> impl Iterator<Item = u32> + '_ {
+        let max = self.max;
+        Primes {
+            words: self.is_not_prime.iter().copied(),
+            is_prime: 0,
+            offset: 0,
+            state: if max >= 2 {
+                State::Entry
+            } else {
+                State::Finished
+            },
+        }
+        // odd numbers greater than `max` are not sieved
+        .take_while(move |&p| p as usize <= max)
+    }
+
     pub fn into_primes(self) -> Primes {
         let Self {
             mut is_not_prime,
             max,
+            ..
         } = self;
 
-        is_not_prime[max / 128] |= !0 << ((max + 1) / 2 % 64);
+        // remove odd numbers greater than `max`, i.e. `2 * i + 1` for `i >= (max + 1) / 2`
+        let end = (max + 1) / 2;
+        if let Some(bits) = is_not_prime.get_mut(end / 64) {
+            *bits |= !0 << (end % 64)
+        }
         Primes {
-            into_iter: is_not_prime.into_vec().into_iter(),
+            words: is_not_prime.into_vec().into_iter(),
             is_prime: 0,
             offset: 0,
             state: if max >= 2 {
@@ -88,19 +236,19 @@ const DIVIDABLE_BY_3_OR_5_OR_7: [u64; 3 * 5 * 7] = {
     // 3 = 2 * 1 + 1
     let mut i = 1;
     while i < 105 * 64 {
-        result[i / 64] |= 1 << i % 64;
+        result[i / 64] |= 1 << (i % 64);
         i += 3
     }
     // 5 = 2 * 2 + 1;
     i = 2;
     while i < 105 * 64 {
-        result[i / 64] |= 1 << i % 64;
+        result[i / 64] |= 1 << (i % 64);
         i += 5
     }
     // 7 = 2* 3 + 1
     i = 3;
     while i < 105 * 64 {
-        result[i / 64] |= 1 << i % 64;
+        result[i / 64] |= 1 << (i % 64);
         i += 7
     }
 
@@ -112,14 +260,15 @@ enum State {
     OnGoing,
     Finished,
 }
-pub struct Primes {
-    into_iter: <Vec<u64> as IntoIterator>::IntoIter,
+pub struct Primes<I = <Vec<u64> as IntoIterator>::IntoIter> {
+    /// words of `is_not_prime`
+    words: I,
     is_prime: u64,
     offset: u32,
     state: State,
 }
 
-impl Iterator for Primes {
+impl<I: Iterator<Item = u64>> Iterator for Primes<I> {
     type Item = u32;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -127,7 +276,7 @@ impl Iterator for Primes {
             State::OnGoing => (),
             State::Finished => return None,
             State::Entry => {
-                if let Some(is_not_prime) = self.into_iter.next() {
+                if let Some(is_not_prime) = self.words.next() {
                     self.is_prime = !is_not_prime;
                     self.state = State::OnGoing;
                     return Some(2);
@@ -140,7 +289,7 @@ impl Iterator for Primes {
 
         match self.is_prime.trailing_zeros() {
             64 => {
-                while let Some(is_not_prime) = self.into_iter.next() {
+                for is_not_prime in self.words.by_ref() {
                     self.offset += 64;
                     self.is_prime = !is_not_prime;
 
@@ -166,7 +315,141 @@ impl Iterator for Primes {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (_, max) = self.into_iter.size_hint();
+        let (_, max) = self.words.size_hint();
         (0, max.map(|v| v * 64))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gcd(a: u32, b: u32) -> u32 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn prime_pi() {
+        let sieve = SieveOfEratosthenes::new(100_000);
+        assert_eq!(sieve.prime_pi(0), 0);
+        assert_eq!(sieve.prime_pi(1), 0);
+        assert_eq!(sieve.prime_pi(2), 1);
+        assert_eq!(sieve.prime_pi(10), 4);
+        assert_eq!(sieve.prime_pi(100), 25);
+        assert_eq!(sieve.prime_pi(1000), 168);
+        assert_eq!(sieve.count_primes(), 9592);
+
+        let mut count = 0;
+        for x in 0..=100_000 {
+            count += sieve.is_prime(x) as usize;
+            assert_eq!(sieve.prime_pi(x), count, "x = {x}");
+        }
+
+        for n in 0..200 {
+            let sieve = SieveOfEratosthenes::new(n);
+            assert_eq!(sieve.count_primes(), sieve.clone().into_primes().count(), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn borrowed_primes() {
+        for n in (0..300).chain([1 << 12, (1 << 12) + 1, 10_007]) {
+            let sieve = SieveOfEratosthenes::new(n);
+            assert!(sieve.primes().eq(sieve.clone().into_primes()), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SieveOfEratosthenes>();
+    }
+
+    #[test]
+    fn prime_gaps() {
+        for n in [0, 1, 2, 3, 4, 5, 100, 10_000] {
+            let sieve = SieveOfEratosthenes::new(n);
+            let primes = Vec::from_iter(sieve.clone().into_primes());
+            let gaps = Vec::from_iter(sieve.prime_gaps());
+            assert_eq!(gaps.len(), primes.len().saturating_sub(1), "n = {n}");
+
+            for (&(p, q), pq) in gaps.iter().zip(primes.windows(2)) {
+                assert_eq!([p, q], pq);
+                assert!(sieve.is_prime(p as usize) && sieve.is_prime(q as usize));
+                // no prime in between
+                assert!((p + 1..q).all(|x| !sieve.is_prime(x as usize)));
+            }
+        }
+
+        let sieve = SieveOfEratosthenes::new(100);
+        let gaps = sieve.prime_gaps();
+        // borrows `sieve`
+        assert!(sieve.is_prime(97));
+        assert!(gaps.take(5).eq([(2, 3), (3, 5), (5, 7), (7, 11), (11, 13)]));
+        // the gap between 89 and 97 is the largest below 100
+        assert_eq!(sieve.prime_gaps().map(|(p, q)| q - p).max(), Some(8));
+    }
+
+    #[test]
+    fn mobius_table() {
+        let mu = SieveOfEratosthenes::new(1000).mobius_table();
+        assert_eq!(mu.len(), 1001);
+        assert_eq!(mu[0], 0);
+        assert_eq!(mu[1], 1);
+
+        for n in 1..=1000_usize {
+            // trial division
+            let (mut m, mut num_factors, mut square_free) = (n, 0, true);
+            for p in 2..=n {
+                if m % p == 0 {
+                    m /= p;
+                    num_factors += 1;
+                    square_free &= m % p != 0;
+                    while m % p == 0 {
+                        m /= p
+                    }
+                }
+            }
+            let expected = if !square_free {
+                0
+            } else if num_factors % 2 == 0 {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(mu[n], expected, "n = {n}");
+
+            // Σ_{d | n} μ(d) = [n == 1]
+            let sum: i32 = (1..=n).filter(|d| n % d == 0).map(|d| mu[d] as i32).sum();
+            assert_eq!(sum, (n == 1) as i32);
+        }
+    }
+
+    #[test]
+    fn totient_table() {
+        let sieve = SieveOfEratosthenes::new(1000);
+        let phi = sieve.totient_table();
+        assert_eq!(phi.len(), 1001);
+        assert_eq!(phi[0], 0);
+        assert_eq!(phi[1], 1);
+
+        for p in sieve.into_primes() {
+            assert_eq!(phi[p as usize], p - 1);
+            // φ(p^k) = p^(k - 1) (p - 1)
+            let mut pk = p;
+            while pk * p <= 1000 {
+                assert_eq!(phi[(pk * p) as usize], pk * (p - 1));
+                pk *= p;
+            }
+        }
+
+        for n in 1..=1000 {
+            let expected = (1..=n).filter(|&k| gcd(n, k) == 1).count() as u32;
+            assert_eq!(phi[n as usize], expected, "n = {n}");
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use math_traits::isqrt;
+
+/// Returns the number of primes less than or equal to `n`, that is, π(*n*).
+///
+/// Uses the DP by Lucy_Hedgehog, which only tracks values at `n / i` for all `i`.
+///
+/// # Time complexity
+///
+/// *O*(*N*<sup>3/4</sup>)
+pub fn prime_counting_function(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+
+    let r = isqrt(n) as usize;
+
+    // small[v] = the number of survivors in [2, v] for `v <= r`
+    // large[i] = the number of survivors in [2, n / i] for `i <= r`
+    let mut small = Vec::from_iter((0..=r as u64).map(|v| v.saturating_sub(1)));
+    let mut large = Vec::from_iter((0..=r as u64).map(|i| (n / i.max(1)) - 1));
+    for p in 2..=r {
+        // `p` has been sieved out
+        if small[p] == small[p - 1] {
+            continue;
+        }
+
+        // remove numbers whose smallest prime factor is `p`
+        let num_smaller_primes = small[p - 1];
+        let p2 = (p * p) as u64;
+        for i in 1..=r.min((n / p2) as usize) {
+            let d = i * p;
+            let survivors = if d <= r {
+                large[d]
+            } else {
+                small[(n / d as u64) as usize]
+            };
+            large[i] -= survivors - num_smaller_primes;
+        }
+        for v in (p * p..=r).rev() {
+            small[v] -= small[v / p] - num_smaller_primes;
+        }
+    }
+
+    large[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SieveOfEratosthenes;
+
+    #[test]
+    fn compare_with_sieve() {
+        for n in 0..3000 {
+            let expected = SieveOfEratosthenes::new(n).into_primes().count() as u64;
+            assert_eq!(prime_counting_function(n as u64), expected, "n = {n}");
+        }
+        for n in [65_535, 65_536, 99_991, 123_456, 999_983, 1_000_000] {
+            let expected = SieveOfEratosthenes::new(n).into_primes().count() as u64;
+            assert_eq!(prime_counting_function(n as u64), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(prime_counting_function(10_000_000), 664_579);
+        assert_eq!(prime_counting_function(1_000_000_000), 50_847_534);
+    }
+}
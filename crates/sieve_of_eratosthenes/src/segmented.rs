@@ -0,0 +1,143 @@
+use math_traits::isqrt;
+
+use crate::SieveOfEratosthenes;
+
+/// Returns primes in `[lo, hi)` in ascending order.
+///
+/// Only odd numbers in the window are sieved, chunk by chunk, with primes up to sqrt(`hi`).
+/// Memory usage is *O*(sqrt(*hi*) + *hi* - *lo*) regardless of how large `lo` is.
+///
+/// # Time complexity
+///
+/// *O*((*hi* - *lo*) log log *hi* + sqrt(*hi*))
+pub fn primes_in_range(lo: u64, hi: u64) -> Vec<u64> {
+    let lo = lo.max(2);
+    if lo >= hi {
+        return Vec::new();
+    }
+
+    // odd primes `p` such that `p * p < hi`
+    let sqrt = isqrt(hi - 1);
+    let small_primes = Vec::from_iter(
+        SieveOfEratosthenes::new(sqrt as usize)
+            .into_primes()
+            .skip(1)
+            .map(u64::from),
+    );
+
+    let mut primes = Vec::new();
+    if lo == 2 {
+        primes.push(2)
+    }
+    sieve_odd(lo | 1, hi, &small_primes, &mut primes);
+
+    primes
+}
+
+/// `2 * i + start` for `i`-th bit in a chunk
+const CHUNK_BITS: u64 = SieveOfEratosthenes::CHUNK_SIZE as u64 * 64;
+
+/// Pushes odd `n` in `[start, hi)` which has no factor `p` in `small_primes` with `p * p <= n`.
+///
+/// `start` must be odd. Offsets from `start` are used instead of the multiples themselves,
+/// so that nothing overflows even if `hi` is close to `u64::MAX`.
+fn sieve_odd(mut start: u64, hi: u64, small_primes: &[u64], primes: &mut Vec<u64>) {
+    let mut is_not_prime = vec![0_u64; SieveOfEratosthenes::CHUNK_SIZE];
+    while start < hi {
+        let end = start.saturating_add(2 * CHUNK_BITS).min(hi);
+        // the number of odd numbers in `[start, end)`
+        let len = (end - start + 1) / 2;
+        is_not_prime.fill(0);
+
+        for &p in small_primes.iter().take_while(|&&p| p * p < end) {
+            // `start + d` is the smallest odd multiple of `p` not less than `max(start, p * p)`
+            let mut d = (p - start % p) % p;
+            if d % 2 == 1 {
+                d += p
+            }
+            d = d.max((p * p).saturating_sub(start));
+            for j in (d / 2..len).step_by(p as usize) {
+                is_not_prime[j as usize / 64] |= 1 << (j % 64)
+            }
+        }
+
+        for (i, &bits) in is_not_prime.iter().enumerate() {
+            let mut bits = !bits;
+            while bits != 0 {
+                let j = i as u64 * 64 + bits.trailing_zeros() as u64;
+                if j >= len {
+                    break;
+                }
+                primes.push(start + 2 * j);
+                bits &= bits - 1;
+            }
+        }
+
+        // `end` is odd if `end == u64::MAX`
+        start = end + (end % 2 == 0) as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_with_sieve() {
+        let n = 3_000_000;
+        let all = Vec::from_iter(SieveOfEratosthenes::new(n).into_primes().map(u64::from));
+        let in_range =
+            |lo: u64, hi: u64| Vec::from_iter(all.iter().copied().filter(|&p| lo <= p && p < hi));
+
+        for lo in 0..40 {
+            for hi in lo..60 {
+                assert_eq!(primes_in_range(lo, hi), in_range(lo, hi), "[{lo}, {hi})");
+            }
+        }
+        // windows larger than a chunk
+        for (lo, hi) in [
+            (0, 3_000_000),
+            (1, 1_000_000),
+            (123_457, 2_345_679),
+            (2_000_000, 2_999_999),
+        ] {
+            assert_eq!(primes_in_range(lo, hi), in_range(lo, hi), "[{lo}, {hi})");
+        }
+    }
+
+    #[test]
+    fn high_range() {
+        let lo = 1_000_000_000_000;
+        let is_prime = |n: u64| (2..).take_while(|d| d * d <= n).all(|d| n % d != 0);
+
+        let primes = primes_in_range(lo, lo + 1_000);
+        assert_eq!(
+            primes,
+            Vec::from_iter((lo..lo + 1_000).filter(|&n| is_prime(n)))
+        );
+        assert_eq!(primes[0], 1_000_000_000_039);
+    }
+
+    #[test]
+    fn near_u64_max() {
+        // sieving with all primes up to 2^32 takes too long, so only check the window arithmetic
+        let small_primes = [3, 5, 7, 11, 13, 65_521];
+        let no_small_factor = |n: u64| n % 2 == 1 && small_primes.iter().all(|&p| n % p != 0);
+
+        for lo in [
+            u64::MAX - 1_000,
+            u64::MAX - 2 * CHUNK_BITS,
+            u64::MAX - 3 * CHUNK_BITS - 7,
+        ] {
+            for hi in [u64::MAX - 1, u64::MAX] {
+                let mut sieved = Vec::new();
+                sieve_odd(lo | 1, hi, &small_primes, &mut sieved);
+                assert_eq!(
+                    sieved,
+                    Vec::from_iter((lo..hi).filter(|&n| no_small_factor(n))),
+                    "[{lo}, {hi})"
+                );
+            }
+        }
+    }
+}
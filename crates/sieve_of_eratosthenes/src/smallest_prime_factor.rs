@@ -0,0 +1,186 @@
+/// A table of the smallest prime factor of each integer up to `n`, which enables fast factorization.
+#[derive(Debug, Clone)]
+pub struct SmallestPrimeFactor {
+    /// `spf[0]` and `spf[1]` are `0`.
+    spf: Box<[u32]>,
+}
+
+impl SmallestPrimeFactor {
+    /// Creates the table for integers up to `n` (inclusive) by the linear sieve.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N*)
+    pub fn new(n: usize) -> Self {
+        assert!(n <= u32::MAX as usize);
+
+        let mut spf = vec![0; n + 1].into_boxed_slice();
+        let mut primes = Vec::new();
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+            // every composite number is marked exactly once by its smallest prime factor
+            for &p in &primes {
+                if p > spf[i] || i * p as usize > n {
+                    break;
+                }
+                spf[i * p as usize] = p;
+            }
+        }
+
+        Self { spf }
+    }
+
+    /// Returns the largest integer in the table.
+    pub fn max(&self) -> usize {
+        self.spf.len() - 1
+    }
+
+    /// Returns the smallest prime factor of `x`, or `None` if `x < 2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is greater than [SmallestPrimeFactor::max].
+    pub fn smallest_prime_factor(&self, x: usize) -> Option<usize> {
+        match self.spf[x] {
+            0 => None,
+            p => Some(p as usize),
+        }
+    }
+
+    /// Returns prime factors of `x` and their exponents in ascending order of primes.
+    /// The factorization of `1` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is `0` or greater than [SmallestPrimeFactor::max].
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *x*)
+    pub fn factorize(&self, mut x: usize) -> Vec<(usize, u32)> {
+        assert!(x != 0, "0 cannot be factorized");
+        assert!(x < self.spf.len(), "out of bounds");
+
+        let mut res: Vec<(usize, u32)> = Vec::new();
+        while x > 1 {
+            let p = self.spf[x] as usize;
+            match res.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => res.push((p, 1)),
+            }
+            x /= p;
+        }
+
+        res
+    }
+}
+
+/// Returns the number of divisors τ(*i*) for each `i <= n`, where τ(0) is defined as `0`.
+///
+/// # Time complexity
+///
+/// *O*(*N*)
+pub fn num_divisors_table(n: usize) -> Box<[u64]> {
+    multiplicative_table(n, |_, prev| prev + 1)
+}
+
+/// Returns the sum of divisors σ(*i*) for each `i <= n`, where σ(0) is defined as `0`.
+///
+/// # Time complexity
+///
+/// *O*(*N*)
+pub fn sum_divisors_table(n: usize) -> Box<[u64]> {
+    multiplicative_table(n, |p, prev| prev * p as u64 + 1)
+}
+
+/// Computes a multiplicative function `f` up to `n`, where `f(p^k) = next(p, f(p^(k - 1)))` and `f(1) = 1`.
+fn multiplicative_table<F>(n: usize, next: F) -> Box<[u64]>
+where
+    F: Fn(usize, u64) -> u64,
+{
+    let spf = SmallestPrimeFactor::new(n);
+    let mut f = vec![0; n + 1].into_boxed_slice();
+    if n >= 1 {
+        f[1] = 1
+    }
+    // the largest power of the smallest prime factor which divides `i`
+    let mut spf_power = vec![1; n + 1];
+    for i in 2..=n {
+        let p = spf.spf[i] as usize;
+        let m = i / p;
+        spf_power[i] = if m % p == 0 { spf_power[m] * p } else { p };
+
+        f[i] = if spf_power[i] == i {
+            next(p, f[m])
+        } else {
+            f[i / spf_power[i]] * f[spf_power[i]]
+        };
+    }
+
+    f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorize() {
+        let spf = SmallestPrimeFactor::new(100_000);
+        assert_eq!(spf.factorize(1), []);
+        assert_eq!(spf.factorize(2), [(2, 1)]);
+        assert_eq!(spf.factorize(97), [(97, 1)]);
+        assert_eq!(spf.factorize(1024), [(2, 10)]);
+        assert_eq!(spf.factorize(360), [(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(spf.factorize(99_999), [(3, 2), (41, 1), (271, 1)]);
+        assert_eq!(spf.factorize(100_000), [(2, 5), (5, 5)]);
+
+        for x in 1..=spf.max() {
+            let factors = spf.factorize(x);
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(factors.iter().all(|&(p, _)| spf.factorize(p) == [(p, 1)]));
+            let product: usize = factors.iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, x);
+        }
+    }
+
+    #[test]
+    fn smallest_prime_factor() {
+        let spf = SmallestPrimeFactor::new(50);
+        assert_eq!(spf.smallest_prime_factor(0), None);
+        assert_eq!(spf.smallest_prime_factor(1), None);
+        assert_eq!(spf.smallest_prime_factor(49), Some(7));
+        assert_eq!(spf.smallest_prime_factor(47), Some(47));
+    }
+
+    #[test]
+    fn divisor_tables() {
+        let n = 3000;
+        let num_divisors = num_divisors_table(n);
+        let sum_divisors = sum_divisors_table(n);
+        assert_eq!(num_divisors[..2], [0, 1]);
+        assert_eq!(sum_divisors[..2], [0, 1]);
+
+        for i in 1..=n {
+            let divisors = Vec::from_iter((1..=i).filter(|d| i % d == 0));
+            assert_eq!(num_divisors[i], divisors.len() as u64, "i = {i}");
+            assert_eq!(
+                sum_divisors[i],
+                divisors.iter().sum::<usize>() as u64,
+                "i = {i}"
+            );
+        }
+
+        assert_eq!(*num_divisors_table(0), [0]);
+        assert_eq!(*sum_divisors_table(1), [0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn factorize_zero() {
+        SmallestPrimeFactor::new(10).factorize(0);
+    }
+}
@@ -7,7 +7,7 @@ use sparse_table::{DisjointSparseTable, Semigroup};
 fn main() {
     input! { n: usize, q: usize, a: [u64; n], lr: [(usize, usize); q], }
 
-    let dst = DisjointSparseTable::from_iter(a.into_iter().map(|a| RangeSum(a)));
+    let dst = DisjointSparseTable::from_iter(a.into_iter().map(RangeSum));
     for (l, r) in lr {
         println!("{}", dst.range_query(l..r).unwrap().0)
     }
@@ -7,16 +7,16 @@ use sparse_table::{DisjointSparseTable, Semigroup};
 fn main() {
     input! { n: usize, q: usize, a: [u32; n], lr: [(usize, usize); q], }
 
-    let dst = DisjointSparseTable::from_iter(a.into_iter().map(|a| RMQ(a)));
+    let dst = DisjointSparseTable::from_iter(a.into_iter().map(Rmq));
     for (l, r) in lr {
         println!("{}", dst.range_query(l..r).unwrap().0)
     }
 }
 
 #[derive(Debug, Clone)]
-struct RMQ(u32);
+struct Rmq(u32);
 
-impl Semigroup for RMQ {
+impl Semigroup for Rmq {
     fn binary_operation(&self, rhs: &Self) -> Self {
         Self(self.0.min(rhs.0))
     }
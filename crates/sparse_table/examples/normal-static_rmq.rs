@@ -7,18 +7,18 @@ use sparse_table::{Idempotent, Semigroup, SparseTable};
 fn main() {
     input! { n: usize, q: usize, a: [u32; n], lr: [(usize, usize); q], }
 
-    let st = SparseTable::from_iter(a.into_iter().map(|a| RMQ(a)));
+    let st = SparseTable::from_iter(a.into_iter().map(Rmq));
     for (l, r) in lr {
         println!("{}", st.range_query(l..r).unwrap().0)
     }
 }
 
 #[derive(Debug)]
-struct RMQ(u32);
+struct Rmq(u32);
 
-impl Idempotent for RMQ {}
+impl Idempotent for Rmq {}
 
-impl Semigroup for RMQ {
+impl Semigroup for Rmq {
     fn binary_operation(&self, rhs: &Self) -> Self {
         Self(self.0.min(rhs.0))
     }
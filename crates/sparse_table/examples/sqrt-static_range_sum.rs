@@ -7,7 +7,7 @@ use sparse_table::{Semigroup, SqrtTable};
 fn main() {
     input! { n: usize, q: usize, a: [u64; n], lr: [(usize, usize); q], }
 
-    let sqrt = SqrtTable::from(Vec::from_iter(a.into_iter().map(|a| RangeSum(a))));
+    let sqrt = SqrtTable::from(Vec::from_iter(a.into_iter().map(RangeSum)));
     for (l, r) in lr {
         println!("{}", sqrt.range_query(l..r).unwrap().0)
     }
@@ -0,0 +1,160 @@
+use std::ops::RangeBounds;
+
+/// Sparse table which returns the position of the minimum, with ties broken toward the smaller index.
+///
+/// Wrap values in [`Reverse`](std::cmp::Reverse) for the position of the maximum.
+///
+/// # Example
+///
+/// ```
+/// use std::cmp::Reverse;
+///
+/// use sparse_table::ArgSparseTable;
+///
+/// let argmin = ArgSparseTable::from_iter([3, 1, 4, 1, 5]);
+/// assert_eq!(argmin.range_query(..), Some((&1, 1)));
+/// assert_eq!(argmin.range_query(2..), Some((&1, 3)));
+/// assert_eq!(argmin.range_query(2..2), None);
+///
+/// let argmax = ArgSparseTable::from_iter([3, 1, 4, 1, 5].map(Reverse));
+/// assert_eq!(argmax.range_query(..4), Some((&Reverse(4), 2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArgSparseTable<T: Ord> {
+    values: Box<[T]>,
+    /// `table[k][i]` is the position of the minimum over `[i, i + 2^k)`
+    table: Vec<Box<[usize]>>,
+}
+
+impl<T: Ord> ArgSparseTable<T> {
+    /// Returns the minimum over the given `range` and its position, or `None` if the range is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given `range` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn range_query<R>(&self, range: R) -> Option<(&T, usize)>
+    where
+        R: RangeBounds<usize>,
+    {
+        let l = match range.start_bound() {
+            std::ops::Bound::Included(l) => *l,
+            std::ops::Bound::Excluded(l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            std::ops::Bound::Included(r) => r + 1,
+            std::ops::Bound::Excluded(r) => *r,
+            std::ops::Bound::Unbounded => self.values.len(),
+        };
+        assert!(r <= self.values.len(), "range end {} out of bounds", r);
+
+        if l >= r {
+            return None;
+        }
+
+        let w = (r - l).ilog2() as usize;
+        let i = self.arg_min(self.table[w][l], self.table[w][r - (1 << w)]);
+        Some((&self.values[i], i))
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.values.into_vec()
+    }
+
+    /// Compares `(values[i], i)` with `(values[j], j)`.
+    #[inline]
+    fn arg_min(&self, i: usize, j: usize) -> usize {
+        if (&self.values[j], j) < (&self.values[i], i) {
+            j
+        } else {
+            i
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for ArgSparseTable<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values = Vec::from_iter(iter).into_boxed_slice();
+        let mut res = Self {
+            table: Vec::with_capacity(values.len().max(1).ilog2() as usize + 1),
+            values,
+        };
+
+        res.table.push(Box::from_iter(0..res.values.len()));
+        for k in 1..=res.values.len().max(1).ilog2() as usize {
+            let prev = &res.table[k - 1];
+            let level = Box::from_iter(
+                (0..=res.values.len() - (1 << k))
+                    .map(|i| res.arg_min(prev[i], prev[i + (1 << (k - 1))])),
+            );
+            res.table.push(level);
+        }
+
+        res
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for ArgSparseTable<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_iter(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut next = xorshift(0xa5a5);
+
+        for len in 0..=40 {
+            // small values to produce many ties
+            let values = Vec::from_iter((0..len).map(|_| next() % 5));
+            let argmin = ArgSparseTable::from(values.clone());
+            let argmax = ArgSparseTable::from_iter(values.iter().map(Reverse));
+            assert_eq!(argmin.len(), len);
+
+            for l in 0..=len {
+                assert_eq!(argmin.range_query(l..l), None);
+                for r in l + 1..=len {
+                    let min = *values[l..r].iter().min().unwrap();
+                    let max = *values[l..r].iter().max().unwrap();
+                    let first_min = (l..r).find(|&i| values[i] == min).unwrap();
+                    let first_max = (l..r).find(|&i| values[i] == max).unwrap();
+
+                    assert_eq!(argmin.range_query(l..r), Some((&min, first_min)));
+                    assert_eq!(argmax.range_query(l..r), Some((&Reverse(&max), first_max)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ties_toward_smaller_index() {
+        let table = ArgSparseTable::from_iter([2, 0, 0, 1, 0, 0, 0, 2]);
+        assert_eq!(table.range_query(..).map(|(_, i)| i), Some(1));
+        assert_eq!(table.range_query(2..).map(|(_, i)| i), Some(2));
+        assert_eq!(table.range_query(3..8).map(|(_, i)| i), Some(4));
+        assert_eq!(table.range_query(5..=6).map(|(_, i)| i), Some(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        ArgSparseTable::from_iter([1, 2, 3]).range_query(1..4);
+    }
+}
@@ -2,6 +2,16 @@ use std::ops::RangeBounds;
 
 use super::Semigroup;
 
+/// Sparse table over disjoint blocks, which answers range queries in *O*(1) for any semigroup.
+///
+/// Unlike [`SparseTable`](super::SparseTable), no element is counted twice,
+/// so [`Idempotent`](super::Idempotent) is not required.
+///
+/// # Performance note
+///
+/// | [from_iter](DisjointSparseTable::from_iter) | [range_query](DisjointSparseTable::range_query) |
+/// |---------------------------------------------|-------------------------------------------------|
+/// | *O*(*N* log *N*)                            | *O*(1)                                          |
 #[derive(Debug, Clone)]
 pub struct DisjointSparseTable<T: Semigroup + Clone> {
     table: Box<[T]>,
@@ -23,6 +33,8 @@ impl<T: Semigroup + Clone> DisjointSparseTable<T> {
             std::ops::Bound::Excluded(r) => *r,
             std::ops::Bound::Unbounded => self.len,
         };
+        // otherwise, `r - 1` would silently index into the next level
+        assert!(r <= self.len, "range end {} out of bounds", r);
 
         if l >= r {
             return None;
@@ -39,6 +51,44 @@ impl<T: Semigroup + Clone> DisjointSparseTable<T> {
             )
         }
     }
+
+    /// Same as [`range_query`](Self::range_query).
+    ///
+    /// This is the alternative to [`SparseTable::range_query`](super::SparseTable::range_query)
+    /// for semigroups which are not [`Idempotent`](super::Idempotent), such as sums.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sparse_table::{DisjointSparseTable, Semigroup};
+    ///
+    /// #[derive(Clone)]
+    /// struct Sum(i64);
+    ///
+    /// impl Semigroup for Sum {
+    ///     fn binary_operation(&self, rhs: &Self) -> Self {
+    ///         Self(self.0 + rhs.0)
+    ///     }
+    /// }
+    ///
+    /// let table = DisjointSparseTable::from_iter([3, 1, 4, 1, 5].map(Sum));
+    /// assert_eq!(table.sum_query(1..4).map(|sum| sum.0), Some(6));
+    /// assert!(table.sum_query(2..2).is_none());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given `range` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1)
+    pub fn sum_query<R>(&self, range: R) -> Option<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.range_query(range)
+    }
 }
 
 impl<T: Semigroup + Clone> DisjointSparseTable<T> {
@@ -56,6 +106,11 @@ impl<T: Semigroup + Clone> DisjointSparseTable<T> {
 }
 
 impl<T: Semigroup + Clone> FromIterator<T> for DisjointSparseTable<T> {
+    /// Builds the table level by level.
+    ///
+    /// At level *k*, the array is split into blocks of width 2^*k* (the last one may be shorter).
+    /// Even-indexed blocks store suffix products and odd-indexed blocks store prefix products,
+    /// so that any range whose endpoints first differ at bit *k* is the product of two entries.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let iter = iter.into_iter();
         let (min, max) = iter.size_hint();
@@ -92,7 +147,8 @@ impl<T: Semigroup + Clone> FromIterator<T> for DisjointSparseTable<T> {
                 } else {
                     stack.push(table[n.min(i * b + b) - 1].clone()); // n > 0 and b > 0
                     for v in table[i * b..n.min(i * b + b) - 1].iter().rev() {
-                        stack.push(stack.last().unwrap().binary_operation(v));
+                        // suffix product: `v` comes first
+                        stack.push(v.binary_operation(stack.last().unwrap()));
                     }
                     while let Some(v) = stack.pop() {
                         table.push(v);
@@ -113,3 +169,96 @@ impl<T: Semigroup + Clone> From<Vec<T>> for DisjointSparseTable<T> {
         Self::from_iter(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Concat(String);
+
+    impl Semigroup for Concat {
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0.clone() + &rhs.0)
+        }
+    }
+
+    fn check(values: &[Concat], table: &DisjointSparseTable<Concat>) {
+        let n = values.len();
+        assert_eq!(table.len(), n);
+        for l in 0..=n {
+            for r in l..=n {
+                let expected = values[l..r].iter().fold(None, |acc: Option<Concat>, v| {
+                    Some(acc.map_or(v.clone(), |acc| acc.binary_operation(v)))
+                });
+                assert_eq!(table.range_query(l..r), expected, "n = {n}, {l}..{r}");
+            }
+        }
+    }
+
+    #[test]
+    fn concat_all_ranges() {
+        for n in [0, 1, 2, 3, 4, 5, 7, 8, 9, 13, 16, 17, 100] {
+            let values =
+                Vec::from_iter((0..n).map(|i| Concat(((b'a' + i as u8 % 26) as char).into())));
+
+            // exact size hint
+            check(&values, &DisjointSparseTable::from(values.clone()));
+            // inexact size hint
+            check(
+                &values,
+                &DisjointSparseTable::from_iter(values.iter().filter(|_| true).cloned()),
+            );
+        }
+    }
+
+    #[test]
+    fn idempotent_and_non_idempotent() {
+        use crate::{Idempotent, SparseTable};
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Sum(i64);
+
+        impl Semigroup for Sum {
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Max(i64);
+
+        impl Semigroup for Max {
+            fn binary_operation(&self, rhs: &Self) -> Self {
+                Self(self.0.max(rhs.0))
+            }
+        }
+
+        impl Idempotent for Max {}
+
+        let values = Vec::from_iter((0..50).map(|i| (i * 37 % 23) as i64 - 11));
+        let sum = DisjointSparseTable::from_iter(values.iter().copied().map(Sum));
+        let max = SparseTable::from_iter(values.iter().copied().map(Max));
+        for l in 0..values.len() {
+            for r in l + 1..=values.len() {
+                assert_eq!(sum.sum_query(l..r), Some(Sum(values[l..r].iter().sum())));
+                assert_eq!(
+                    max.range_query(l..r),
+                    values[l..r].iter().copied().max().map(Max)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn into_vec() {
+        let values = Vec::from_iter((0..13).map(|i| Concat(i.to_string())));
+        let table = DisjointSparseTable::from_iter(values.clone());
+
+        assert_eq!(
+            table.range_query(..),
+            Some(Concat("0123456789101112".into()))
+        );
+        assert_eq!(table.into_vec(), values);
+    }
+}
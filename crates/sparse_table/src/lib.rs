@@ -1,8 +1,10 @@
+mod arg;
 mod disjoint;
 mod normal;
 mod sqrt;
 mod traits;
 
+pub use arg::ArgSparseTable;
 pub use disjoint::DisjointSparseTable;
 pub use normal::SparseTable;
 pub use sqrt::SqrtTable;
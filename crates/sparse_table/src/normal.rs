@@ -2,6 +2,32 @@ use std::{fmt::Debug, ops::RangeBounds};
 
 use super::{Idempotent, Semigroup};
 
+/// Sparse table, which answers range queries in *O*(1) by combining two overlapping blocks.
+///
+/// Since an element may be counted twice, the operation must be [`Idempotent`].
+/// For non-idempotent semigroups such as sums, use [`DisjointSparseTable::sum_query`](super::DisjointSparseTable::sum_query) instead.
+///
+/// ```compile_fail
+/// use sparse_table::{Semigroup, SparseTable};
+///
+/// #[derive(Clone)]
+/// struct Sum(i64);
+///
+/// impl Semigroup for Sum {
+///     fn binary_operation(&self, rhs: &Self) -> Self {
+///         Self(self.0 + rhs.0)
+///     }
+/// }
+///
+/// // `Sum` is not `Idempotent`
+/// let table = SparseTable::from_iter([Sum(1), Sum(2), Sum(3)]);
+/// ```
+///
+/// # Performance note
+///
+/// | [from_iter](SparseTable::from_iter) | [range_query](SparseTable::range_query) |
+/// |-------------------------------------|-----------------------------------------|
+/// | *O*(*N* log *N*)                    | *O*(1)                                  |
 #[derive(Clone)]
 pub struct SparseTable<T: Semigroup + Idempotent> {
     table: Box<[T]>,
@@ -50,6 +76,10 @@ impl<T: Semigroup + Idempotent + Debug> Debug for SparseTable<T> {
 }
 
 impl<T: Semigroup + Idempotent> FromIterator<T> for SparseTable<T> {
+    /// Builds the doubling table.
+    ///
+    /// If the size hint of `iter` is exact, elements are written directly into the table.
+    /// Otherwise, they are collected first because the height of the table depends on the length.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let iter = iter.into_iter();
         let (min, max) = iter.size_hint();
@@ -74,8 +104,8 @@ impl<T: Semigroup + Idempotent> FromIterator<T> for SparseTable<T> {
             height += 1
         }
         for i in 1..height {
-            for j in (partition[i - 1]..partition[i]).skip(1 << i - 1) {
-                table.push(table[j - (1 << i - 1)].binary_operation(&table[j]));
+            for j in (partition[i - 1]..partition[i]).skip(1 << (i - 1)) {
+                table.push(table[j - (1 << (i - 1))].binary_operation(&table[j]));
             }
             partition.push(table.len());
         }
@@ -92,3 +122,58 @@ impl<T: Semigroup + Idempotent> From<Vec<T>> for SparseTable<T> {
         Self::from_iter(value)
     }
 }
+
+impl<T: Semigroup + Idempotent + Clone> From<&[T]> for SparseTable<T> {
+    fn from(value: &[T]) -> Self {
+        Self::from_iter(value.iter().cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Min(i32);
+
+    impl Semigroup for Min {
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0.min(rhs.0))
+        }
+    }
+
+    impl Idempotent for Min {}
+
+    fn assert_same_queries(lhs: &SparseTable<Min>, rhs: &SparseTable<Min>, len: usize) {
+        for l in 0..=len {
+            for r in l..=len {
+                assert_eq!(lhs.range_query(l..r), rhs.range_query(l..r), "{l}..{r}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_iter_matches_slice() {
+        for len in 0..=33 {
+            let values = Vec::from_iter((0..len).map(|i| Min((i * 37 % 11) as i32 - 5)));
+
+            let from_slice = SparseTable::from(values.as_slice());
+            // exact size hint
+            let from_exact =
+                SparseTable::from_iter((0..len).map(|i| Min((i * 37 % 11) as i32 - 5)));
+            // inexact size hint
+            let from_inexact = SparseTable::from_iter(values.iter().filter(|_| true).cloned());
+
+            assert_same_queries(&from_slice, &from_exact, len);
+            assert_same_queries(&from_slice, &from_inexact, len);
+            for l in 0..len {
+                for r in l + 1..=len {
+                    assert_eq!(
+                        from_slice.range_query(l..r),
+                        values[l..r].iter().min_by_key(|v| v.0).cloned()
+                    );
+                }
+            }
+        }
+    }
+}
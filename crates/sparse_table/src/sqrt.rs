@@ -1,16 +1,39 @@
 use std::ops::RangeBounds;
 
-use super::{DisjointSparseTable, Semigroup};
+use math_traits::isqrt;
 
+use super::Semigroup;
+
+/// Sqrt decomposition of a static array.
+///
+/// Unlike [SparseTable](super::SparseTable), any semigroup is supported.
+///
+/// # Performance note
+///
+/// | [from_iter](SqrtTable::from_iter) | [range_query](SqrtTable::range_query) |
+/// |-----------------------------------|---------------------------------------|
+/// | *O*(*N*)                          | *O*(√*N*)                             |
 #[derive(Debug, Clone)]
 pub struct SqrtTable<T: Semigroup + Clone> {
-    large_table: DisjointSparseTable<T>,
-    small_table: Box<[DisjointSparseTable<T>]>,
+    data: Box<[T]>,
+    /// `block[i]` is the product of `data[i * block_size..(i + 1) * block_size]`
+    block: Box<[T]>,
     block_size: usize,
-    len: usize,
 }
 
 impl<T: Semigroup + Clone> SqrtTable<T> {
+    /// Returns the product of the given range, or `None` if the range is empty.
+    ///
+    /// The partial blocks at both ends are scanned and the whole blocks between them are combined
+    /// by their precomputed products.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(√*N*)
     pub fn range_query<R>(&self, range: R) -> Option<T>
     where
         R: RangeBounds<usize>,
@@ -23,62 +46,122 @@ impl<T: Semigroup + Clone> SqrtTable<T> {
         let r = match range.end_bound() {
             std::ops::Bound::Included(r) => r + 1,
             std::ops::Bound::Excluded(r) => *r,
-            std::ops::Bound::Unbounded => self.len,
+            std::ops::Bound::Unbounded => self.data.len(),
         };
+        assert!(r <= self.data.len(), "range end {} out of bounds", r);
         if l >= r {
             return None;
         }
 
-        let (il, ir) = (l / self.block_size, r / self.block_size);
-
+        let (il, ir) = (l / self.block_size, (r - 1) / self.block_size);
         if il == ir {
-            return self.small_table[il]
-                .range_query(l - il * self.block_size..r - ir * self.block_size);
+            return Some(Self::fold(&self.data[l..r]));
+        }
+
+        let mut acc = Self::fold(&self.data[l..(il + 1) * self.block_size]);
+        for v in &self.block[il + 1..ir] {
+            acc = acc.binary_operation(v)
+        }
+        for v in &self.data[ir * self.block_size..r] {
+            acc = acc.binary_operation(v)
         }
 
-        let left = self.small_table[il].range_query(l - il * self.block_size..);
-        let center = self.large_table.range_query(il + 1..ir);
-        let right = self.small_table[ir].range_query(..r - ir * self.block_size);
-        [center, right]
-            .into_iter()
-            .fold(left, |acc, v| match (acc, v) {
-                (Some(acc), Some(v)) => Some(acc.binary_operation(&v)),
-                (None, Some(v)) => Some(v),
-                (Some(acc), None) => Some(acc),
-                (None, None) => None,
-            })
+        Some(acc)
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data.into_vec()
+    }
+
+    /// `slice` should not be empty.
+    fn fold(slice: &[T]) -> T {
+        slice[1..]
+            .iter()
+            .fold(slice[0].clone(), |acc, v| acc.binary_operation(v))
     }
 }
 
-impl<T: Semigroup + Clone> From<Vec<T>> for SqrtTable<T> {
-    fn from(mut value: Vec<T>) -> Self {
-        let len = value.len();
-        // TODO: use ilog2()
-        let block_size = len.next_power_of_two().ilog2() as usize;
-        let large_table = DisjointSparseTable::from_iter(value.chunks(block_size).map(|b| {
-            if b.len() == 1 {
-                b[0].clone()
-            } else {
-                b.iter()
-                    .skip(2)
-                    .fold(b[0].binary_operation(&b[1]), |acc, v| {
-                        acc.binary_operation(v)
-                    })
-            }
-        }));
-        let mut small_table = Vec::from_iter(
-            (0..len)
-                .step_by(block_size)
-                .rev()
-                .map(|i| DisjointSparseTable::from(value.split_off(i))),
-        );
-        small_table.reverse();
+impl<T: Semigroup + Clone> FromIterator<T> for SqrtTable<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data = Vec::from_iter(iter).into_boxed_slice();
+        let block_size = (isqrt(data.len() as u64) as usize).max(1);
+
+        let block = Vec::from_iter(data.chunks(block_size).map(Self::fold)).into_boxed_slice();
 
         Self {
-            large_table,
-            small_table: small_table.into_boxed_slice(),
+            data,
+            block,
             block_size,
-            len,
         }
     }
 }
+
+impl<T: Semigroup + Clone> From<Vec<T>> for SqrtTable<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_iter(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Concat(String);
+
+    impl Semigroup for Concat {
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0.clone() + &rhs.0)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Sum(u64);
+
+    impl Semigroup for Sum {
+        fn binary_operation(&self, rhs: &Self) -> Self {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    #[test]
+    fn concat_all_ranges() {
+        for n in [0, 1, 2, 3, 4, 5, 8, 9, 10, 15, 16, 17, 50] {
+            let values =
+                Vec::from_iter((0..n).map(|i| Concat(((b'a' + i as u8 % 26) as char).into())));
+            let table = SqrtTable::from(values.clone());
+            assert_eq!(table.len(), n);
+
+            for l in 0..=n {
+                for r in l..=n {
+                    let expected = values[l..r].iter().fold(None, |acc: Option<Concat>, v| {
+                        Some(acc.map_or(v.clone(), |acc| acc.binary_operation(v)))
+                    });
+                    assert_eq!(table.range_query(l..r), expected, "{l}..{r}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sum_with_bounds() {
+        let table = SqrtTable::from_iter((1..=100).map(Sum));
+
+        assert_eq!(table.range_query(..), Some(Sum(5050)));
+        assert_eq!(table.range_query(..=9), Some(Sum(55)));
+        assert_eq!(table.range_query(90..), Some(Sum(955)));
+        assert_eq!(table.range_query(3..3), None);
+        assert_eq!(table.into_vec().len(), 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        SqrtTable::from_iter((0..10).map(Sum)).range_query(5..11);
+    }
+}
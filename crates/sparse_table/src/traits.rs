@@ -2,4 +2,7 @@ pub trait Semigroup {
     fn binary_operation(&self, rhs: &Self) -> Self;
 }
 
+/// Marker for semigroups where `a.binary_operation(&a) == a`.
+///
+/// Required by [`SparseTable`](crate::SparseTable), whose queries may count an element twice.
 pub trait Idempotent: Semigroup {}
@@ -0,0 +1,45 @@
+//! Random inputs shared by the tests of the workspace.
+//!
+//! Only for `[dev-dependencies]`.
+
+/// Returns a xorshift64 generator, which yields the same sequence for the same `seed`.
+///
+/// # Example
+///
+/// ```
+/// use test_utils::xorshift;
+///
+/// let mut next = xorshift(0x5eed);
+/// let mut other = xorshift(0x5eed);
+/// assert!((0..100).all(|_| next() == other()));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `seed` is zero, which would yield only zeros.
+pub fn xorshift(mut seed: u64) -> impl FnMut() -> u64 {
+    assert_ne!(seed, 0, "the seed must be non-zero");
+
+    move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    }
+}
+
+/// Returns `n - 1` edges `(parent, child)` of a random tree on `0..n`, where `parent < child`.
+///
+/// # Example
+///
+/// ```
+/// use test_utils::random_tree;
+///
+/// let edges = random_tree(10, 1);
+/// assert_eq!(edges.len(), 9);
+/// assert!(edges.iter().all(|&(parent, child)| parent < child));
+/// ```
+pub fn random_tree(n: usize, seed: u64) -> Vec<(usize, usize)> {
+    let mut next = xorshift(seed);
+    Vec::from_iter((1..n).map(|i| (next() as usize % i, i)))
+}
@@ -17,10 +17,235 @@ impl<N, E> CSR<N, E> {
         self.node_list.len() - 1
     }
 
+    /// Collects nodes and directed edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use csr::CSR;
+    ///
+    /// let graph = CSR::from_edges([(); 3], [(0, 1, 5), (1, 2, 7)]).build();
+    /// assert_eq!(Vec::from_iter(graph.neighbors(1)), [(2, &7)]);
+    /// assert_eq!(graph.neighbors(2).count(), 0);
+    /// ```
+    pub fn from_edges<I, J>(nodes: I, edges: J) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        J: IntoIterator<Item = (usize, usize, E)>,
+    {
+        Self {
+            node_list: Vec::from_iter(nodes),
+            edge_list: Vec::from_iter(edges),
+        }
+    }
+
     /// Appends a directed edge.
     pub fn push_edge(&mut self, src: usize, tar: usize, weight: E) {
         self.edge_list.push((src, tar, weight))
     }
 
-    
+    /// Sorts edges by their source and compresses them into [`CompiledCSR`].
+    ///
+    /// Edges are directed, so each of them appears exactly once in the neighbors of its source.
+    /// For undirected graphs, push both `(u, v)` and `(v, u)`, e.g. by [`push_undirected`](Self::push_undirected), before building.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some edge refers to a node which has not been pushed.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N* + *M* log *M*)
+    pub fn build(self) -> CompiledCSR<N, E> {
+        let Self {
+            node_list,
+            mut edge_list,
+        } = self;
+
+        let mut offset = vec![0; node_list.len() + 1];
+        for &(src, tar, _) in &edge_list {
+            assert!(src < node_list.len() && tar < node_list.len());
+            offset[src + 1] += 1;
+        }
+        for i in 1..offset.len() {
+            offset[i] += offset[i - 1];
+        }
+
+        // stable sort keeps the insertion order among edges with the same source
+        edge_list.sort_by_key(|&(src, _, _)| src);
+
+        CompiledCSR {
+            node_list,
+            offset,
+            edge_list: Vec::from_iter(edge_list.into_iter().map(|(_, tar, weight)| (tar, weight))),
+        }
+    }
+}
+
+impl<N, E: Clone> CSR<N, E> {
+    /// Collects nodes and undirected edges, each of which is expanded by [`push_undirected`](Self::push_undirected).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use csr::CSR;
+    ///
+    /// let graph = CSR::from_edges_undirected([(); 3], [(0, 1, 5), (1, 2, 7)]).build();
+    /// assert_eq!(Vec::from_iter(graph.neighbors(1)), [(0, &5), (2, &7)]);
+    /// assert_eq!(Vec::from_iter(graph.neighbors(2)), [(1, &7)]);
+    /// ```
+    pub fn from_edges_undirected<I, J>(nodes: I, edges: J) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        J: IntoIterator<Item = (usize, usize, E)>,
+    {
+        let edges = edges.into_iter();
+        let mut csr = Self {
+            node_list: Vec::from_iter(nodes),
+            edge_list: Vec::with_capacity(edges.size_hint().0 * 2),
+        };
+        for (u, v, weight) in edges {
+            csr.push_undirected(u, v, weight)
+        }
+
+        csr
+    }
+
+    /// Appends an undirected edge, that is, both `(u, v)` and `(v, u)`.
+    ///
+    /// A self-loop is also pushed twice, so it appears twice in the neighbors of `u`.
+    pub fn push_undirected(&mut self, u: usize, v: usize, weight: E) {
+        self.edge_list.push((u, v, weight.clone()));
+        self.edge_list.push((v, u, weight))
+    }
+}
+
+/// Compressed sparse row built by [`CSR::build`].
+///
+/// Outgoing edges of `u` are stored contiguously in `edge_list[offset[u]..offset[u + 1]]`.
+#[derive(Debug, Clone)]
+pub struct CompiledCSR<N, E> {
+    node_list: Vec<N>,
+    offset: Vec<usize>,
+    edge_list: Vec<(usize, E)>,
+}
+
+impl<N, E> CompiledCSR<N, E> {
+    pub fn num_nodes(&self) -> usize {
+        self.node_list.len()
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.edge_list.len()
+    }
+
+    pub fn node_weight(&self, u: usize) -> &N {
+        &self.node_list[u]
+    }
+
+    /// Returns the offset array, whose length is `num_nodes() + 1`.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offset
+    }
+
+    /// Returns the number of outgoing edges of `u`.
+    pub fn out_degree(&self, u: usize) -> usize {
+        self.offset[u + 1] - self.offset[u]
+    }
+
+    /// Returns targets of outgoing edges of `u` with their weights, in insertion order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` is out of bounds.
+    pub fn neighbors(&self, u: usize) -> impl Iterator<Item = (usize, &E)> {
+        self.edge_list[self.offset[u]..self.offset[u + 1]]
+            .iter()
+            .map(|(tar, weight)| (*tar, weight))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_graph() -> CompiledCSR<char, u32> {
+        let mut csr = CSR::with_capacity(4, 5);
+        let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|w| csr.push_node(w));
+        csr.push_edge(c, a, 10);
+        csr.push_edge(a, b, 1);
+        csr.push_edge(a, c, 2);
+        csr.push_edge(c, d, 30);
+        csr.push_edge(b, c, 20);
+        csr.build()
+    }
+
+    #[test]
+    fn neighbors() {
+        let csr = small_graph();
+        assert_eq!(csr.num_nodes(), 4);
+        assert_eq!(csr.num_edges(), 5);
+
+        let neighbors = |u| Vec::from_iter(csr.neighbors(u).map(|(v, &w)| (v, w)));
+        assert_eq!(neighbors(0), [(1, 1), (2, 2)]);
+        assert_eq!(neighbors(1), [(2, 20)]);
+        assert_eq!(neighbors(2), [(0, 10), (3, 30)]);
+        assert_eq!(neighbors(3), []);
+        assert_eq!(*csr.node_weight(2), 'c');
+    }
+
+    #[test]
+    fn offsets() {
+        let csr = small_graph();
+        assert_eq!(csr.offsets(), [0, 2, 3, 5, 5]);
+        assert_eq!(
+            Vec::from_iter((0..4).map(|u| csr.out_degree(u))),
+            [2, 1, 2, 0]
+        );
+    }
+
+    #[test]
+    fn undirected_is_symmetric() {
+        let edges = [(0, 1, 3), (1, 2, 4), (2, 0, 5), (3, 1, 6), (4, 4, 7)];
+        let mut csr = CSR::with_capacity(5, edges.len() * 2);
+        (0..5).for_each(|_| {
+            csr.push_node(());
+        });
+        for &(u, v, w) in &edges {
+            csr.push_undirected(u, v, w);
+        }
+        let graph = csr.build();
+        assert_eq!(graph.num_edges(), edges.len() * 2);
+
+        let adjacency = |u| {
+            let mut adjacency = Vec::from_iter(graph.neighbors(u).map(|(v, &w)| (v, w)));
+            adjacency.sort_unstable();
+            adjacency
+        };
+        for u in 0..5 {
+            for (v, w) in adjacency(u) {
+                assert_eq!(
+                    adjacency(u).iter().filter(|&&e| e == (v, w)).count(),
+                    adjacency(v).iter().filter(|&&e| e == (u, w)).count(),
+                );
+            }
+        }
+        assert_eq!(adjacency(1), [(0, 3), (2, 4), (3, 6)]);
+        assert_eq!(adjacency(4), [(4, 7), (4, 7)]);
+
+        let other = CSR::from_edges_undirected([(); 5], edges).build();
+        assert_eq!(other.offsets(), graph.offsets());
+        for u in 0..5 {
+            assert!(other.neighbors(u).eq(graph.neighbors(u)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_node() {
+        let mut csr = CSR::with_capacity(1, 1);
+        let u = csr.push_node(());
+        csr.push_edge(u, u + 1, ());
+        csr.build();
+    }
 }
@@ -0,0 +1,129 @@
+/// Returns both endpoints of the longest path in the tree and its length (the number of edges).
+///
+/// Nodes are numbered from `0` to `edges.len()`. A tree with a single node has diameter `0`.
+///
+/// # Panics
+///
+/// Panics if given edges does NOT represent a tree.
+///
+/// # Time complexity
+///
+/// *O*(*N*)
+pub fn diameter(edges: &[(usize, usize)]) -> (usize, usize, usize) {
+    let edges = Vec::from_iter(edges.iter().map(|&(u, v)| (u, v, 1)));
+    let (u, v, len) = diameter_weighted(&edges);
+
+    (u, v, len as usize)
+}
+
+/// Returns both endpoints of the longest path in the weighted tree and its weight.
+///
+/// Nodes are numbered from `0` to `edges.len()`. A tree with a single node has diameter `0`.
+///
+/// # Panics
+///
+/// Panics if given edges does NOT represent a tree.
+///
+/// # Time complexity
+///
+/// *O*(*N*)
+pub fn diameter_weighted(edges: &[(usize, usize, u64)]) -> (usize, usize, u64) {
+    let n = edges.len() + 1;
+    let mut adj = vec![Vec::new(); n];
+    for &(u, v, w) in edges {
+        adj[u].push((v, w));
+        adj[v].push((u, w));
+    }
+
+    // the farthest node from any node is an endpoint of some longest path
+    let (u, _) = farthest(&adj, 0);
+    let (v, len) = farthest(&adj, u);
+
+    (u, v, len)
+}
+
+/// Returns the farthest node from `src` and the distance to it.
+fn farthest(adj: &[Vec<(usize, u64)>], src: usize) -> (usize, u64) {
+    const NULL: u64 = u64::MAX;
+    let mut dist = vec![NULL; adj.len()];
+    dist[src] = 0;
+
+    let mut stack = Vec::with_capacity(adj.len());
+    stack.push(src);
+    let mut num_visited = 0;
+    let mut res = (src, 0);
+    while let Some(i) = stack.pop() {
+        num_visited += 1;
+        if dist[i] > res.1 {
+            res = (i, dist[i])
+        }
+
+        for &(j, w) in &adj[i] {
+            if dist[j] == NULL {
+                dist[j] = dist[i] + w;
+                stack.push(j)
+            }
+        }
+    }
+    assert_eq!(num_visited, adj.len(), "invalid input");
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_node() {
+        assert_eq!(diameter(&[]), (0, 0, 0));
+        assert_eq!(diameter_weighted(&[]), (0, 0, 0));
+    }
+
+    #[test]
+    fn path_graph() {
+        // 3 - 1 - 4 - 0 - 2
+        let edges = [(3, 1), (1, 4), (4, 0), (0, 2)];
+        let (u, v, len) = diameter(&edges);
+        assert_eq!(len, 4);
+        assert_eq!([u.min(v), u.max(v)], [2, 3]);
+    }
+
+    #[test]
+    fn star() {
+        let edges = Vec::from_iter((1..6).map(|i| (0, i)));
+        let (u, v, len) = diameter(&edges);
+        assert_eq!(len, 2);
+        assert!(u != 0 && v != 0 && u != v);
+
+        // the heaviest two spokes form the diameter
+        let edges = [(0, 1, 5), (0, 2, 1), (3, 0, 7), (0, 4, 2)];
+        let (u, v, len) = diameter_weighted(&edges);
+        assert_eq!(len, 12);
+        assert_eq!([u.min(v), u.max(v)], [1, 3]);
+    }
+
+    #[test]
+    fn weighted() {
+        //     0
+        //  1 / \ 10
+        //   1   2
+        //  100  | 1
+        //   3   4 - 5 (1000)
+        let edges = [(0, 1, 1), (0, 2, 10), (1, 3, 100), (2, 4, 1), (4, 5, 1000)];
+        let (u, v, len) = diameter_weighted(&edges);
+        assert_eq!(len, 1112);
+        assert_eq!([u.min(v), u.max(v)], [3, 5]);
+
+        // weights are ignored
+        let (_, _, len) = diameter(&Vec::from_iter(edges.iter().map(|&(u, v, _)| (u, v))));
+        assert_eq!(len, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_a_tree() {
+        // cycle and isolated node
+        diameter(&[(0, 1), (1, 2), (2, 0)]);
+    }
+}
@@ -0,0 +1,185 @@
+use csr::CompiledCSR;
+
+/// Answers queries on every subtree offline by small-to-large merging (a.k.a. Sack).
+///
+/// `query(v)` is called exactly when the nodes added but not removed are exactly the subtree rooted at `v`.
+/// Each node is passed to `add` and `remove` *O*(log *N*) times.
+///
+/// Since `add`, `remove` and `query` usually share a state, wrap it in [`RefCell`](std::cell::RefCell) or similar.
+///
+/// # Panics
+///
+/// Panics if `tree` is not a tree. Each edge should be stored in both directions.
+///
+/// # Time complexity
+///
+/// *O*(*N* log *N*) calls of `add` and `remove`, and *N* calls of `query`.
+pub fn dsu_on_tree<N, E, A, R, Q>(
+    tree: &CompiledCSR<N, E>,
+    root: usize,
+    mut add: A,
+    mut remove: R,
+    mut query: Q,
+) where
+    A: FnMut(usize),
+    R: FnMut(usize),
+    Q: FnMut(usize),
+{
+    const NULL: usize = !0;
+    let n = tree.num_nodes();
+
+    // preorder, so that each subtree occupies a consecutive range
+    let mut parent = vec![NULL; n];
+    let mut preorder = Vec::with_capacity(n);
+    let mut stack = vec![root];
+    parent[root] = root;
+    while let Some(i) = stack.pop() {
+        preorder.push(i);
+        for (j, _) in tree.neighbors(i) {
+            if parent[j] == NULL {
+                parent[j] = i;
+                stack.push(j)
+            }
+        }
+    }
+    assert_eq!(preorder.len(), n, "invalid input");
+    assert_eq!(tree.num_edges(), 2 * (n - 1), "invalid input");
+
+    let mut size = vec![1; n];
+    let mut heavy = vec![NULL; n];
+    for &i in preorder.iter().skip(1).rev() {
+        let p = parent[i];
+        size[p] += size[i];
+        if heavy[p] == NULL || size[heavy[p]] < size[i] {
+            heavy[p] = i
+        }
+    }
+    let mut start = vec![0; n];
+    for (k, &i) in preorder.iter().enumerate() {
+        start[i] = k
+    }
+    let subtree = |i: usize| &preorder[start[i]..start[i] + size[i]];
+    let (parent, heavy) = (&parent, &heavy);
+    let light_children = |i: usize| {
+        tree.neighbors(i)
+            .map(|(j, _)| j)
+            .filter(move |&j| j != parent[i] && j != heavy[i])
+    };
+
+    enum Frame {
+        /// (node, keep)
+        Enter(usize, bool),
+        Exit(usize, bool),
+    }
+    let mut stack = vec![Frame::Enter(root, true)];
+    while let Some(frame) = stack.pop() {
+        match frame {
+            Frame::Enter(i, keep) => {
+                // light children first, then the heavy child, whose nodes are kept
+                stack.push(Frame::Exit(i, keep));
+                if heavy[i] != NULL {
+                    stack.push(Frame::Enter(heavy[i], true));
+                }
+                stack.extend(light_children(i).map(|j| Frame::Enter(j, false)));
+            }
+            Frame::Exit(i, keep) => {
+                for j in light_children(i) {
+                    subtree(j).iter().for_each(|&k| add(k));
+                }
+                add(i);
+
+                query(i);
+
+                if !keep {
+                    subtree(i).iter().for_each(|&k| remove(k));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use csr::CSR;
+    use test_utils::random_tree;
+
+    use super::*;
+
+    #[test]
+    fn distinct_colors() {
+        for n in 1..60 {
+            let edges = random_tree(n, n as u64 * 31);
+            let color = Vec::from_iter((0..n).map(|i| (i * i + 3 * i) % 7));
+            let root = n / 2;
+
+            let mut csr = CSR::with_capacity(n, 2 * (n - 1));
+            (0..n).for_each(|_| {
+                csr.push_node(());
+            });
+            for &(u, v) in &edges {
+                csr.push_edge(u, v, ());
+                csr.push_edge(v, u, ());
+            }
+            let tree = csr.build();
+
+            // (count of each color, number of distinct colors)
+            let state = RefCell::new((vec![0; 7], 0));
+            let mut res = vec![None; n];
+            dsu_on_tree(
+                &tree,
+                root,
+                |i| {
+                    let (count, distinct) = &mut *state.borrow_mut();
+                    count[color[i]] += 1;
+                    *distinct += (count[color[i]] == 1) as usize;
+                },
+                |i| {
+                    let (count, distinct) = &mut *state.borrow_mut();
+                    count[color[i]] -= 1;
+                    *distinct -= (count[color[i]] == 0) as usize;
+                },
+                |i| {
+                    assert!(res[i].is_none());
+                    res[i] = Some(state.borrow().1)
+                },
+            );
+
+            // brute force
+            let mut parent = vec![usize::MAX; n];
+            let mut order = vec![root];
+            parent[root] = root;
+            let mut k = 0;
+            while k < order.len() {
+                let i = order[k];
+                for &(u, v) in &edges {
+                    for (a, b) in [(u, v), (v, u)] {
+                        if a == i && parent[b] == usize::MAX {
+                            parent[b] = i;
+                            order.push(b);
+                        }
+                    }
+                }
+                k += 1;
+            }
+            let mut colors = vec![Vec::new(); n];
+            for (j, &c) in color.iter().enumerate() {
+                // `j` belongs to the subtrees of its ancestors
+                let mut k = j;
+                loop {
+                    colors[k].push(c);
+                    if k == root {
+                        break;
+                    }
+                    k = parent[k]
+                }
+            }
+            for (i, mut colors) in colors.into_iter().enumerate() {
+                colors.sort_unstable();
+                colors.dedup();
+                assert_eq!(res[i], Some(colors.len()), "n = {n}, i = {i}");
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#[allow(dead_code)]
 pub struct EulerTour {
     first: Box<[usize]>,
     last: Box<[usize]>,
@@ -28,8 +29,7 @@ impl EulerTour {
             stack.extend(
                 std::mem::take(&mut children[i])
                     .into_iter()
-                    .map(|c| [i, c])
-                    .flatten(),
+                    .flat_map(|c| [i, c]),
             );
 
             time += 1;
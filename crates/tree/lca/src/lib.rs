@@ -54,8 +54,89 @@ impl LCA {
         }
         assert_eq!(num_visited, n, "invalid input");
 
-        let mut ancestor_table = Vec::with_capacity(n * max_depth.ilog2() as usize);
-        for _ in 0..max_depth.ilog2() {
+        Self::with_doubling(parent, depth, max_depth, dfs_postorder)
+    }
+
+    /// Creates a new instance from the parent of each node, where `parents[root] == root`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root` is not the only node which is its own parent, or if parents form a cycle.
+    pub fn from_parents(parents: Vec<usize>, root: usize) -> Self {
+        const NULL: usize = !0;
+        let n = parents.len();
+        assert_eq!(parents[root], root, "invalid input");
+
+        // depth
+        let mut depth = vec![NULL; n].into_boxed_slice();
+        depth[root] = 0;
+        let mut max_depth = 0;
+        let mut path = Vec::new();
+        for i in 0..n {
+            let mut j = i;
+            while depth[j] == NULL {
+                // mark `j` as visiting
+                depth[j] = NULL - 1;
+                path.push(j);
+                j = parents[j];
+            }
+            assert_ne!(depth[j], NULL - 1, "invalid input");
+
+            while let Some(k) = path.pop() {
+                depth[k] = depth[parents[k]] + 1;
+                max_depth = max_depth.max(depth[k]);
+            }
+        }
+
+        // sort by depth, so that parents come before their children
+        let mut order = vec![0; n];
+        {
+            let mut offset = vec![0; max_depth + 2];
+            for &d in depth.iter() {
+                offset[d + 1] += 1
+            }
+            for d in 1..offset.len() {
+                offset[d] += offset[d - 1]
+            }
+            for i in 0..n {
+                order[offset[depth[i]]] = i;
+                offset[depth[i]] += 1;
+            }
+        }
+
+        // the subtree of `i` occupies `[start[i], start[i] + size[i])` in postorder
+        let mut size = vec![1; n];
+        for &i in order.iter().skip(1).rev() {
+            size[parents[i]] += size[i]
+        }
+        let mut start = vec![0; n];
+        let mut next_start = vec![0; n];
+        let mut dfs_postorder = vec![NULL; n].into_boxed_slice();
+        for &i in &order {
+            if i != root {
+                let p = parents[i];
+                start[i] = next_start[p];
+                next_start[p] += size[i];
+            }
+            next_start[i] = start[i];
+            dfs_postorder[i] = start[i] + size[i] - 1;
+        }
+
+        Self::with_doubling(parents, depth, max_depth, dfs_postorder)
+    }
+
+    /// Builds the ancestor table by doubling. `parent[root]` should be `root`.
+    fn with_doubling(
+        mut parent: Vec<usize>,
+        depth: Box<[usize]>,
+        max_depth: usize,
+        dfs_postorder: Box<[usize]>,
+    ) -> Self {
+        let n = parent.len();
+        let height = max_depth.checked_ilog2().unwrap_or(0) as usize;
+
+        let mut ancestor_table = Vec::with_capacity(n * (height + 1));
+        for _ in 0..height {
             ancestor_table.extend(parent.iter().copied());
             parent = Vec::from_iter(parent.iter().map(|&i| parent[i]))
         }
@@ -110,12 +191,14 @@ impl LCA {
             }
         }
 
+        // the first `len` entries are the parents, i.e. `ancestor_table[len * 0 + i]`
         let lca = ancestor_table[i];
         let dist = d - 2 * depth[lca];
         (lca, dist)
     }
 
     /// Returns the LCA of given nodes and the minimum length of path which connects all of them.
+    #[allow(clippy::comparison_chain)]
     pub fn lca_many(&self, mut node_list: Vec<usize>) -> Option<(usize, usize)> {
         // ３つ以上のノードのLCAとすべての頂点を結ぶ最短パスの長さを求める
         // dfs postorderでノードをソートして、順にLCAを計算
@@ -142,3 +225,134 @@ impl LCA {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    fn random_parents(n: usize, root: usize, seed: u64) -> Vec<usize> {
+        // random tree on a shuffled labeling
+        let mut next = xorshift(seed);
+        let mut rand = |m: usize| next() as usize % m;
+        let mut label = Vec::from_iter(0..n);
+        for i in (1..n).rev() {
+            label.swap(i, rand(i + 1));
+        }
+        let root_index = label.iter().position(|&v| v == root).unwrap();
+        label.swap(0, root_index);
+
+        let mut parents = vec![0; n];
+        parents[root] = root;
+        for i in 1..n {
+            parents[label[i]] = label[rand(i)];
+        }
+        parents
+    }
+
+    #[test]
+    fn compare_with_from_edges() {
+        for n in 1..40 {
+            let root = n / 3;
+            let parents = random_parents(n, root, n as u64 + 1);
+            let edges = Vec::from_iter((0..n).filter(|&i| i != root).map(|i| (i, parents[i])));
+
+            let lhs = LCA::from_parents(parents, root);
+            let rhs = LCA::from_edges(edges, root);
+            for i in 0..n {
+                for j in 0..n {
+                    assert_eq!(lhs.lca(i, j), rhs.lca(i, j));
+                }
+            }
+            for k in 0..n {
+                let nodes = Vec::from_iter((0..n).filter(|i| i % (k + 1) == 0));
+                assert_eq!(lhs.lca_many(nodes.clone()), rhs.lca_many(nodes));
+            }
+        }
+    }
+
+    /// returns the LCA and the distance by walking up parents
+    fn naive_lca(parents: &[usize], mut i: usize, mut j: usize) -> (usize, usize) {
+        let depth = |mut v: usize| {
+            let mut d = 0;
+            while parents[v] != v {
+                v = parents[v];
+                d += 1
+            }
+            d
+        };
+
+        let mut dist = 0;
+        let (mut di, mut dj) = (depth(i), depth(j));
+        while di > dj {
+            (i, di, dist) = (parents[i], di - 1, dist + 1)
+        }
+        while dj > di {
+            (j, dj, dist) = (parents[j], dj - 1, dist + 1)
+        }
+        while i != j {
+            (i, j, dist) = (parents[i], parents[j], dist + 2)
+        }
+        (i, dist)
+    }
+
+    #[test]
+    fn lca_several_levels_up() {
+        // two long branches hanging from the middle of a path:
+        // 0 - 1 - .. - 9, 5 - 10 - 11 - .. - 29, 5 - 30 - 31 - .. - 49
+        let mut parents = Vec::from_iter((0..50).map(|i: usize| i.saturating_sub(1)));
+        parents[10] = 5;
+        parents[30] = 5;
+
+        let lca = LCA::from_parents(parents.clone(), 0);
+        assert_eq!(lca.lca(29, 49), (5, 40));
+        assert_eq!(lca.lca(9, 49), (5, 24));
+        assert_eq!(lca.lca(17, 3), (3, 10));
+        assert_eq!(lca.lca(28, 11), (11, 17));
+        for i in 0..50 {
+            for j in 0..50 {
+                assert_eq!(lca.lca(i, j), naive_lca(&parents, i, j), "{i} {j}");
+            }
+        }
+    }
+
+    #[test]
+    fn random_deep_trees() {
+        for (n, seed) in [(2, 1), (64, 2), (100, 3), (257, 4)] {
+            let root = n / 2;
+            // a long path from the root in a shuffled order, and leaves attached to the root
+            let mut parents = vec![root; n];
+            let mut label = Vec::from_iter((0..n).filter(|&i| i != root));
+            label.sort_unstable_by_key(|&i| (i * 37 + seed as usize) % n);
+            let mut prev = root;
+            for (k, &i) in label.iter().enumerate() {
+                if k % 3 != 0 {
+                    parents[i] = prev;
+                    prev = i
+                } else {
+                    parents[i] = root
+                }
+            }
+
+            let lca = LCA::from_parents(parents.clone(), root);
+            for i in 0..n {
+                for j in 0..n {
+                    assert_eq!(lca.lca(i, j), naive_lca(&parents, i, j), "{i} {j}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn two_roots() {
+        LCA::from_parents(vec![0, 0, 2, 1], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cycle() {
+        LCA::from_parents(vec![0, 2, 3, 1], 0);
+    }
+}
@@ -0,0 +1,196 @@
+/// Computes a tree DP for every choice of the root.
+///
+/// The value of the subtree rooted at `v` is defined as `finalize(&acc, v)`,
+/// where `acc` is the result of folding the values of its children with `merge`, starting from `identity`.
+/// Returns the value of the whole tree rooted at each node.
+///
+/// `merge` should be associative and commutative, and `identity` should be its identity element,
+/// since children are folded in arbitrary order and with arbitrary grouping.
+///
+/// # Examples
+///
+/// Sum of distances from each node.
+///
+/// ```
+/// use rerooting::reroot;
+///
+/// // 0 - 1 - 2
+/// //     |
+/// //     3
+/// let edges = [(0, 1), (1, 2), (1, 3)];
+/// // (size of subtree, sum of distances from its root)
+/// let res = reroot(
+///     &edges,
+///     4,
+///     (0, 0),
+///     |a: &(usize, usize), b: &(usize, usize)| (a.0 + b.0, a.1 + b.1),
+///     |acc, _| (acc.0 + 1, acc.1 + acc.0),
+/// );
+/// assert_eq!(Vec::from_iter(res.into_iter().map(|(_, sum)| sum)), [5, 3, 5, 5]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if given edges does NOT represent a tree with `n` nodes.
+///
+/// # Time complexity
+///
+/// *O*(*N*)
+pub fn reroot<V, F, G>(
+    edges: &[(usize, usize)],
+    n: usize,
+    identity: V,
+    merge: F,
+    finalize: G,
+) -> Vec<V>
+where
+    V: Clone,
+    F: Fn(&V, &V) -> V,
+    G: Fn(&V, usize) -> V,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+    assert_eq!(edges.len() + 1, n, "invalid input");
+
+    let mut adj = vec![Vec::new(); n];
+    for &(u, v) in edges {
+        adj[u].push(v);
+        adj[v].push(u);
+    }
+
+    // root the tree at 0 and fix the preorder
+    const NULL: usize = !0;
+    let mut parent = vec![NULL; n];
+    let mut preorder = Vec::with_capacity(n);
+    let mut stack = vec![0];
+    parent[0] = 0;
+    while let Some(i) = stack.pop() {
+        preorder.push(i);
+        for &j in &adj[i] {
+            if parent[j] == NULL {
+                parent[j] = i;
+                stack.push(j)
+            }
+        }
+    }
+    assert_eq!(preorder.len(), n, "invalid input");
+
+    // bottom-up: values of subtrees rooted at 0
+    let mut acc = vec![identity.clone(); n];
+    let mut down = vec![identity.clone(); n];
+    for &i in preorder.iter().rev() {
+        down[i] = finalize(&acc[i], i);
+        if i != 0 {
+            let p = parent[i];
+            acc[p] = merge(&acc[p], &down[i]);
+        }
+    }
+
+    // top-down: `up[i]` is the value of the subtree rooted at `parent[i]` when `i` is the root
+    let mut up = vec![identity.clone(); n];
+    let mut res = Vec::from_iter(std::iter::repeat_with(|| identity.clone()).take(n));
+    let mut values = Vec::new();
+    let mut suffix = Vec::new();
+    for &i in &preorder {
+        values.clear();
+        values.extend(adj[i].iter().map(|&j| {
+            if j == parent[i] {
+                up[i].clone()
+            } else {
+                down[j].clone()
+            }
+        }));
+
+        // suffix[k] = merge of values[k..]
+        suffix.clear();
+        suffix.push(identity.clone());
+        for v in values.iter().rev() {
+            let v = merge(v, suffix.last().unwrap());
+            suffix.push(v);
+        }
+        suffix.reverse();
+
+        let mut prefix = identity.clone();
+        for (k, &j) in adj[i].iter().enumerate() {
+            if j != parent[i] {
+                up[j] = finalize(&merge(&prefix, &suffix[k + 1]), i);
+            }
+            prefix = merge(&prefix, &values[k]);
+        }
+        res[i] = finalize(&prefix, i);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::random_tree;
+
+    use super::*;
+
+    fn distances(edges: &[(usize, usize)], n: usize, root: usize) -> Vec<usize> {
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        let mut dist = vec![usize::MAX; n];
+        dist[root] = 0;
+        let mut stack = vec![root];
+        while let Some(i) = stack.pop() {
+            for &j in &adj[i] {
+                if dist[j] == usize::MAX {
+                    dist[j] = dist[i] + 1;
+                    stack.push(j)
+                }
+            }
+        }
+        dist
+    }
+
+    #[test]
+    fn sum_of_distances() {
+        for n in 1..50 {
+            let edges = random_tree(n, n as u64);
+            let res = reroot(
+                &edges,
+                n,
+                (0, 0),
+                |a: &(usize, usize), b: &(usize, usize)| (a.0 + b.0, a.1 + b.1),
+                |acc, _| (acc.0 + 1, acc.1 + acc.0),
+            );
+
+            for (root, (size, sum)) in res.into_iter().enumerate() {
+                assert_eq!(size, n);
+                assert_eq!(sum, distances(&edges, n, root).into_iter().sum());
+            }
+        }
+    }
+
+    #[test]
+    fn eccentricity() {
+        for n in 1..50 {
+            let edges = random_tree(n, !(n as u64));
+            // height of the subtree + 1, or 0 for the empty forest
+            let res = reroot(
+                &edges,
+                n,
+                0,
+                |a: &usize, b: &usize| *a.max(b),
+                |acc, _| acc + 1,
+            );
+
+            for (root, height) in res.into_iter().enumerate() {
+                let max = distances(&edges, n, root).into_iter().max().unwrap();
+                assert_eq!(height, max + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(reroot(&[], 0, 0, |a: &i32, b: &i32| a + b, |acc, _| *acc).is_empty());
+    }
+}
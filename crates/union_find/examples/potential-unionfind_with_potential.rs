@@ -1,7 +1,8 @@
 // verification-helper: PROBLEM https://judge.yosupo.jp/problem/unionfind_with_potential
 
+use math_traits::Group;
 use mod_int::SMint;
-use union_find::{Group, UnionFindWithPotential};
+use union_find::UnionFindWithPotential;
 
 use proconio::{fastout, input};
 
@@ -45,7 +46,7 @@ impl Group for Potential {
         Self(SMint::new(0))
     }
 
-    fn binary_operation(&self, rhs: Self) -> Self {
+    fn bin_op(&self, rhs: &Self) -> Self {
         Self(self.0 + rhs.0)
     }
 
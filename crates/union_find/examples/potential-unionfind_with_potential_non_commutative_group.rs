@@ -2,9 +2,10 @@
 
 use std::ops::Deref;
 
+use math_traits::Group;
 use mod_int::SMint;
 use proconio::{fastout, input};
-use union_find::{Group, UnionFindWithPotential};
+use union_find::UnionFindWithPotential;
 
 type Mint = SMint<998_244_353>;
 
@@ -71,7 +72,7 @@ impl Group for Matrix2x2 {
         }
     }
 
-    fn binary_operation(&self, rhs: Self) -> Self {
+    fn bin_op(&self, rhs: &Self) -> Self {
         let mut values = [[Mint::new(0); 2]; 2];
         for i in 0..2 {
             for j in 0..2 {
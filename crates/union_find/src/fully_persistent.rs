@@ -0,0 +1,151 @@
+use persistent_array::PersistentArray;
+
+/// Fully Persistent Union Find Tree.
+///
+/// # Fully persistent data structure
+///
+/// You can query and update any version. [unite](FullyPersistentUnionFind::unite) creates a new version
+/// from the given one, so that versions form a tree rather than a timeline.
+/// Versions are numbered in the order of creation, and the initial one is `0`.
+///
+/// # Example
+///
+/// ```
+/// use union_find::FullyPersistentUnionFind;
+///
+/// let mut uf = FullyPersistentUnionFind::new(4);
+/// let v1 = uf.unite(0, 1, 0);
+/// let v2 = uf.unite(1, 2, v1);
+/// // branch from `v1`
+/// let v3 = uf.unite(2, 3, v1);
+///
+/// assert!(uf.same(0, 2, v2));
+/// assert!(!uf.same(2, 3, v2));
+/// assert!(!uf.same(0, 2, v3));
+/// assert!(uf.same(2, 3, v3));
+/// assert_eq!(uf.size(0, v2), 3);
+/// assert_eq!(uf.size(0, 0), 1);
+/// ```
+///
+/// # Performance note
+///
+/// Path compression is not applicable, since every version is shared.
+///
+/// | [new](FullyPersistentUnionFind::new) | [find](FullyPersistentUnionFind::find)/[size](FullyPersistentUnionFind::size)/[same](FullyPersistentUnionFind::same)/[unite](FullyPersistentUnionFind::unite) |
+/// |--------------------------------------|----------------------------------------------------------------------------------------------------------------------------------------------------------------|
+/// | *O*(*N*)                             | *O*((log *N*)²)                                                                                                                                                |
+#[derive(Debug, Clone)]
+pub struct FullyPersistentUnionFind {
+    /// non-negative integer indicates parent index, negative integer indicates the size of the group.
+    par_or_size: PersistentArray<isize>,
+    /// `versions[v]` is the version of `par_or_size` corresponding to the version `v`
+    versions: Vec<usize>,
+}
+
+impl FullyPersistentUnionFind {
+    pub fn new(size: usize) -> Self {
+        Self {
+            par_or_size: PersistentArray::from_iter(std::iter::repeat(-1).take(size)),
+            versions: vec![0],
+        }
+    }
+
+    /// Returns the number of versions, including the initial one.
+    pub fn num_versions(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Returns the representative of the group that `i` belongs to at the given `version`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `version` is out of bounds.
+    pub fn find(&self, mut i: usize, version: usize) -> usize {
+        let version = self.versions[version];
+        // union by size keeps the height O(log N)
+        while let Ok(p) = usize::try_from(*self.par_or_size.get(version, i)) {
+            i = p
+        }
+
+        i
+    }
+
+    pub fn same(&self, i: usize, j: usize, version: usize) -> bool {
+        self.find(i, version) == self.find(j, version)
+    }
+
+    pub fn size(&self, i: usize, version: usize) -> usize {
+        let root = self.find(i, version);
+        self.par_or_size
+            .get(self.versions[version], root)
+            .unsigned_abs()
+    }
+
+    /// Unites the groups of `i` and `j` at the given `version`, then returns the new version.
+    ///
+    /// A new version is created even if they already belong to the same group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i`, `j` or `version` is out of bounds.
+    pub fn unite(&mut self, i: usize, j: usize, version: usize) -> usize {
+        let (mut ri, mut rj) = (self.find(i, version), self.find(j, version));
+        let version = self.versions[version];
+
+        let new_version = if ri == rj {
+            self.par_or_size.duplicate(version)
+        } else {
+            let (si, sj) = (
+                *self.par_or_size.get(version, ri),
+                *self.par_or_size.get(version, rj),
+            );
+            // union by size (sizes are negated)
+            if si > sj {
+                std::mem::swap(&mut ri, &mut rj)
+            }
+
+            let version = self.par_or_size.set(version, ri, si + sj);
+            self.par_or_size.set(version, rj, ri as isize)
+        };
+        self.versions.push(new_version);
+
+        self.versions.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::UnionFind;
+
+    #[test]
+    fn branches_are_independent() {
+        let n = 30;
+        let mut rng = xorshift(0x2718_2818);
+        let mut next = move || rng() as usize;
+
+        let mut uf = FullyPersistentUnionFind::new(n);
+        let mut snapshots = vec![UnionFind::new(n)];
+        for _ in 0..300 {
+            let version = next() % snapshots.len();
+            let (i, j) = (next() % n, next() % n);
+
+            let mut snapshot = snapshots[version].clone();
+            snapshot.unite(i, j);
+            assert_eq!(uf.unite(i, j, version), snapshots.len());
+            snapshots.push(snapshot);
+        }
+
+        assert_eq!(uf.num_versions(), snapshots.len());
+        for (version, snapshot) in snapshots.iter().enumerate() {
+            for i in 0..n {
+                assert_eq!(uf.size(i, version), snapshot.size(i));
+                for j in 0..n {
+                    assert_eq!(uf.same(i, j, version), snapshot.same(i, j));
+                }
+            }
+        }
+    }
+}
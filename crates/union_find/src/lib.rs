@@ -1,10 +1,14 @@
 //! A collection of union-find tree variants
 //!
 //!
+mod fully_persistent;
 mod normal;
 mod partially_persistent;
 mod potential;
+mod weighted;
 
+pub use fully_persistent::FullyPersistentUnionFind;
 pub use normal::{Groups, UnionFind};
 pub use partially_persistent::PartiallyPersistentUnionFind;
-pub use potential::{Group, UnionFindWithPotential};
+pub use potential::UnionFindWithPotential;
+pub use weighted::WeightedUnionFind;
@@ -12,6 +12,8 @@ use std::{cell::Cell, marker::PhantomData};
 #[derive(Debug, Clone)]
 pub struct UnionFind {
     par_or_size: Vec<Cell<i32>>,
+    /// the number of groups
+    count: usize,
 }
 
 impl UnionFind {
@@ -22,7 +24,7 @@ impl UnionFind {
     /// # Example
     ///
     /// ```
-    /// use alds::union_find::UnionFind;
+    /// use union_find::UnionFind;
     ///
     /// let mut uf = UnionFind::new(1_000);
     /// uf.unite(0, 2);
@@ -36,6 +38,7 @@ impl UnionFind {
 
         Self {
             par_or_size: vec![Cell::new(-1); size],
+            count: size,
         }
     }
 
@@ -46,7 +49,7 @@ impl UnionFind {
     /// # Example
     ///
     /// ```
-    /// use alds::union_find::UnionFind;
+    /// use union_find::UnionFind;
     ///
     /// let mut uf = UnionFind::new(100);
     /// assert_eq!(uf.find(0), 0);
@@ -70,12 +73,43 @@ impl UnionFind {
         ra
     }
 
+    /// Returns the root of the group that given node belongs, without path compression.
+    ///
+    /// Unlike [find](UnionFind::find), no interior state is modified,
+    /// which helps when `self` is a read-only snapshot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use union_find::UnionFind;
+    ///
+    /// let mut uf = UnionFind::new(100);
+    /// uf.unite(0, 1);
+    /// uf.unite(1, 2);
+    /// assert_eq!(uf.find_immutable(2), uf.find(2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given node is unknown.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log *N*), since union by size keeps the height of each tree logarithmic.
+    pub fn find_immutable(&self, mut a: usize) -> usize {
+        while let Ok(p) = usize::try_from(self.par_or_size[a].get()) {
+            a = p
+        }
+
+        a
+    }
+
     /// Check if given two node is in the same group.
     ///
     /// # Example
     ///
     /// ```
-    /// use alds::union_find::UnionFind;
+    /// use union_find::UnionFind;
     ///
     /// let mut uf = UnionFind::new(100);
     /// assert!(!uf.same(0, 1));
@@ -96,7 +130,7 @@ impl UnionFind {
     /// # Example
     ///
     /// ```
-    /// use alds::union_find::UnionFind;
+    /// use union_find::UnionFind;
     ///
     /// let mut uf = UnionFind::new(100);
     /// assert!((0..100).all(|i| uf.size(i) == 1));
@@ -123,7 +157,7 @@ impl UnionFind {
     ///
     /// # Example
     /// ```
-    /// use alds::union_find::UnionFind;
+    /// use union_find::UnionFind;
     ///
     /// let mut uf = UnionFind::new(100);
     ///
@@ -151,16 +185,36 @@ impl UnionFind {
         //* this method changes belongings of nodes.*//
         self.par_or_size[ra] = Cell::new(self.par_or_size[ra].take() + self.par_or_size[rb].get());
         self.par_or_size[rb] = Cell::new(ra as i32);
+        self.count -= 1;
 
         true
     }
 
+    /// Returns the number of groups in *O*(1) time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use union_find::UnionFind;
+    ///
+    /// let mut uf = UnionFind::new(5);
+    /// assert_eq!(uf.count(), 5);
+    ///
+    /// uf.unite(0, 1);
+    /// uf.unite(1, 0);
+    /// uf.unite(2, 3);
+    /// assert_eq!(uf.count(), 3);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
     /// Returns iterator of groups.
     ///
     /// # Example
     ///
     /// ```
-    /// use alds::union_find::UnionFind;
+    /// use union_find::UnionFind;
     ///
     /// let mut uf = UnionFind::new(100);
     /// for i in (2..100).step_by(2) {
@@ -190,7 +244,7 @@ impl UnionFind {
             size.push(self.par_or_size[i].get().unsigned_abs() as usize);
         }
 
-        let mut groups = Vec::from_iter(size.into_iter().map(|n| Vec::with_capacity(n)));
+        let mut groups = Vec::from_iter(size.into_iter().map(Vec::with_capacity));
         for i in 0..n {
             groups[group_id[self.find(i)]].push(i);
         }
@@ -215,3 +269,60 @@ impl Iterator for Groups<'_> {
         self.groups.pop()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn count_matches_groups() {
+        let n = 200;
+        let mut uf = UnionFind::new(n);
+        assert_eq!(uf.count(), n);
+        assert_eq!(uf.count(), uf.groups().count());
+
+        let mut rng = xorshift(0x2545_f491);
+        let mut next = move || rng() as usize % n;
+        for _ in 0..300 {
+            let (a, b) = (next(), next());
+            let merged = !uf.same(a, b);
+            let count = uf.count();
+
+            assert_eq!(uf.unite(a, b), merged);
+            assert_eq!(uf.count(), count - merged as usize);
+            assert_eq!(uf.count(), uf.groups().count());
+        }
+
+        for i in 1..n {
+            uf.unite(i - 1, i);
+        }
+        assert_eq!(uf.count(), 1);
+        assert_eq!(UnionFind::new(0).count(), 0);
+    }
+
+    #[test]
+    fn find_immutable_matches_find() {
+        let n = 300;
+        let mut uf = UnionFind::new(n);
+
+        let mut rng = xorshift(0x8bad_f00d);
+        let mut next = move || rng() as usize % n;
+        for _ in 0..10 {
+            for _ in 0..30 {
+                uf.unite(next(), next());
+            }
+
+            // no path is compressed
+            let snapshot = uf.par_or_size.clone();
+            let roots = Vec::from_iter((0..n).map(|i| uf.find_immutable(i)));
+            assert_eq!(uf.par_or_size, snapshot);
+
+            for (i, root) in roots.into_iter().enumerate() {
+                assert_eq!(root, uf.find(i));
+                assert_eq!(uf.find_immutable(i), root);
+            }
+        }
+    }
+}
@@ -46,6 +46,43 @@ impl PartiallyPersistentUnionFind {
         self.node[self.find(i, time)].size(time) as usize
     }
 
+    /// Checks if `i` and `j` were in the same group at the given `time`,
+    /// i.e. after the first `time` successful [unite](Self::unite) operations.
+    ///
+    /// Any `time` after [current_time](Self::current_time) refers to the latest version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use union_find::PartiallyPersistentUnionFind;
+    ///
+    /// let mut uf = PartiallyPersistentUnionFind::new(4);
+    /// uf.unite(0, 1);
+    /// uf.unite(0, 1); // fails, so the time does not advance
+    /// uf.unite(1, 2);
+    ///
+    /// assert!(!uf.same_at(0, 2, 1));
+    /// assert!(uf.same_at(0, 2, 2));
+    /// assert_eq!(uf.size_at(2, 1), 1);
+    /// assert_eq!(uf.size_at(2, usize::MAX), 3);
+    /// ```
+    pub fn same_at(&self, i: usize, j: usize, time: usize) -> bool {
+        self.same(i, j, Self::clamp_time(time))
+    }
+
+    /// Returns the size of the group that `i` belonged to at the given `time`.
+    ///
+    /// See [same_at](Self::same_at) for the meaning of `time`.
+    pub fn size_at(&self, i: usize, time: usize) -> usize {
+        self.size(i, Self::clamp_time(time))
+    }
+
+    #[inline]
+    fn clamp_time(time: usize) -> u32 {
+        // `u32::MAX` is never reached by `self.now`
+        u32::try_from(time).unwrap_or(Node::TIME_INFINITY)
+    }
+
     pub fn unite(&mut self, i: usize, j: usize) -> bool {
         let mut ri = self.find(i, self.now);
         let mut rj = self.find(j, self.now);
@@ -62,14 +99,16 @@ impl PartiallyPersistentUnionFind {
             assert_eq!(node[rj].time_updated, Node::TIME_INFINITY);
 
             // union by rank
-            if node[ri].par_or_rank > node[rj].par_or_rank {
-                std::mem::swap(&mut ri, &mut rj);
-            } else if node[ri].par_or_rank == node[rj].par_or_rank {
-                // keep `size_history` small
-                if node[ri].size_history.len() > node[rj].size_history.len() {
-                    std::mem::swap(&mut ri, &mut rj);
+            match node[ri].par_or_rank.cmp(&node[rj].par_or_rank) {
+                std::cmp::Ordering::Greater => std::mem::swap(&mut ri, &mut rj),
+                std::cmp::Ordering::Equal => {
+                    // keep `size_history` small
+                    if node[ri].size_history.len() > node[rj].size_history.len() {
+                        std::mem::swap(&mut ri, &mut rj);
+                    }
+                    node[ri].par_or_rank -= 1;
                 }
-                node[ri].par_or_rank -= 1;
+                std::cmp::Ordering::Less => (),
             }
 
             *now += 1;
@@ -132,3 +171,50 @@ impl Node {
         if i == 0 { 1 } else { self.size_history[i - 1].1 }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+    use crate::UnionFind;
+
+    #[test]
+    fn queries_at_each_time() {
+        let n = 50;
+        let mut rng = xorshift(0x9e37_79b9);
+        let mut next = move || rng() as usize % n;
+
+        let mut uf = PartiallyPersistentUnionFind::new(n);
+        // snapshots[t] is the state after `t` successful unions
+        let mut snapshots = vec![UnionFind::new(n)];
+        for _ in 0..200 {
+            let (i, j) = (next(), next());
+            if uf.unite(i, j) {
+                let mut latest = snapshots.last().unwrap().clone();
+                latest.unite(i, j);
+                snapshots.push(latest);
+            }
+            assert_eq!(uf.current_time() as usize, snapshots.len() - 1);
+        }
+
+        for (t, snapshot) in snapshots.iter().enumerate() {
+            for i in 0..n {
+                assert_eq!(uf.size_at(i, t), snapshot.size(i));
+                for j in 0..n {
+                    assert_eq!(uf.same_at(i, j, t), snapshot.same(i, j));
+                }
+            }
+        }
+        for i in 0..n {
+            // connectivity is monotone in time
+            for j in 0..n {
+                let first = (0..snapshots.len()).position(|t| uf.same_at(i, j, t));
+                if let Some(first) = first {
+                    assert!((first..snapshots.len()).all(|t| uf.same_at(i, j, t)));
+                }
+                assert_eq!(uf.same_at(i, j, usize::MAX), first.is_some());
+            }
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use std::cell::Cell;
 
+use math_traits::Group;
+
 /// Union Find with Potential
 ///
+/// Potentials are elements of a [Group], which may be non-commutative.
+/// For abelian groups, [WeightedUnionFind](crate::WeightedUnionFind) provides an additive interface.
+///
 /// # Performance note
 ///
 /// | [new](UnionFindWithPotential::new) | [find](UnionFindWithPotential::find)/[size](UnionFindWithPotential::size)/[same](UnionFindWithPotential::same)/[unite](UnionFindWithPotential::unite)/[potential](UnionFindWithPotential::potential) |
@@ -10,11 +15,11 @@ use std::cell::Cell;
 ///
 /// * α(*N*) is the functional inverse of Ackermann's function which diverges very slowly.
 #[derive(Debug, Clone)]
-pub struct UnionFindWithPotential<P: Group> {
+pub struct UnionFindWithPotential<P: Group + Copy + PartialEq> {
     node: Vec<Cell<Node<P>>>,
 }
 
-impl<P: Group> UnionFindWithPotential<P> {
+impl<P: Group + Copy + PartialEq> UnionFindWithPotential<P> {
     const MAX_SIZE: usize = i32::MAX as usize + 1; // 2^31
 
     pub fn new(size: usize) -> Self {
@@ -32,8 +37,7 @@ impl<P: Group> UnionFindWithPotential<P> {
             // P(i) = Pi ∘ P(parent) = Pi ∘ Pp ∘ P(root)
             self.node[i].set(Node {
                 par_or_size: r as i32,
-                potential: (self.node[i].get().potential())
-                    .binary_operation(self.node[p].get().potential()),
+                potential: (self.node[i].get().potential()).bin_op(&self.node[p].get().potential()),
             });
 
             return r;
@@ -62,13 +66,33 @@ impl<P: Group> UnionFindWithPotential<P> {
         // the parent is the root due to path compression.
         // P(i) = Pi @ P(root), P(j) = Pj @ P(root) => P(i) = Pi @ inv(Pj) @ P(j)
         // => P_ij = Pi @ inv(Pj)
-        Some(
-            (self.node[i].get().potential())
-                .binary_operation(self.node[j].get().potential().inverse()),
-        )
+        Some((self.node[i].get().potential()).bin_op(&self.node[j].get().potential().inverse()))
+    }
+
+    /// Returns D_ab of `P(b) = D_ab ∘ P(a)` if determined, i.e. `P(b) - P(a)` for additive potentials.
+    ///
+    /// This is the same as [`potential(b, a)`](Self::potential).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use math_traits::monoids::Add;
+    /// use union_find::UnionFindWithPotential;
+    ///
+    /// let mut uf = UnionFindWithPotential::new(3);
+    /// // P(0) = 5 + P(1)
+    /// uf.unite(0, 1, Add(5_i64)).unwrap();
+    ///
+    /// assert_eq!(uf.diff(0, 1), Some(Add(-5)));
+    /// assert_eq!(uf.diff(1, 0), Some(Add(5)));
+    /// assert_eq!(uf.diff(0, 2), None);
+    /// ```
+    pub fn diff(&self, a: usize, b: usize) -> Option<P> {
+        self.potential(b, a)
     }
 
     /// Sets P(i) = P_ij ∘ P(j) if there is no contradiction.
+    #[allow(clippy::result_unit_err)]
     pub fn unite(&mut self, i: usize, j: usize, potential_ij: P) -> Result<bool, ()> {
         if let Some(p_ij) = self.potential(i, j) {
             if potential_ij == p_ij {
@@ -87,8 +111,8 @@ impl<P: Group> UnionFindWithPotential<P> {
             // P(i) = Pi @ P(ri), P(j) = Pj @ P(rj), P(i) = P_ij @ P(j)
             // => P(ri) = inv(Pi) @ P_ij @ Pj @ P(rj)
             let mut potential_ri_rj = (node[i].get().potential().inverse())
-                .binary_operation(potential_ij)
-                .binary_operation(node[j].get().potential());
+                .bin_op(&potential_ij)
+                .bin_op(&node[j].get().potential());
 
             if node[ri].get().get_size().unwrap() > node[rj].get().get_size().unwrap() {
                 std::mem::swap(&mut ri, &mut rj);
@@ -107,13 +131,13 @@ impl<P: Group> UnionFindWithPotential<P> {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Node<P: Group> {
+struct Node<P: Group + Copy + PartialEq> {
     par_or_size: i32,
     /// P(self) = P ∘ P(parent)
     potential: P,
 }
 
-impl<P: Group> Node<P> {
+impl<P: Group + Copy + PartialEq> Node<P> {
     fn new() -> Self {
         Self {
             par_or_size: -1,
@@ -142,9 +166,41 @@ impl<P: Group> Node<P> {
     }
 }
 
-pub trait Group: Copy + PartialEq + Eq {
-    fn identity() -> Self;
-    /// associative binary operation
-    fn binary_operation(&self, rhs: Self) -> Self;
-    fn inverse(&self) -> Self;
+#[cfg(test)]
+mod tests {
+    use math_traits::monoids::Add;
+
+    use super::*;
+
+    #[test]
+    fn diff_of_consistent_system() {
+        let height = [3, -1, 4, 1, -5, 9, 2, -6];
+        let n = height.len();
+        let mut uf = UnionFindWithPotential::new(n);
+
+        // connect {0, 2, 4, 6} and {1, 3, 5, 7} separately via P(i) = (h_i - h_j) + P(j)
+        for (i, j) in [(0, 2), (6, 4), (2, 6), (1, 3), (7, 5), (5, 1)] {
+            assert_eq!(uf.unite(i, j, Add(height[i] - height[j])), Ok(true));
+        }
+        for a in 0..n {
+            for b in 0..n {
+                if a % 2 == b % 2 {
+                    assert_eq!(uf.diff(a, b), Some(Add(height[b] - height[a])));
+                } else {
+                    assert_eq!(uf.diff(a, b), None);
+                }
+            }
+        }
+
+        // redundant and contradictory constraints
+        assert_eq!(uf.unite(0, 6, Add(height[0] - height[6])), Ok(false));
+        assert_eq!(uf.unite(0, 6, Add(height[0] - height[6] + 1)), Err(()));
+
+        assert_eq!(uf.unite(3, 4, Add(height[3] - height[4])), Ok(true));
+        for a in 0..n {
+            for b in 0..n {
+                assert_eq!(uf.diff(a, b), Some(Add(height[b] - height[a])));
+            }
+        }
+    }
 }
@@ -0,0 +1,160 @@
+use math_traits::{marker::Commutative, Group};
+
+use crate::UnionFindWithPotential;
+
+/// Union find tree with potentials in an abelian group, such as integers, modular integers or vectors under addition.
+///
+/// This is a thin wrapper of [UnionFindWithPotential] in additive notation. Use the latter for non-commutative potentials.
+///
+/// # Example
+///
+/// ```
+/// use math_traits::monoids::Add;
+/// use union_find::WeightedUnionFind;
+///
+/// let mut uf = WeightedUnionFind::new(4);
+/// // P(1) = P(0) + 3, P(2) = P(1) - 5
+/// assert!(uf.unite(0, 1, Add(3_i64)));
+/// assert!(uf.unite(1, 2, Add(-5)));
+///
+/// assert_eq!(uf.diff(0, 2), Some(Add(-2)));
+/// assert_eq!(uf.diff(2, 0), Some(Add(2)));
+/// assert_eq!(uf.diff(0, 3), None);
+///
+/// // consistent and contradictory constraints
+/// assert!(uf.unite(2, 0, Add(2)));
+/// assert!(!uf.unite(2, 0, Add(1)));
+/// ```
+///
+/// # Performance note
+///
+/// | [new](WeightedUnionFind::new) | [find](WeightedUnionFind::find)/[size](WeightedUnionFind::size)/[same](WeightedUnionFind::same)/[unite](WeightedUnionFind::unite)/[diff](WeightedUnionFind::diff) |
+/// |-------------------------------|------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+/// | *O*(*N*)                      | *O*(α(*N*)), amortized                                                                                                                                           |
+///
+/// * α(*N*) is the functional inverse of Ackermann's function which diverges very slowly.
+#[derive(Debug, Clone)]
+pub struct WeightedUnionFind<T: Group + Commutative + Copy + PartialEq> {
+    /// `P(i) = P_ij ∘ P(j)` is stored as `P(i) = P(j) + P_ij`
+    inner: UnionFindWithPotential<T>,
+}
+
+impl<T: Group + Commutative + Copy + PartialEq> WeightedUnionFind<T> {
+    pub fn new(size: usize) -> Self {
+        Self {
+            inner: UnionFindWithPotential::new(size),
+        }
+    }
+
+    /// Returns the root of the group that given node belongs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given node is unknown.
+    pub fn find(&self, i: usize) -> usize {
+        self.inner.find(i)
+    }
+
+    pub fn same(&self, i: usize, j: usize) -> bool {
+        self.inner.same(i, j)
+    }
+
+    pub fn size(&self, i: usize) -> usize {
+        self.inner.size(i)
+    }
+
+    /// Returns P(j) - P(i) if determined.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given node is unknown.
+    pub fn diff(&self, i: usize, j: usize) -> Option<T> {
+        self.inner.diff(i, j)
+    }
+
+    /// Adds the constraint P(j) = P(i) + `w`.
+    ///
+    /// Returns `false` and does nothing if it contradicts the existing constraints.
+    /// Otherwise, returns `true`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given node is unknown.
+    pub fn unite(&mut self, i: usize, j: usize, w: T) -> bool {
+        self.inner.unite(j, i, w).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use math_traits::monoids::Add;
+    use mod_int::SMint;
+    use test_utils::xorshift;
+
+    use super::*;
+
+    #[test]
+    fn integer_potentials() {
+        let mut next = xorshift(0x2545_f491);
+
+        let n = 100;
+        let height = Vec::from_iter((0..n).map(|_| next() as i64 % 1000));
+        let mut uf = WeightedUnionFind::new(n);
+        for _ in 0..150 {
+            let (i, j) = (next() as usize % n, next() as usize % n);
+            let merged = !uf.same(i, j);
+            assert!(uf.unite(i, j, Add(height[j] - height[i])));
+            if !merged {
+                assert!(!uf.unite(i, j, Add(height[j] - height[i] + 1)));
+            }
+
+            for i in 0..n {
+                for j in 0..n {
+                    let expected = uf.same(i, j).then_some(Add(height[j] - height[i]));
+                    assert_eq!(uf.diff(i, j), expected);
+                }
+            }
+        }
+        assert_eq!(uf.diff(3, 3), Some(Add(0)));
+    }
+
+    #[test]
+    fn modint_potentials() {
+        type Mint = SMint<7>;
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct ModAdd(Mint);
+
+        impl Group for ModAdd {
+            fn identity() -> Self {
+                Self(Mint::new(0))
+            }
+
+            fn bin_op(&self, rhs: &Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+
+            fn inverse(&self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Commutative for ModAdd {}
+
+        let mut uf = WeightedUnionFind::new(5);
+        // P(0) = 0, P(1) = 4, P(2) = 4 + 5 = 2, P(3) = 2 + 6 = 1
+        assert!(uf.unite(0, 1, ModAdd(Mint::new(4))));
+        assert!(uf.unite(1, 2, ModAdd(Mint::new(5))));
+        assert!(uf.unite(3, 2, ModAdd(Mint::new(1))));
+        assert_eq!(uf.size(0), 4);
+
+        assert_eq!(uf.diff(0, 3), Some(ModAdd(Mint::new(1))));
+        assert_eq!(uf.diff(3, 0), Some(ModAdd(Mint::new(6))));
+        assert_eq!(uf.diff(0, 4), None);
+
+        // wraps around the modulus
+        assert!(uf.unite(3, 0, ModAdd(Mint::new(13))));
+        assert!(!uf.unite(3, 0, ModAdd(Mint::new(0))));
+        assert_eq!(uf.size(4), 1);
+    }
+}
@@ -4,9 +4,13 @@ use math_traits::Monoid;
 
 #[repr(align(64))]
 pub struct WideSegmentTree<T: Monoid> {
+    /// Layers from the bottom to the top, each of which is padded with identity elements
+    /// to a multiple of [N](Self::N). The top layer has at most `N` elements.
     data: Box<[T]>,
     /// Partitions between layers
     partition: Box<[usize]>,
+    /// Number of elements in the bottom layer, excluding padding.
+    len: usize,
 }
 
 impl<T: Monoid> WideSegmentTree<T> {
@@ -33,7 +37,9 @@ impl<T: Monoid> WideSegmentTree<T> {
     }
 
     pub fn point_update(&mut self, mut i: usize, elem: T) {
-        let Self { data, partition } = self;
+        let Self {
+            data, partition, ..
+        } = self;
 
         for p in partition.iter() {
             data[p + i] = elem.bin_op(&data[p + i]);
@@ -41,6 +47,15 @@ impl<T: Monoid> WideSegmentTree<T> {
         }
     }
 
+    /// Returns the product of the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(*N* log<sub>*N*</sub> *n*), where *N* is the number of elements in a cache line.
     pub fn range_query<R>(&self, range: R) -> T
     where
         R: RangeBounds<usize>,
@@ -53,43 +68,161 @@ impl<T: Monoid> WideSegmentTree<T> {
         let mut r = match range.end_bound() {
             std::ops::Bound::Included(r) => r + 1,
             std::ops::Bound::Excluded(r) => *r,
-            std::ops::Bound::Unbounded => todo!(),
+            std::ops::Bound::Unbounded => self.len,
         };
+        assert!(r <= self.len, "range end {} out of bounds", r);
         if l >= r {
             return T::identity();
         }
 
         let (mut res_l, mut res_r) = (T::identity(), T::identity());
-        let Self { data, partition } = self;
+        let Self {
+            data, partition, ..
+        } = self;
         for p in partition.iter() {
-            if l / Self::N == r / Self::N {
+            // `[l, r)` lies in a single block
+            if l / Self::N == (r - 1) / Self::N {
                 return data[p + l..p + r]
                     .iter()
                     .fold(res_l, |acc, v| acc.bin_op(v))
                     .bin_op(&res_r);
-            } else {
-                if l % Self::N != 0 {
-                    res_l = data[p + l..Self::round_up(p + l)]
-                        .iter()
-                        .fold(res_l, |acc, v| acc.bin_op(v));
-                    l += Self::N;
-                }
-                if r & Self::N != 0 {
-                    res_r = data[Self::round_down(p + l)..p + l]
-                        .iter()
-                        .fold(res_r, |acc, v| v.bin_op(&acc));
-                }
+            }
 
-                l >>= Self::BITS;
-                r >>= Self::BITS;
+            if l % Self::N != 0 {
+                res_l = data[p + l..p + Self::round_up(l)]
+                    .iter()
+                    .fold(res_l, |acc, v| acc.bin_op(v));
+                l = Self::round_up(l);
+            }
+            if r % Self::N != 0 {
+                res_r = data[p + Self::round_down(r)..p + r]
+                    .iter()
+                    .rev()
+                    .fold(res_r, |acc, v| v.bin_op(&acc));
+                r = Self::round_down(r);
             }
+            if l == r {
+                return res_l.bin_op(&res_r);
+            }
+
+            l >>= Self::BITS;
+            r >>= Self::BITS;
         }
-        unreachable!()
+        unreachable!("the top layer consists of a single block")
     }
 }
 
 impl<T: Monoid> FromIterator<T> for WideSegmentTree<T> {
+    /// # Time complexity
+    ///
+    /// *O*(*n*)
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        todo!()
+        let mut data = Vec::from_iter(iter);
+        let len = data.len();
+        data.resize_with(Self::round_up(len), T::identity);
+
+        let mut partition = vec![0];
+        let (mut begin, mut end) = (0, len);
+        while end - begin > Self::N {
+            let next = data.len();
+            for i in (begin..end).step_by(Self::N) {
+                let block = data[i..i + Self::N]
+                    .iter()
+                    .fold(T::identity(), |acc, v| acc.bin_op(v));
+                data.push(block)
+            }
+            (begin, end) = (next, data.len());
+            data.resize_with(next + Self::round_up(end - next), T::identity);
+            partition.push(next);
+        }
+
+        Self {
+            data: data.into_boxed_slice(),
+            partition: partition.into_boxed_slice(),
+            len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::xorshift;
+
+    use super::*;
+
+    /// `x -> ax + b` over integers modulo 2^32, applied from left to right
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Affine(u32, u32);
+
+    impl Monoid for Affine {
+        fn identity() -> Self {
+            Self(1, 0)
+        }
+
+        fn bin_op(&self, rhs: &Self) -> Self {
+            Self(
+                self.0.wrapping_mul(rhs.0),
+                self.1.wrapping_mul(rhs.0).wrapping_add(rhs.1),
+            )
+        }
+    }
+
+    /// same as [Affine], but only 4 elements fit a cache line
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct WideAffine(u64, u64);
+
+    impl Monoid for WideAffine {
+        fn identity() -> Self {
+            Self(1, 0)
+        }
+
+        fn bin_op(&self, rhs: &Self) -> Self {
+            Self(
+                self.0.wrapping_mul(rhs.0),
+                self.1.wrapping_mul(rhs.0).wrapping_add(rhs.1),
+            )
+        }
+    }
+
+    fn check<T: Monoid + Copy + PartialEq + std::fmt::Debug>(f: impl Fn(u64) -> T) {
+        let mut next = xorshift(0x9e37_79b9);
+
+        for n in [
+            0, 1, 2, 3, 4, 5, 7, 8, 9, 15, 16, 17, 31, 33, 64, 65, 100, 300,
+        ] {
+            let values = Vec::from_iter((0..n).map(|_| f(next())));
+            let seg_tree = WideSegmentTree::from_iter(values.iter().copied());
+
+            let naive = |l: usize, r: usize| {
+                values[l..r]
+                    .iter()
+                    .fold(T::identity(), |acc, v| acc.bin_op(v))
+            };
+            assert_eq!(seg_tree.range_query(..), naive(0, n));
+            for _ in 0..500 {
+                let (l, r) = (next() as usize % (n + 1), next() as usize % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                assert_eq!(seg_tree.range_query(l..r), naive(l, r), "{n}: {l}..{r}");
+            }
+            if n <= 33 {
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(seg_tree.range_query(l..r), naive(l, r), "{n}: {l}..{r}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_ranges() {
+        check(|x| Affine(x as u32 | 1, (x >> 32) as u32));
+        check(|x| WideAffine(x | 1, x.rotate_left(17)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        WideSegmentTree::from_iter((0..10).map(|i| Affine(1, i))).range_query(5..11);
     }
 }